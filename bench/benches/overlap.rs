@@ -0,0 +1,112 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use str_overlap::{
+    overlap_end_translated, overlap_index, overlap_index_linear, ByteSliceOverlap, Overlap,
+};
+
+/// Inputs of the form `"aaaa…aab"` and `"aaaa…aac"`, where every candidate overlap matches for all
+/// but its final byte. This is the worst case for comparing each candidate directly.
+fn long_partial_matches(len: usize) -> (String, String) {
+    let a = "a".repeat(len);
+    (a.clone() + "b", a + "c")
+}
+
+/// Inputs with a short overlap and otherwise unrelated contents, representing the common case.
+fn short_overlap(len: usize) -> (String, String) {
+    let text = "abcdefghijklmnopqrstuvwxyz".repeat(len / 26 + 1);
+    (text[..len].to_owned(), text[(len - 8)..].to_owned() + "!")
+}
+
+/// Generates a pair of inputs of roughly the given length.
+type Input = fn(usize) -> (String, String);
+
+/// The inputs that every benchmark is run on, along with their names.
+const INPUTS: [(&str, Input); 2] = [
+    ("long_partial_matches", long_partial_matches),
+    ("short_overlap", short_overlap),
+];
+
+fn overlap_end(c: &mut Criterion) {
+    for &(name, input) in &INPUTS {
+        let mut group = c.benchmark_group(name);
+        for &len in &[64, 1024, 4096] {
+            let (left, right) = input(len);
+            group.bench_with_input(BenchmarkId::new("scan", len), &len, |b, _| {
+                b.iter(|| black_box(&left).overlap_end(black_box(&right)))
+            });
+            group.bench_with_input(BenchmarkId::new("kmp", len), &len, |b, _| {
+                b.iter(|| overlap_index_linear(black_box(&left), black_box(&right)))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn overlap_start(c: &mut Criterion) {
+    for &(name, input) in &INPUTS {
+        let mut group = c.benchmark_group(format!("{}/overlap_start", name));
+        for &len in &[64, 1024, 4096] {
+            let (left, right) = input(len);
+            group.bench_with_input(BenchmarkId::new("scan", len), &len, |b, _| {
+                b.iter(|| black_box(&right).overlap_start(black_box(&left)))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn overlap_index_only(c: &mut Criterion) {
+    for &(name, input) in &INPUTS {
+        let mut group = c.benchmark_group(format!("{}/overlap_index", name));
+        for &len in &[64, 1024, 4096] {
+            let (left, right) = input(len);
+            group.bench_with_input(BenchmarkId::new("scan", len), &len, |b, _| {
+                b.iter(|| overlap_index(black_box(&left), black_box(&right)))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Compares bytes ignoring ASCII case using a translation table and using a closure.
+fn ascii_case_insensitive(c: &mut Criterion) {
+    let mut table = [0; 256];
+    for (byte, entry) in table.iter_mut().enumerate() {
+        *entry = (byte as u8).to_ascii_lowercase();
+    }
+
+    for &(name, input) in &INPUTS {
+        let mut group = c.benchmark_group(format!("{}/ascii_case_insensitive", name));
+        for &len in &[64, 1024, 4096] {
+            let (left, right) = input(len);
+            let right = right.to_ascii_uppercase();
+            group.bench_with_input(BenchmarkId::new("table", len), &len, |b, _| {
+                b.iter(|| {
+                    overlap_end_translated(
+                        black_box(left.as_bytes()),
+                        black_box(right.as_bytes()),
+                        &table,
+                    )
+                })
+            });
+            group.bench_with_input(BenchmarkId::new("closure", len), &len, |b, _| {
+                b.iter(|| {
+                    black_box(left.as_bytes())
+                        .overlap_end_bytes_by(black_box(right.as_bytes()), |a, b| {
+                            a.eq_ignore_ascii_case(&b)
+                        })
+                        .len()
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(
+    benches,
+    overlap_end,
+    overlap_start,
+    overlap_index_only,
+    ascii_case_insensitive
+);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+//! Benchmarks for `str_overlap`.
+//!
+//! The benchmarks themselves are located in the `benches` directory, and can be run using
+//! `cargo bench` from within this directory.
@@ -1,5 +1,11 @@
 extern crate autocfg;
 
 fn main() {
-    autocfg::new().emit_rustc_version(1, 6);
+    let ac = autocfg::new();
+    ac.emit_rustc_version(1, 6);
+    ac.emit_rustc_version(1, 20);
+    ac.emit_rustc_version(1, 21);
+    ac.emit_rustc_version(1, 23);
+    ac.emit_rustc_version(1, 46);
+    ac.emit_rustc_version(1, 74);
 }
@@ -0,0 +1,47 @@
+//! Compile test for using `str_overlap` with neither `std` nor `alloc`.
+//!
+//! This crate depends on `str_overlap` with default features disabled, and never links `alloc`. It
+//! is built in CI for a target without `std`, such as `thumbv6m-none-eabi`, which ensures that the
+//! borrowing overlap methods never require an allocator. Its tests can be run on the host using
+//! `cargo test` from within this directory.
+
+#![cfg_attr(not(test), no_std)]
+
+use str_overlap::{Overlap, StrOverlap};
+
+/// Returns the overlap found at the end of `left` and the start of `right`.
+pub fn overlap_end<'a>(left: &'a str, right: &str) -> &'a str {
+    left.overlap_end(right)
+}
+
+/// Returns the overlap found at the start of `left` and the end of `right`.
+pub fn overlap_start<'a>(left: &'a str, right: &str) -> &'a str {
+    left.overlap_start(right)
+}
+
+/// Returns the overlap found at the end of `left` and the start of `right`, for raw bytes.
+pub fn bytes_overlap_end<'a>(left: &'a [u8], right: &[u8]) -> &'a [u8] {
+    left.overlap_end(right)
+}
+
+/// Returns the byte range of the overlap found at the end of `left` and the start of `right`.
+pub fn overlap_end_range(left: &str, right: &str) -> core::ops::Range<usize> {
+    left.overlap_end_range(right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_overlap_end, overlap_end, overlap_end_range, overlap_start};
+
+    #[test]
+    fn str_overlap() {
+        assert_eq!(overlap_end("abc", "bcd"), "bc");
+        assert_eq!(overlap_start("bcd", "abc"), "bc");
+        assert_eq!(overlap_end_range("abc", "bcd"), 1..3);
+    }
+
+    #[test]
+    fn bytes_overlap() {
+        assert_eq!(bytes_overlap_end(b"a\xFF\x00", b"\xFF\x00b"), b"\xFF\x00");
+    }
+}
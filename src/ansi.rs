@@ -0,0 +1,185 @@
+//! Overlaps of strings that skip over ANSI escape sequences.
+//!
+//! Terminal output interleaves visible text with escape sequences that set colors or move the
+//! cursor, and the same text may be captured with different sequences each time. Only the visible
+//! characters are compared, while the overlap is still reported as ranges within the original
+//! strings.
+
+use core::ops::Range;
+use mapped::Mapped;
+
+/// The escape character that begins every recognized sequence.
+const ESC: u8 = 0x1b;
+/// The bell character, which may terminate an OSC sequence.
+const BEL: u8 = 0x07;
+
+/// Returns the length in bytes of the complete escape sequence at the start of `bytes`, or `None`
+/// if it doesn't begin with one.
+///
+/// Two kinds of sequences are recognized:
+///
+/// - CSI sequences, such as SGR color codes, consisting of `ESC [`, any parameter and
+///   intermediate bytes in the range `0x20` to `0x3F`, and a final byte in the range `0x40` to
+///   `0x7E`.
+/// - OSC sequences, such as window titles and hyperlinks, consisting of `ESC ]` and any bytes up
+///   to a terminating `BEL` or `ESC \`.
+///
+/// A sequence that is cut off or contains an unexpected byte is not complete.
+#[inline]
+fn escape_len(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() < 2 || bytes[0] != ESC {
+        return None;
+    }
+    match bytes[1] {
+        b'[' => {
+            for (index, &byte) in bytes.iter().enumerate().skip(2) {
+                // Parameter and intermediate bytes are in the range 0x20 to 0x3F.
+                if byte & 0xe0 == 0x20 {
+                    continue;
+                }
+                // Final bytes are in the range 0x40 to 0x7E.
+                if byte & 0xc0 == 0x40 && byte != 0x7f {
+                    return Some(index + 1);
+                }
+                return None;
+            }
+            None
+        }
+        b']' => {
+            for (index, &byte) in bytes.iter().enumerate().skip(2) {
+                if byte == BEL {
+                    return Some(index + 1);
+                }
+                if byte == ESC {
+                    return if bytes.get(index + 1) == Some(&b'\\') {
+                        Some(index + 2)
+                    } else {
+                        None
+                    };
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Splits the visible characters of `s` from its escape sequences, which are skipped.
+#[inline]
+fn visible(s: &str) -> Mapped<char> {
+    let mut visible = Mapped::new(s.len());
+    let mut index = 0;
+    while let Some(c) = s[index..].chars().next() {
+        if let Some(len) = escape_len(&s.as_bytes()[index..]) {
+            // Every byte of a complete sequence is ASCII, so `index` remains a character bound.
+            index += len;
+            continue;
+        }
+        let end = index + c.len_utf8();
+        visible.push(c, index..end);
+        index = end;
+    }
+    visible
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when only visible characters are compared, within `left` and within `right` respectively.
+///
+/// The range within `left` runs from its first compared character to its end, and the range
+/// within `right` runs from its start to its last compared character, so that escape sequences
+/// within either span are included. If nothing overlaps, the ranges are empty and located at the
+/// end of `left` and the start of `right`.
+#[inline]
+pub fn ansi_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    visible(left).overlap_indices(&visible(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ansi_overlap_indices, escape_len};
+    use StrOverlap;
+
+    #[test]
+    fn sgr_mid_word() {
+        let left = "the qu\x1b[31mick\x1b[0m brown";
+        let right = "quick \x1b[1;32mbr\x1b[0mown fox";
+
+        assert_eq!(left.overlap_end_ansi_indices(right), (4..24, 0..22));
+        assert_eq!(&left[4..24], "qu\x1b[31mick\x1b[0m brown");
+        assert_eq!(&right[0..22], "quick \x1b[1;32mbr\x1b[0mown");
+        assert_eq!(right.overlap_start_ansi_indices(left), (0..22, 4..24));
+    }
+
+    #[test]
+    fn different_colors() {
+        let left = "\x1b[31mab\x1b[32mcd";
+        let right = "\x1b[34mc\x1b[35md\x1b[0mef";
+
+        assert_eq!(left.overlap_end_ansi_indices(right), (12..14, 0..12));
+    }
+
+    #[test]
+    fn escapes_within_spans() {
+        // A trailing reset in `left` and a leading color in `right` are within the spans.
+        assert_eq!(
+            "xab\x1b[0m".overlap_end_ansi_indices("\x1b[1mabc"),
+            (1..7, 0..6)
+        );
+    }
+
+    #[test]
+    fn cursor_movement() {
+        assert_eq!(
+            "ab\x1b[2Kcd".overlap_end_ansi_indices("bc\x1b[10;20Hd"),
+            (1..8, 0..11)
+        );
+    }
+
+    #[test]
+    fn osc_sequences() {
+        // A hyperlink terminated by ST, and a window title terminated by BEL.
+        let left = "a\x1b]8;;http://x\x1b\\bc";
+        let right = "b\x1b]0;title\x07cd";
+
+        assert_eq!(left.overlap_end_ansi_indices(right), (16..18, 0..12));
+    }
+
+    #[test]
+    fn incomplete_escape_at_edge() {
+        // The sequence cut off at the end of `left` is compared as literal text, matching the
+        // malformed sequence in `right`.
+        assert_eq!(
+            "ab\x1b[3".overlap_end_ansi_indices("b\x1b[3\u{e9}"),
+            (1..5, 0..4)
+        );
+        assert_eq!(
+            "ab\x1b[3".overlap_end_ansi_indices("b\x1b[31m"),
+            (5..5, 0..0)
+        );
+        // The rest of a sequence cut off at the start of `right` is also literal text.
+        assert_eq!("x\x1b[".overlap_end_ansi_indices("[1mb"), (2..3, 0..1));
+        assert_eq!("xa\x1b".overlap_end_ansi_indices("\x1b]"), (2..3, 0..1));
+    }
+
+    #[test]
+    fn escape_len_bounds() {
+        assert_eq!(escape_len(b"\x1b[0m"), Some(4));
+        assert_eq!(escape_len(b"\x1b[38;5;196mx"), Some(11));
+        assert_eq!(escape_len(b"\x1b]0;t\x07x"), Some(6));
+        assert_eq!(escape_len(b"\x1b]0;t\x1b\\x"), Some(7));
+        assert_eq!(escape_len(b"\x1b[31"), None);
+        assert_eq!(escape_len(b"\x1b]0;t"), None);
+        assert_eq!(escape_len(b"\x1b]0;t\x1bx"), None);
+        assert_eq!(escape_len(b"\x1b[\x7f"), None);
+        assert_eq!(escape_len(b"\x1b"), None);
+        assert_eq!(escape_len(b"a"), None);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(ansi_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(ansi_overlap_indices("a\x1b[0m", "\x1b[0mb"), (5..5, 0..0));
+        assert_eq!(ansi_overlap_indices("", "abc"), (0..0, 0..0));
+        assert_eq!(ansi_overlap_indices("abc", ""), (3..3, 0..0));
+    }
+}
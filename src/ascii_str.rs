@@ -0,0 +1,272 @@
+//! Overlap support for ASCII strings from the [`ascii`] crate.
+//!
+//! Every byte of an [`AsciiStr`] is a whole character, so overlaps are found by comparing bytes
+//! directly, the same as for `[u8]`. Unlike the implementation on `str`, there are no character
+//! bounds to account for when truncating an overlap.
+//!
+//! [`ascii`]: https://docs.rs/ascii
+
+use ascii::AsciiStr;
+use {Direction, Overlap};
+
+/// Converts a subslice of the bytes backing an [`AsciiStr`] back into an [`AsciiStr`].
+#[cfg(not(feature = "safe"))]
+#[inline]
+fn from_bytes(bytes: &[u8]) -> &AsciiStr {
+    unsafe {
+        // SAFETY: `bytes` is a subslice of an `AsciiStr`'s bytes, all of which are ASCII.
+        AsciiStr::from_ascii_unchecked(bytes)
+    }
+}
+
+/// Converts a subslice of the bytes backing an [`AsciiStr`] back into an [`AsciiStr`].
+#[cfg(feature = "safe")]
+#[inline]
+fn from_bytes(bytes: &[u8]) -> &AsciiStr {
+    // `bytes` is a subslice of an `AsciiStr`'s bytes, so this can never fail.
+    AsciiStr::from_ascii(bytes).unwrap()
+}
+
+/// Overlap methods for ASCII string slices.
+///
+/// These methods return the same overlaps as the implementation on `str` would for the same text.
+/// `AsciiString` can use these methods through its implementation of [`Deref<Target = AsciiStr>`].
+/// This implementation is only available when the `ascii` feature is enabled.
+///
+/// [`Deref<Target = AsciiStr>`]: core::ops::Deref
+impl Overlap for AsciiStr {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        from_bytes(self.as_bytes().overlap_start(other.as_bytes()))
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        from_bytes(self.as_bytes().overlap_end(other.as_bytes()))
+    }
+
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        self.as_bytes().overlap_start_len(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.as_bytes().overlap_end_len(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        self.as_bytes().has_overlap_start(other.as_bytes())
+    }
+
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        self.as_bytes().has_overlap_end(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        self.as_bytes().overlap_start_is_empty(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        self.as_bytes().overlap_end_is_empty(other.as_bytes())
+    }
+
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        self.as_bytes()
+            .try_overlap_start(other.as_bytes())
+            .map(from_bytes)
+    }
+
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        self.as_bytes()
+            .try_overlap_end(other.as_bytes())
+            .map(from_bytes)
+    }
+
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        from_bytes(self.as_bytes().overlap_start_min(other.as_bytes(), min_len))
+    }
+
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        from_bytes(self.as_bytes().overlap_end_min(other.as_bytes(), min_len))
+    }
+
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        from_bytes(self.as_bytes().overlap_start_max(other.as_bytes(), max_len))
+    }
+
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        from_bytes(self.as_bytes().overlap_end_max(other.as_bytes(), max_len))
+    }
+
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        from_bytes(self.as_bytes().trim_overlap_start(other.as_bytes()))
+    }
+
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        from_bytes(self.as_bytes().trim_overlap_end(other.as_bytes()))
+    }
+
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let (overlap, rest) = self.as_bytes().split_at_overlap_start(other.as_bytes());
+        (from_bytes(overlap), from_bytes(rest))
+    }
+
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let (rest, overlap) = self.as_bytes().split_at_overlap_end(other.as_bytes());
+        (from_bytes(rest), from_bytes(overlap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ascii::AsciiStr;
+    use Overlap;
+
+    fn ascii_str(s: &str) -> &AsciiStr {
+        AsciiStr::from_ascii(s).unwrap()
+    }
+
+    /// Pairs of strings covering partial, full, empty, and missing overlaps.
+    const PAIRS: &[(&str, &str)] = &[
+        ("abc", "bcd"),
+        ("bcd", "abc"),
+        ("abc", "abc"),
+        ("abc", "def"),
+        ("abcbc", "bcbcd"),
+        ("", "abc"),
+        ("abc", ""),
+        ("", ""),
+    ];
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(
+            ascii_str("bcd").overlap_start(ascii_str("abc")),
+            ascii_str("bc")
+        );
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(
+            ascii_str("abc").overlap_end(ascii_str("bcd")),
+            ascii_str("bc")
+        );
+    }
+
+    #[test]
+    fn matches_str_overlap() {
+        for &(left, right) in PAIRS {
+            assert_eq!(
+                ascii_str(left).overlap_start(ascii_str(right)).as_str(),
+                left.overlap_start(right)
+            );
+            assert_eq!(
+                ascii_str(left).overlap_end(ascii_str(right)).as_str(),
+                left.overlap_end(right)
+            );
+            assert_eq!(
+                ascii_str(left).has_overlap_end(ascii_str(right)),
+                left.has_overlap_end(right)
+            );
+            assert_eq!(
+                ascii_str(left).overlap_end_is_empty(ascii_str(right)),
+                left.overlap_end_is_empty(right)
+            );
+        }
+    }
+
+    #[test]
+    fn matches_str_trim() {
+        for &(left, right) in PAIRS {
+            assert_eq!(
+                ascii_str(left)
+                    .trim_overlap_start(ascii_str(right))
+                    .as_str(),
+                left.trim_overlap_start(right)
+            );
+            assert_eq!(
+                ascii_str(left).trim_overlap_end(ascii_str(right)).as_str(),
+                left.trim_overlap_end(right)
+            );
+        }
+    }
+
+    #[test]
+    fn matches_str_split() {
+        for &(left, right) in PAIRS {
+            let (overlap, rest) = ascii_str(left).split_at_overlap_start(ascii_str(right));
+            assert_eq!(
+                (overlap.as_str(), rest.as_str()),
+                left.split_at_overlap_start(right)
+            );
+            let (rest, overlap) = ascii_str(left).split_at_overlap_end(ascii_str(right));
+            assert_eq!(
+                (rest.as_str(), overlap.as_str()),
+                left.split_at_overlap_end(right)
+            );
+        }
+    }
+
+    #[test]
+    fn matches_str_min_and_max() {
+        for &(left, right) in PAIRS {
+            for len in 0..4 {
+                assert_eq!(
+                    ascii_str(left)
+                        .overlap_start_min(ascii_str(right), len)
+                        .as_str(),
+                    left.overlap_start_min(right, len)
+                );
+                assert_eq!(
+                    ascii_str(left)
+                        .overlap_end_max(ascii_str(right), len)
+                        .as_str(),
+                    left.overlap_end_max(right, len)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overlap_end_borrows_from_self() {
+        let ascii_str = ascii_str("abc");
+        let overlap = ascii_str.overlap_end(self::ascii_str("bcd"));
+
+        assert_eq!(
+            overlap.as_ptr(),
+            ascii_str.as_bytes()[1..].as_ptr() as *const _
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Overlap support for byte strings from the [`bstr`] crate.
+//!
+//! A [`BStr`] is conventionally, but not necessarily, UTF-8. Overlaps are found by comparing its
+//! bytes directly, the same as for `[u8]`, so no UTF-8 validation is performed and an overlap may
+//! begin or end in the middle of a multi-byte sequence.
+//!
+//! [`bstr`]: https://docs.rs/bstr
+
+use bstr::{BStr, ByteSlice};
+use {Direction, Overlap};
+
+/// Overlap methods for byte strings.
+///
+/// These methods behave the same as the implementation on `[u8]`, returning [`BStr`]s instead of
+/// byte slices. `BString` can use these methods through its implementation of
+/// [`Deref<Target = BStr>`]. This implementation is only available when the `bstr` feature is
+/// enabled.
+///
+/// [`Deref<Target = BStr>`]: core::ops::Deref
+impl Overlap for BStr {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        BStr::new(self.as_bytes().overlap_start(other.as_bytes()))
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        BStr::new(self.as_bytes().overlap_end(other.as_bytes()))
+    }
+
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        self.as_bytes().overlap_start_len(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.as_bytes().overlap_end_len(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        self.as_bytes().has_overlap_start(other.as_bytes())
+    }
+
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        self.as_bytes().has_overlap_end(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        self.as_bytes().overlap_start_is_empty(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        self.as_bytes().overlap_end_is_empty(other.as_bytes())
+    }
+
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        self.as_bytes()
+            .try_overlap_start(other.as_bytes())
+            .map(BStr::new)
+    }
+
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        self.as_bytes()
+            .try_overlap_end(other.as_bytes())
+            .map(BStr::new)
+    }
+
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        BStr::new(self.as_bytes().overlap_start_min(other.as_bytes(), min_len))
+    }
+
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        BStr::new(self.as_bytes().overlap_end_min(other.as_bytes(), min_len))
+    }
+
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        BStr::new(self.as_bytes().overlap_start_max(other.as_bytes(), max_len))
+    }
+
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        BStr::new(self.as_bytes().overlap_end_max(other.as_bytes(), max_len))
+    }
+
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        BStr::new(self.as_bytes().trim_overlap_start(other.as_bytes()))
+    }
+
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        BStr::new(self.as_bytes().trim_overlap_end(other.as_bytes()))
+    }
+
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let (overlap, rest) = self.as_bytes().split_at_overlap_start(other.as_bytes());
+        (BStr::new(overlap), BStr::new(rest))
+    }
+
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let (rest, overlap) = self.as_bytes().split_at_overlap_end(other.as_bytes());
+        (BStr::new(rest), BStr::new(overlap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::BStr;
+    use Overlap;
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(BStr::new("bcd").overlap_start(BStr::new("abc")), "bc");
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(BStr::new("abc").overlap_end(BStr::new("bcd")), "bc");
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(BStr::new("abc").overlap_end(BStr::new("def")), "");
+        assert!(BStr::new("abc")
+            .try_overlap_start(BStr::new("def"))
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_utf8_at_seam_end() {
+        // The overlap is the lone continuation byte, which is not valid UTF-8 on its own.
+        assert_eq!(
+            BStr::new(b"a\xE6\x97").overlap_end(BStr::new(b"\x97b")),
+            BStr::new(b"\x97")
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_at_seam_start() {
+        assert_eq!(
+            BStr::new(b"\xFFb").overlap_start(BStr::new(b"a\xFF")),
+            BStr::new(b"\xFF")
+        );
+    }
+
+    #[test]
+    fn splits_multi_byte_sequence() {
+        // "日" is encoded as `E6 97 A5`. Comparing bytes splits it, unlike the implementation on
+        // `str`.
+        assert_eq!(
+            BStr::new("a日").overlap_end(BStr::new(b"\x97\xA5b")),
+            BStr::new(b"\x97\xA5")
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(
+            BStr::new(b"a\xFF\xFF").overlap_end_len(BStr::new(b"\xFF\xFFb")),
+            2
+        );
+        assert_eq!(
+            BStr::new(b"\xFF\xFFb").overlap_start_len(BStr::new(b"a\xFF\xFF")),
+            2
+        );
+    }
+
+    #[test]
+    fn trim_overlap() {
+        assert_eq!(
+            BStr::new(b"a\xFF\xFF").trim_overlap_end(BStr::new(b"\xFF\xFFb")),
+            "a"
+        );
+        assert_eq!(
+            BStr::new(b"\xFF\xFFb").trim_overlap_start(BStr::new(b"a\xFF\xFF")),
+            "b"
+        );
+    }
+
+    #[test]
+    fn split_at_overlap() {
+        assert_eq!(
+            BStr::new(b"a\xFF\xFF").split_at_overlap_end(BStr::new(b"\xFF\xFFb")),
+            (BStr::new("a"), BStr::new(b"\xFF\xFF"))
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Overlap support for bit slices from the [`bitvec`] crate.
+//!
+//! Overlaps of a [`BitSlice`] are measured in bits, so they need not be aligned to, or be a
+//! multiple of, any storage element.
+//!
+//! [`BitSlice`]: bitvec::slice::BitSlice
+//! [`bitvec`]: https://docs.rs/bitvec
+
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+use {Direction, Overlap};
+
+/// Finds the index at which two bit slices overlap.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+///
+/// Candidate overlaps are compared as whole bit slices. For the [`Lsb0`] and [`Msb0`] orderings,
+/// `bitvec` compares these a word at a time, loading across element bounds where the bits are
+/// not aligned.
+///
+/// [`Lsb0`]: bitvec::order::Lsb0
+/// [`Msb0`]: bitvec::order::Msb0
+#[inline]
+fn overlap_index<T, O>(left: &BitSlice<T, O>, right: &BitSlice<T, O>) -> usize
+where
+    T: BitStore,
+    O: BitOrder,
+{
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    (left.len().saturating_sub(right.len())..left.len())
+        .find(|&index| left[index..] == right[..(left.len() - index)])
+        .unwrap_or(left.len())
+}
+
+/// Overlap methods for bit slices.
+///
+/// Lengths are measured in bits. This implementation is only available when the `bitvec` feature
+/// is enabled.
+impl<T, O> Overlap for BitSlice<T, O>
+where
+    T: BitStore,
+    O: BitOrder,
+{
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        &self[..self.overlap_start_len(other)]
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        &self[overlap_index(self, other)..]
+    }
+
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        other.len() - overlap_index(other, self)
+    }
+
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.len() - overlap_index(self, other)
+    }
+
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        overlap_index(other, self) != other.len()
+    }
+
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        overlap_index(self, other) != self.len()
+    }
+
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        overlap_index(other, self) == other.len()
+    }
+
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        overlap_index(self, other) == self.len()
+    }
+
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        let overlap = self.overlap_start(other);
+        if overlap.is_empty() {
+            None
+        } else {
+            Some(overlap)
+        }
+    }
+
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        let overlap = self.overlap_end(other);
+        if overlap.is_empty() {
+            None
+        } else {
+            Some(overlap)
+        }
+    }
+
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() < min_len {
+            &overlap[..0]
+        } else {
+            overlap
+        }
+    }
+
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() < min_len {
+            &overlap[overlap.len()..]
+        } else {
+            overlap
+        }
+    }
+
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() <= max_len {
+            overlap
+        } else {
+            &overlap[..max_len]
+        }
+    }
+
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() <= max_len {
+            overlap
+        } else {
+            &overlap[(overlap.len() - max_len)..]
+        }
+    }
+
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        &self[self.overlap_start_len(other)..]
+    }
+
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        &self[..overlap_index(self, other)]
+    }
+
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        self.split_at(self.overlap_start_len(other))
+    }
+
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        self.split_at(overlap_index(self, other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::order::{Lsb0, Msb0};
+    use bitvec::slice::BitSlice;
+    use bitvec::view::BitView;
+    use Overlap;
+
+    /// Bytes whose bits contain many repeated, unaligned patterns.
+    const BYTES: [u8; 6] = [0b1011_0010, 0b0110_1101, 0b1100_1011, 0x5A, 0xA5, 0x3C];
+
+    /// Finds the overlap length by comparing one bit at a time.
+    fn naive_overlap_end_len<T, O>(left: &BitSlice<T, O>, right: &BitSlice<T, O>) -> usize
+    where
+        T: ::bitvec::store::BitStore,
+        O: ::bitvec::order::BitOrder,
+    {
+        (0..(left.len().min(right.len()) + 1))
+            .rev()
+            .find(|&len| (0..len).all(|i| left[left.len() - len + i] == right[i]))
+            .unwrap()
+    }
+
+    #[test]
+    fn partial_overlap() {
+        let left = [0b0000_0110u8];
+        let right = [0b0000_0011u8];
+        let left = &left.view_bits::<Lsb0>()[..3];
+        let right = &right.view_bits::<Lsb0>()[..3];
+
+        // `left` is `011` and `right` is `110`.
+        assert_eq!(left.overlap_end(right), right[..2]);
+        assert_eq!(left.overlap_end_len(right), 2);
+        assert_eq!(right.overlap_start_len(left), 2);
+    }
+
+    #[test]
+    fn no_overlap() {
+        let zeros = [0u8];
+        let ones = [0xFFu8];
+
+        assert!(zeros
+            .view_bits::<Msb0>()
+            .try_overlap_end(ones.view_bits::<Msb0>())
+            .is_none());
+        assert!(!zeros
+            .view_bits::<Msb0>()
+            .has_overlap_start(ones.view_bits::<Msb0>()));
+    }
+
+    #[test]
+    fn overlap_not_multiple_of_eight_lsb0() {
+        let bits = BYTES.view_bits::<Lsb0>();
+        let left = &bits[..21];
+        let right = &bits[8..40];
+
+        // `left[8..21]` is a prefix of `right`, which is 13 bits long.
+        assert!(left.overlap_end_len(right) >= 13);
+        assert_eq!(
+            left.overlap_end_len(right),
+            naive_overlap_end_len(left, right)
+        );
+    }
+
+    #[test]
+    fn overlap_not_multiple_of_eight_msb0() {
+        let bits = BYTES.view_bits::<Msb0>();
+        let left = &bits[3..19];
+        let right = &bits[12..45];
+
+        assert!(left.overlap_end_len(right) >= 7);
+        assert_eq!(
+            left.overlap_end_len(right),
+            naive_overlap_end_len(left, right)
+        );
+    }
+
+    #[test]
+    fn unaligned_matches_naive() {
+        let lsb0 = BYTES.view_bits::<Lsb0>();
+        let msb0 = BYTES.view_bits::<Msb0>();
+        for start in 0..9 {
+            for split in (start + 1)..lsb0.len() {
+                for end in (split..lsb0.len()).step_by(5) {
+                    let (left, right) = (&lsb0[start..end], &lsb0[split..]);
+                    assert_eq!(
+                        left.overlap_end_len(right),
+                        naive_overlap_end_len(left, right)
+                    );
+
+                    let (left, right) = (&msb0[start..end], &msb0[split..]);
+                    assert_eq!(
+                        left.overlap_end_len(right),
+                        naive_overlap_end_len(left, right)
+                    );
+                    assert_eq!(
+                        right.overlap_start_len(left),
+                        naive_overlap_end_len(left, right)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wide_storage() {
+        let words = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210];
+        let bits = words.view_bits::<Msb0>();
+        let left = &bits[5..100];
+        let right = &bits[37..];
+
+        assert_eq!(left.overlap_end(right), bits[37..100]);
+        assert_eq!(left.overlap_end_max(right, 10), bits[90..100]);
+        assert_eq!(right.overlap_start_min(left, 63), bits[37..100]);
+        assert!(right.overlap_start_min(left, 64).is_empty());
+    }
+}
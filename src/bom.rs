@@ -0,0 +1,129 @@
+//! Overlaps that tolerate a UTF-8 byte order mark at the seam.
+//!
+//! Text exported by some tools begins with a byte order mark (BOM), U+FEFF, which is encoded in
+//! UTF-8 as the bytes `EF BB BF`. When such text is joined after a previous chunk, the BOM sits
+//! between the overlapping parts and prevents any overlap from being found.
+
+use slice_overlap_index;
+
+/// The UTF-8 encoding of U+FEFF BYTE ORDER MARK.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Finds the overlap of `left` and `right`, ignoring a BOM at the end of `left` and at the start
+/// of `right`.
+///
+/// Returns the index at which the overlap begins in `left` and the index at which it ends in
+/// `right`. A nonempty overlap includes any ignored BOM of either slice, so that it always ends at
+/// the end of `left` and begins at the start of `right`. If nothing other than the BOMs overlaps,
+/// `(left.len(), 0)` is returned.
+///
+/// If both `left` and `right` are valid UTF-8, both indices are on character bounds, for the same
+/// reasons described on [`overlap_index`](::overlap_index).
+#[inline]
+#[must_use]
+pub fn bom_overlap_indices(left: &[u8], right: &[u8]) -> (usize, usize) {
+    let compared_left = if left.ends_with(BOM) {
+        &left[..(left.len() - BOM.len())]
+    } else {
+        left
+    };
+    let right_offset = if right.starts_with(BOM) { BOM.len() } else { 0 };
+    let compared_right = &right[right_offset..];
+    let index = slice_overlap_index(compared_left, compared_right);
+    if index == compared_left.len() {
+        (left.len(), 0)
+    } else {
+        (index, right_offset + compared_left.len() - index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bom_overlap_indices;
+    use {ByteSliceOverlap, Overlap, StrOverlap};
+
+    #[test]
+    fn leading_bom_on_other() {
+        assert_eq!("abc".overlap_end("\u{feff}bcd"), "");
+        assert_eq!("abc".overlap_end_ignore_bom("\u{feff}bcd"), "bc");
+        assert_eq!("\u{feff}bcd".overlap_start_ignore_bom("abc"), "\u{feff}bc");
+    }
+
+    #[test]
+    fn trailing_bom_on_self() {
+        assert_eq!("abc\u{feff}".overlap_end_ignore_bom("bcd"), "bc\u{feff}");
+        assert_eq!("bcd".overlap_start_ignore_bom("abc\u{feff}"), "bc");
+    }
+
+    #[test]
+    fn bom_on_both_sides() {
+        assert_eq!(
+            "abc\u{feff}".overlap_end_ignore_bom("\u{feff}bcd"),
+            "bc\u{feff}"
+        );
+        assert_eq!(
+            "\u{feff}bcd".overlap_start_ignore_bom("abc\u{feff}"),
+            "\u{feff}bc"
+        );
+        // A BOM at the start of `self` is not at the seam, so is compared as usual.
+        assert_eq!("\u{feff}abc".overlap_end_ignore_bom("\u{feff}abcd"), "abc");
+        assert_eq!(
+            "\u{feff}abc".overlap_end_ignore_bom("\u{feff}\u{feff}abc"),
+            "\u{feff}abc"
+        );
+    }
+
+    #[test]
+    fn bom_only() {
+        // BOMs alone are never an overlap.
+        assert_eq!("\u{feff}".overlap_end_ignore_bom("\u{feff}"), "");
+        assert_eq!("abc".overlap_end_ignore_bom("\u{feff}"), "");
+        assert_eq!("\u{feff}".overlap_end_ignore_bom("abc"), "");
+        assert_eq!("\u{feff}".overlap_start_ignore_bom("\u{feff}"), "");
+        assert_eq!(
+            bom_overlap_indices(b"\xEF\xBB\xBF", b"\xEF\xBB\xBF"),
+            (3, 0)
+        );
+    }
+
+    #[test]
+    fn no_bom_matches_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("a語b語", "語b語c"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(left.overlap_end_ignore_bom(right), left.overlap_end(right));
+            assert_eq!(
+                left.overlap_start_ignore_bom(right),
+                left.overlap_start(right)
+            );
+        }
+    }
+
+    #[test]
+    fn byte_slices() {
+        assert_eq!(
+            b"abc\xEF\xBB\xBF".overlap_end_ignore_bom(b"\xEF\xBB\xBFbcd"),
+            b"bc\xEF\xBB\xBF"
+        );
+        assert_eq!(
+            b"\xEF\xBB\xBFbcd".overlap_start_ignore_bom(b"abc"),
+            b"\xEF\xBB\xBFbc"
+        );
+    }
+
+    #[test]
+    fn utf16_bom_bytes_compared_as_is() {
+        // Only the UTF-8 encoding of a BOM is ignored. UTF-16 BOMs are compared like any other
+        // bytes.
+        assert_eq!(b"abc".overlap_end_ignore_bom(b"\xFF\xFEbcd"), b"");
+        assert_eq!(b"abc".overlap_end_ignore_bom(b"\xFE\xFFbcd"), b"");
+        assert_eq!(
+            b"ab\xFF\xFE".overlap_end_ignore_bom(b"\xFF\xFEcd"),
+            b"\xFF\xFE"
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! Additional overlap methods for byte slices.
+
+use bom::bom_overlap_indices;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+#[cfg(feature = "alloc")]
+use percent::percent_overlap_indices;
+use {eq_ignore_ascii_case, slice_overlap_index_by};
+
+/// Provides additional methods for finding overlaps between byte slices.
+///
+/// These complement the [`Overlap`](::Overlap) implementation on slices with comparison modes
+/// that are specific to bytes. This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::ByteSliceOverlap;
+/// ```
+pub trait ByteSliceOverlap {
+    /// Returns the overlap found at the start of `self` and the end of `other`, ignoring ASCII
+    /// case.
+    ///
+    /// ASCII letters are compared case-insensitively, while all other bytes must match exactly.
+    /// The returned slice is a reference to the subslice contained in `self`, and therefore keeps
+    /// the casing of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"BCD".overlap_start_ignore_ascii_case(b"abc"), b"BC");
+    /// ```
+    #[must_use]
+    fn overlap_start_ignore_ascii_case(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, ignoring ASCII
+    /// case.
+    ///
+    /// ASCII letters are compared case-insensitively, while all other bytes must match exactly.
+    /// The returned slice is a reference to the subslice contained in `self`, and therefore keeps
+    /// the casing of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"ABC".overlap_end_ignore_ascii_case(b"bcd"), b"BC");
+    /// // Bytes outside of the ASCII range must match exactly.
+    /// assert_eq!(b"a\xC4".overlap_end_ignore_ascii_case(b"\xE4b"), b"");
+    /// ```
+    #[must_use]
+    fn overlap_end_ignore_ascii_case(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, comparing bytes
+    /// using `eq`.
+    ///
+    /// This is the same as [`overlap_end_bytes_by`] with `self` and `other` swapped, except that
+    /// the returned slice is a reference to the subslice contained in `self`. `eq` is still called
+    /// with a byte of `self` before a byte of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// // Compare only the low 7 bits of each byte.
+    /// let eq = |a: u8, b: u8| (a ^ b) & 0x7F == 0;
+    ///
+    /// assert_eq!(b"\xE2cd".overlap_start_bytes_by(b"abc", eq), b"\xE2c");
+    /// ```
+    ///
+    /// [`overlap_end_bytes_by`]: ByteSliceOverlap::overlap_end_bytes_by
+    #[must_use]
+    fn overlap_start_bytes_by<F>(&self, other: &Self, eq: F) -> &Self
+    where
+        F: FnMut(u8, u8) -> bool;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, comparing bytes
+    /// using `eq`.
+    ///
+    /// Every candidate overlap is compared directly, so `eq` need not be an equivalence relation.
+    /// For example, it may treat some byte as a wildcard matching any other.
+    ///
+    /// `eq` is called in a deterministic order. It is only called with a byte of `self` and a byte
+    /// of `other`, in that order, comparing each candidate overlap from front to back, longest
+    /// first, until a byte is found unequal.
+    ///
+    /// The returned slice is a reference to the subslice contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// // Compare only the low 7 bits of each byte.
+    /// let eq = |a: u8, b: u8| (a ^ b) & 0x7F == 0;
+    ///
+    /// assert_eq!(b"ab\xE3".overlap_end_bytes_by(b"bcd", eq), b"b\xE3");
+    /// ```
+    #[must_use]
+    fn overlap_end_bytes_by<F>(&self, other: &Self, eq: F) -> &Self
+    where
+        F: FnMut(u8, u8) -> bool;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, ignoring a UTF-8
+    /// byte order mark at the start of `self` and at the end of `other`.
+    ///
+    /// Text exported by some tools begins with a UTF-8 byte order mark (BOM), the bytes
+    /// `EF BB BF`, which would otherwise prevent the text from overlapping with whatever it is
+    /// joined after. A BOM is ignored at the end of the slice that comes first and at the start
+    /// of the slice that comes second, but is compared as usual anywhere else. Other byte order
+    /// marks, such as those of UTF-16, are always compared as usual. The returned slice is a
+    /// reference to the subslice contained in `self`, and includes its ignored BOM unless it is
+    /// empty. BOMs alone are never an overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"\xEF\xBB\xBFbcd".overlap_start_ignore_bom(b"abc"), b"\xEF\xBB\xBFbc");
+    /// ```
+    #[must_use]
+    fn overlap_start_ignore_bom(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, ignoring a UTF-8
+    /// byte order mark at the end of `self` and at the start of `other`.
+    ///
+    /// Text exported by some tools begins with a UTF-8 byte order mark (BOM), the bytes
+    /// `EF BB BF`, which would otherwise prevent the text from overlapping with whatever it is
+    /// joined after. A BOM is ignored at the end of the slice that comes first and at the start
+    /// of the slice that comes second, but is compared as usual anywhere else. Other byte order
+    /// marks, such as those of UTF-16, are always compared as usual. The returned slice is a
+    /// reference to the subslice contained in `self`, and includes its ignored BOM unless it is
+    /// empty. BOMs alone are never an overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"abc".overlap_end_ignore_bom(b"\xEF\xBB\xBFbcd"), b"bc");
+    /// ```
+    #[must_use]
+    fn overlap_end_ignore_bom(&self, other: &Self) -> &Self;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when each `%XY` escape is treated as equal to the byte it encodes, within `self` and within
+    /// `other` respectively.
+    ///
+    /// An escape is decoded when it is followed by two hex digits, in either case. Any other
+    /// `'%'`, including one whose escape is cut off at the edge of a slice, is compared literally.
+    /// Escapes are matched whole, so neither range ever begins or ends within one, and the ranges
+    /// may have different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"%FF/b".overlap_start_percent_decoded_indices(b"a\xFF"), (0..3, 1..2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when each `%XY` escape is treated as equal to the byte it encodes, within `self` and within
+    /// `other` respectively.
+    ///
+    /// An escape is decoded when it is followed by two hex digits, in either case. Any other
+    /// `'%'`, including one whose escape is cut off at the edge of a slice, is compared literally.
+    /// Escapes are matched whole, so neither range ever begins or ends within one, and the ranges
+    /// may have different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::ByteSliceOverlap;
+    ///
+    /// assert_eq!(b"a/b\xFF".overlap_end_percent_decoded_indices(b"b%ffc"), (2..4, 0..4));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+}
+
+impl ByteSliceOverlap for [u8] {
+    #[inline]
+    fn overlap_start_ignore_ascii_case(&self, other: &Self) -> &Self {
+        &self[..(other.len()
+            - slice_overlap_index_by(other, self, |&a, &b| eq_ignore_ascii_case(a, b)))]
+    }
+
+    #[inline]
+    fn overlap_end_ignore_ascii_case(&self, other: &Self) -> &Self {
+        &self[slice_overlap_index_by(self, other, |&a, &b| eq_ignore_ascii_case(a, b))..]
+    }
+
+    #[inline]
+    fn overlap_start_bytes_by<F>(&self, other: &Self, mut eq: F) -> &Self
+    where
+        F: FnMut(u8, u8) -> bool,
+    {
+        &self[..(other.len() - slice_overlap_index_by(other, self, |&a, &b| eq(b, a)))]
+    }
+
+    #[inline]
+    fn overlap_end_bytes_by<F>(&self, other: &Self, mut eq: F) -> &Self
+    where
+        F: FnMut(u8, u8) -> bool,
+    {
+        &self[slice_overlap_index_by(self, other, |&a, &b| eq(a, b))..]
+    }
+
+    #[inline]
+    fn overlap_start_ignore_bom(&self, other: &Self) -> &Self {
+        &self[..bom_overlap_indices(other, self).1]
+    }
+
+    #[inline]
+    fn overlap_end_ignore_bom(&self, other: &Self) -> &Self {
+        &self[bom_overlap_indices(self, other).0..]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = percent_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        percent_overlap_indices(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteSliceOverlap;
+    use Overlap;
+
+    #[test]
+    fn ignore_ascii_case_start() {
+        assert_eq!(b"BCD".overlap_start_ignore_ascii_case(b"abc"), b"BC");
+    }
+
+    #[test]
+    fn ignore_ascii_case_end() {
+        assert_eq!(b"ABC".overlap_end_ignore_ascii_case(b"bcd"), b"BC");
+    }
+
+    #[test]
+    fn ignore_ascii_case_mixed_case() {
+        assert_eq!(
+            b"Foo.BAR".overlap_end_ignore_ascii_case(b"foo.bar.baz"),
+            b"Foo.BAR"
+        );
+        assert_eq!(
+            b"foo.bar.baz".overlap_start_ignore_ascii_case(b"Foo.BAR"),
+            b"foo.bar"
+        );
+    }
+
+    #[test]
+    fn ignore_ascii_case_no_overlap() {
+        assert_eq!(b"abc".overlap_end_ignore_ascii_case(b"DEF"), b"");
+        assert_eq!(b"abc".overlap_start_ignore_ascii_case(b"DEF"), b"");
+        assert_eq!(b"".overlap_end_ignore_ascii_case(b""), b"");
+    }
+
+    #[test]
+    fn ignore_ascii_case_only_letters() {
+        // `@` and `` ` `` differ from `A` and `a` only by the case bit, but are not letters.
+        assert_eq!(b"a@".overlap_end_ignore_ascii_case(b"`b"), b"");
+        assert_eq!(b"a[".overlap_end_ignore_ascii_case(b"{b"), b"");
+    }
+
+    #[test]
+    fn ignore_ascii_case_non_ascii_exact() {
+        // These bytes also differ only by the case bit.
+        assert_eq!(b"a\xC4".overlap_end_ignore_ascii_case(b"\xE4b"), b"");
+        assert_eq!(b"a\xC4".overlap_end_ignore_ascii_case(b"\xC4b"), b"\xC4");
+        assert_eq!(b"\xFFA".overlap_end_ignore_ascii_case(b"\xFFab"), b"\xFFA");
+    }
+
+    #[test]
+    fn bytes_by_mask() {
+        let eq = |a: u8, b: u8| (a ^ b) & 0x7F == 0;
+
+        assert_eq!(b"ab\xE3".overlap_end_bytes_by(b"bcd", eq), b"b\xE3");
+        assert_eq!(b"\xE2cd".overlap_start_bytes_by(b"abc", eq), b"\xE2c");
+        assert_eq!(b"ab\xE3".overlap_end(b"bcd"), b"");
+    }
+
+    #[test]
+    fn bytes_by_exact_matches_overlap() {
+        let pairs: [(&[u8], &[u8]); 4] = [
+            (b"abc", b"bcd"),
+            (b"abcab", b"abcabd"),
+            (b"abc", b"def"),
+            (b"", b"abc"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.overlap_end_bytes_by(right, |a, b| a == b),
+                left.overlap_end(right)
+            );
+            assert_eq!(
+                left.overlap_start_bytes_by(right, |a, b| a == b),
+                left.overlap_start(right)
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_by_call_order() {
+        let mut calls = [(0, 0); 3];
+        let mut count = 0;
+
+        assert_eq!(
+            b"abc".overlap_end_bytes_by(b"bcd", |a, b| {
+                calls[count] = (a, b);
+                count += 1;
+                a == b
+            }),
+            b"bc"
+        );
+        assert_eq!(count, 3);
+        assert_eq!(calls, [(b'a', b'b'), (b'b', b'b'), (b'c', b'c')]);
+    }
+
+    #[test]
+    fn bytes_by_wildcard() {
+        // `0xFF` matches any byte, so this is not an equivalence relation.
+        let wild = |a: u8, b: u8| a == 0xFF || b == 0xFF || a == b;
+
+        assert_eq!(b"bbbaa".overlap_end_bytes_by(b"b\xFFbbb", wild), b"");
+        assert_eq!(b"bab".overlap_end_bytes_by(b"b\xFFa", wild), b"b");
+        assert_eq!(b"abaa".overlap_end_bytes_by(b"a\xFF\xFFb", wild), b"aa");
+        assert_eq!(b"b\xFFa".overlap_start_bytes_by(b"bab", wild), b"b");
+    }
+
+    #[test]
+    fn bytes_by_matches_brute_force() {
+        // `0xFF` matches any byte, so this is not an equivalence relation.
+        let wild = |a: u8, b: u8| a == 0xFF || b == 0xFF || a == b;
+        // Every slice of up to 4 bytes over the alphabet `a`, `b`, and the wildcard.
+        let alphabet = [b'a', b'b', 0xFF];
+        let mut slices = [[0; 4]; 121];
+        let mut lens = [0; 121];
+        let mut count = 0;
+        for len in 0..5 {
+            let mut combinations = 1;
+            for _ in 0..len {
+                combinations *= alphabet.len();
+            }
+            for mut combination in 0..combinations {
+                for byte in slices[count].iter_mut().take(len) {
+                    *byte = alphabet[combination % alphabet.len()];
+                    combination /= alphabet.len();
+                }
+                lens[count] = len;
+                count += 1;
+            }
+        }
+
+        for (left, &left_len) in slices.iter().zip(&lens) {
+            let left = &left[..left_len];
+            for (right, &right_len) in slices.iter().zip(&lens) {
+                let right = &right[..right_len];
+                let expected = (0..(left.len() + 1))
+                    .rev()
+                    .filter(|&len| len <= right.len())
+                    .find(|&len| {
+                        left[(left.len() - len)..]
+                            .iter()
+                            .zip(&right[..len])
+                            .all(|(&a, &b)| wild(a, b))
+                    })
+                    .unwrap();
+                assert_eq!(left.overlap_end_bytes_by(right, wild).len(), expected);
+                assert_eq!(right.overlap_start_bytes_by(left, wild).len(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn returns_subslice_of_self() {
+        let bytes = b"xABC";
+        let overlap = bytes.overlap_end_ignore_ascii_case(b"abcd");
+
+        assert_eq!(overlap.as_ptr(), bytes[1..].as_ptr());
+    }
+}
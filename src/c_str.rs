@@ -0,0 +1,164 @@
+//! Overlap support for C strings.
+//!
+//! A [`CStr`] is always terminated by a NUL byte, which complicates returning an overlap. A suffix
+//! of a `CStr` keeps its terminator, and is therefore a valid `CStr` itself. A prefix, however,
+//! does not include the terminator, so it can only be returned as a byte slice.
+//!
+//! The terminating NUL byte is never considered part of an overlap.
+
+use std::ffi::CStr;
+use Overlap;
+
+/// Converts a suffix of the bytes backing a [`CStr`], including its terminating NUL byte, back
+/// into a [`CStr`].
+#[cfg(not(feature = "safe"))]
+#[inline]
+fn from_bytes_with_nul(bytes: &[u8]) -> &CStr {
+    unsafe {
+        // SAFETY: The slice is a suffix of a `CStr`'s bytes including the terminating NUL byte. It
+        // therefore ends with exactly one NUL byte and contains no interior NUL bytes.
+        CStr::from_bytes_with_nul_unchecked(bytes)
+    }
+}
+
+/// Converts a suffix of the bytes backing a [`CStr`], including its terminating NUL byte, back
+/// into a [`CStr`].
+#[cfg(feature = "safe")]
+#[inline]
+fn from_bytes_with_nul(bytes: &[u8]) -> &CStr {
+    // `bytes` is a suffix of a `CStr`'s bytes including its terminator, so this can never fail.
+    CStr::from_bytes_with_nul(bytes).unwrap()
+}
+
+/// Provides methods for finding overlaps between C strings.
+///
+/// Overlaps are found by comparing the bytes returned by [`CStr::to_bytes()`], which exclude the
+/// terminating NUL byte. This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::CStrOverlap;
+/// ```
+///
+/// This trait is only available when the `std` feature is enabled.
+pub trait CStrOverlap {
+    /// Returns the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// Since the overlap is a prefix of `self`, it is not NUL-terminated, and is therefore
+    /// returned as a byte slice. The terminating NUL byte of `self` is never included.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::CStr;
+    /// use str_overlap::CStrOverlap;
+    ///
+    /// let c_str = CStr::from_bytes_with_nul(b"bcd\0").unwrap();
+    /// let other = CStr::from_bytes_with_nul(b"abc\0").unwrap();
+    ///
+    /// assert_eq!(c_str.overlap_start(other), b"bc");
+    /// ```
+    #[must_use]
+    fn overlap_start(&self, other: &Self) -> &[u8];
+
+    /// Returns the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// Since the overlap is a suffix of `self`, it is returned as a `CStr` that shares the
+    /// terminating NUL byte of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::CStr;
+    /// use str_overlap::CStrOverlap;
+    ///
+    /// let c_str = CStr::from_bytes_with_nul(b"abc\0").unwrap();
+    /// let other = CStr::from_bytes_with_nul(b"bcd\0").unwrap();
+    ///
+    /// assert_eq!(c_str.overlap_end(other).to_bytes_with_nul(), b"bc\0");
+    /// ```
+    #[must_use]
+    fn overlap_end(&self, other: &Self) -> &Self;
+}
+
+impl CStrOverlap for CStr {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &[u8] {
+        self.to_bytes().overlap_start(other.to_bytes())
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        let bytes = self.to_bytes_with_nul();
+        let overlap_len = self.to_bytes().overlap_end_len(other.to_bytes());
+        from_bytes_with_nul(&bytes[(bytes.len() - 1 - overlap_len)..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CStrOverlap;
+    use std::ffi::{CStr, CString};
+
+    fn c_str(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(c_str(b"bcd\0").overlap_start(c_str(b"abc\0")), b"bc");
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(c_str(b"abc\0").overlap_end(c_str(b"bcd\0")), c_str(b"bc\0"));
+    }
+
+    #[test]
+    fn full_overlap_start() {
+        assert_eq!(c_str(b"abc\0").overlap_start(c_str(b"abc\0")), b"abc");
+    }
+
+    #[test]
+    fn full_overlap_end() {
+        assert_eq!(
+            c_str(b"abc\0").overlap_end(c_str(b"abc\0")),
+            c_str(b"abc\0")
+        );
+    }
+
+    #[test]
+    fn no_overlap_start() {
+        assert_eq!(c_str(b"abc\0").overlap_start(c_str(b"def\0")), b"");
+    }
+
+    #[test]
+    fn no_overlap_end() {
+        assert_eq!(c_str(b"abc\0").overlap_end(c_str(b"def\0")), c_str(b"\0"));
+    }
+
+    #[test]
+    fn all_empty_start() {
+        assert_eq!(c_str(b"\0").overlap_start(c_str(b"\0")), b"");
+    }
+
+    #[test]
+    fn all_empty_end() {
+        assert_eq!(c_str(b"\0").overlap_end(c_str(b"\0")), c_str(b"\0"));
+    }
+
+    #[test]
+    fn overlap_end_shares_terminator() {
+        let c_str = c_str(b"abc\0");
+        let overlap = c_str.overlap_end(self::c_str(b"bcd\0"));
+
+        assert_eq!(overlap.to_bytes_with_nul(), b"bc\0");
+        assert_eq!(overlap.as_ptr(), c_str.to_bytes()[1..].as_ptr() as *const _);
+    }
+
+    #[test]
+    fn c_string() {
+        let c_string = CString::new("abc").unwrap();
+        let other = CString::new("bcd").unwrap();
+
+        assert_eq!(c_string.overlap_end(&other), c_str(b"bc\0"));
+        assert_eq!(other.overlap_start(&c_string), b"bc");
+    }
+}
@@ -0,0 +1,139 @@
+//! Overlap support under full Unicode case folding, using the [`caseless`] crate.
+//!
+//! Case folding can change the number of characters in a string, such as folding "ß" to "ss".
+//! Overlaps are therefore found between the folded strings, and only accepted where they begin and
+//! end on bounds of the original characters, so that they can be mapped back to ranges within the
+//! original strings.
+//!
+//! [`caseless`]: https://docs.rs/caseless
+
+use caseless::Caseless;
+use core::iter;
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Applies full case folding to `s`, one character at a time.
+#[inline]
+fn fold(s: &str) -> Mapped<char> {
+    let mut folded = Mapped::new(s.len());
+    for (index, c) in s.char_indices() {
+        let range = index..(index + c.len_utf8());
+        for folded_char in iter::once(c).default_case_fold() {
+            folded.push(folded_char, range.clone());
+        }
+    }
+    folded
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// under full case folding, within `left` and within `right` respectively.
+///
+/// The overlap is the longest one whose folded characters are equal and whose bounds lie on
+/// character bounds of both original strings, so that no character is only partially part of the
+/// overlap.
+#[inline]
+#[must_use]
+pub fn fold_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    let left_folded = fold(left);
+    let right_folded = fold(right);
+    let len = left_folded.units.len();
+    left_folded.overlap_indices_at_boundary(&right_folded, |index| {
+        left_folded.is_boundary(index) && right_folded.is_boundary(len - index)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use StrOverlap;
+
+    #[test]
+    fn ascii() {
+        assert_eq!("ABC".overlap_end_fold("bcd"), (1..3, 0..2));
+        assert_eq!("bcd".overlap_start_fold("ABC"), (0..2, 1..3));
+        assert_eq!("abc".overlap_end_fold("xyz"), (3..3, 0..0));
+    }
+
+    #[test]
+    fn sharp_s() {
+        // "ß" folds to "ss", so the overlap has different lengths in each string.
+        assert_eq!("STRASSE".overlap_end_fold("straße und"), (0..7, 0..7));
+        assert_eq!("an der straße".overlap_end_fold("STRASSE"), (7..14, 0..7));
+        assert_eq!("MASS".overlap_end_fold("ßen"), (2..4, 0..2));
+        assert_eq!("ßen".overlap_start_fold("MASS"), (0..2, 2..4));
+    }
+
+    #[test]
+    fn sharp_s_not_split() {
+        // A single "s" matches half of the folded "ß", which can't be returned as a range.
+        assert_eq!("MAS".overlap_end_fold("ßen"), (3..3, 0..0));
+        assert_eq!("xß".overlap_end_fold("sy"), (3..3, 0..0));
+        assert_eq!("xß".overlap_end_fold("SSy"), (1..3, 0..2));
+    }
+
+    #[test]
+    fn dotted_and_dotless_i() {
+        // "İ" folds to "i" followed by a combining dot above.
+        assert_eq!("Xİ".overlap_end_fold("i\u{307}y"), (1..3, 0..3));
+        assert_eq!("Xİ".overlap_end_fold("iy"), (3..3, 0..0));
+        // Default folding is not Turkic, so "I" folds to "i" and "ı" is unchanged.
+        assert_eq!("aI".overlap_end_fold("ib"), (1..2, 0..1));
+        assert_eq!("aı".overlap_end_fold("Ib"), (3..3, 0..0));
+        assert_eq!("aı".overlap_end_fold("ıb"), (1..3, 0..2));
+    }
+
+    #[test]
+    fn greek_final_sigma() {
+        // Both "Σ" and the final form "ς" fold to "σ".
+        assert_eq!("ΟΔΟΣ".overlap_end_fold("οδος και"), (0..8, 0..8));
+        assert_eq!("ὁδός".overlap_end_fold("ΌΣ"), (5..9, 0..4));
+        assert_eq!("λόγος".overlap_end_fold("σε"), (8..10, 0..2));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("".overlap_end_fold("abc"), (0..0, 0..0));
+        assert_eq!("abc".overlap_end_fold(""), (3..3, 0..0));
+        assert_eq!("".overlap_start_fold(""), (0..0, 0..0));
+    }
+
+    #[test]
+    fn folded_sharp_s() {
+        assert_eq!("STRASSE".overlap_end_folded("straße..."), "STRASSE");
+        assert_eq!("an der straße".overlap_end_folded("STRASSE"), "straße");
+        assert_eq!("straße...".overlap_start_folded("AN DER STRASSE"), "straße");
+    }
+
+    #[test]
+    fn folded_keeps_original_text() {
+        // The overlap is copied from `self`, so keeps its case rather than being folded.
+        assert_eq!("Hello WORLD".overlap_end_folded("world!"), "WORLD");
+        assert_eq!("ΟΔΟΣ".overlap_end_folded("οδος και"), "ΟΔΟΣ");
+    }
+
+    #[test]
+    fn folded_not_split() {
+        assert_eq!("MAS".overlap_end_folded("ßen"), "");
+        assert_eq!("xß".overlap_end_folded("sy"), "");
+        assert_eq!("xß".overlap_end_folded("SSy"), "ß");
+    }
+
+    #[test]
+    fn folded_matches_fold_ranges() {
+        let pairs = [
+            ("ABC", "bcd"),
+            ("MASS", "ßen"),
+            ("Xİ", "i\u{307}y"),
+            ("abc", "xyz"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.overlap_end_folded(right),
+                &left[left.overlap_end_fold(right).0]
+            );
+            assert_eq!(
+                left.overlap_start_folded(right),
+                &left[left.overlap_start_fold(right).0]
+            );
+        }
+    }
+}
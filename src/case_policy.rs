@@ -0,0 +1,275 @@
+//! Merging of strings whose overlap is found ignoring ASCII case.
+//!
+//! The overlapping text may be cased differently in each string, so a [`CasePolicy`] decides which
+//! casing is kept in the merged string.
+
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+use string_overlap_index_ignore_ascii_case;
+
+/// Which casing of the overlapping text is kept when merging two strings whose overlap was found
+/// ignoring ASCII case.
+///
+/// This is accepted by [`merge_overlap_ignore_ascii_case`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CasePolicy {
+    /// The overlap is taken from the left string.
+    PreferLeft,
+    /// The overlap is taken from the right string.
+    PreferRight,
+    /// The overlap must be cased the same in both strings, and a [`CaseConflict`] is returned if
+    /// it isn't.
+    ErrorOnConflict,
+}
+
+/// The error returned when merging with [`CasePolicy::ErrorOnConflict`] finds an overlap that is
+/// cased differently in each string.
+///
+/// It records the position of the first differing byte. Since ASCII case never changes the length
+/// of the text, that byte is at the same offset into the overlap in both strings.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CaseConflict {
+    left_index: usize,
+    right_index: usize,
+}
+
+impl CaseConflict {
+    /// Returns the byte index within the left string of the first differently cased byte.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{merge_overlap_ignore_ascii_case, CasePolicy};
+    ///
+    /// let conflict =
+    ///     merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::ErrorOnConflict)
+    ///         .unwrap_err();
+    ///
+    /// assert_eq!(conflict.left_index(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn left_index(&self) -> usize {
+        self.left_index
+    }
+
+    /// Returns the byte index within the right string of the first differently cased byte.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{merge_overlap_ignore_ascii_case, CasePolicy};
+    ///
+    /// let conflict =
+    ///     merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::ErrorOnConflict)
+    ///         .unwrap_err();
+    ///
+    /// assert_eq!(conflict.right_index(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn right_index(&self) -> usize {
+        self.right_index
+    }
+}
+
+impl fmt::Display for CaseConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "overlap is cased differently at byte {} of left and byte {} of right",
+            self.left_index, self.right_index
+        )
+    }
+}
+
+/// This implementation is only available when the `std` feature is enabled.
+#[cfg(feature = "std")]
+impl Error for CaseConflict {
+    fn description(&self) -> &str {
+        "overlap is cased differently"
+    }
+}
+
+/// Concatenates `left` and `right`, including the overlap at the end of `left` and the start of
+/// `right` only once, where the overlap is found ignoring ASCII case.
+///
+/// The overlap is found the same way as in [`overlap_end_ignore_ascii_case`], and `policy`
+/// decides which string's casing of it is kept. If there is no overlap, this is a plain
+/// concatenation, and `policy` has no effect.
+///
+/// This function is only available when the `alloc` feature is enabled.
+///
+/// # Errors
+/// If `policy` is [`CasePolicy::ErrorOnConflict`] and the overlap is cased differently in each
+/// string, a [`CaseConflict`] naming the first differing byte is returned. Any other policy never
+/// fails.
+///
+/// # Example
+/// ```
+/// use str_overlap::{merge_overlap_ignore_ascii_case, CasePolicy};
+///
+/// assert_eq!(
+///     merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::PreferLeft).unwrap(),
+///     "FooBar baz"
+/// );
+/// assert_eq!(
+///     merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::PreferRight).unwrap(),
+///     "Foobar baz"
+/// );
+/// assert!(
+///     merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::ErrorOnConflict)
+///         .is_err()
+/// );
+/// ```
+///
+/// [`overlap_end_ignore_ascii_case`]: ::StrOverlap::overlap_end_ignore_ascii_case
+pub fn merge_overlap_ignore_ascii_case(
+    left: &str,
+    right: &str,
+    policy: CasePolicy,
+) -> Result<String, CaseConflict> {
+    let index = string_overlap_index_ignore_ascii_case(left, right);
+    let len = left.len() - index;
+    let mut merged = String::with_capacity(index + right.len());
+    match policy {
+        CasePolicy::PreferLeft => {
+            merged.push_str(left);
+            merged.push_str(&right[len..]);
+        }
+        CasePolicy::PreferRight => {
+            merged.push_str(&left[..index]);
+            merged.push_str(right);
+        }
+        CasePolicy::ErrorOnConflict => {
+            let differing = left.as_bytes()[index..]
+                .iter()
+                .zip(&right.as_bytes()[..len])
+                .position(|(a, b)| a != b);
+            if let Some(offset) = differing {
+                return Err(CaseConflict {
+                    left_index: index + offset,
+                    right_index: offset,
+                });
+            }
+            merged.push_str(left);
+            merged.push_str(&right[len..]);
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_overlap_ignore_ascii_case, CaseConflict, CasePolicy};
+
+    #[test]
+    fn prefer_left() {
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::PreferLeft),
+            Ok("FooBar baz".into())
+        );
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("Content-TYPE", "type: text", CasePolicy::PreferLeft),
+            Ok("Content-TYPE: text".into())
+        );
+    }
+
+    #[test]
+    fn prefer_right() {
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::PreferRight),
+            Ok("Foobar baz".into())
+        );
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("Content-TYPE", "type: text", CasePolicy::PreferRight),
+            Ok("Content-type: text".into())
+        );
+    }
+
+    #[test]
+    fn error_on_conflict() {
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("FooBar", "bar baz", CasePolicy::ErrorOnConflict),
+            Err(CaseConflict {
+                left_index: 3,
+                right_index: 0,
+            })
+        );
+        // The first differing byte is reported, not the first byte of the overlap.
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("x HeLLo", "Hello y", CasePolicy::ErrorOnConflict),
+            Err(CaseConflict {
+                left_index: 4,
+                right_index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn error_on_conflict_same_case() {
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("FooBar", "Bar baz", CasePolicy::ErrorOnConflict),
+            Ok("FooBar baz".into())
+        );
+        // Non-letters are never in conflict.
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("a 1-2", "1-2 b", CasePolicy::ErrorOnConflict),
+            Ok("a 1-2 b".into())
+        );
+    }
+
+    #[test]
+    fn multi_byte_around_overlap() {
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("日本ABC", "abc語", CasePolicy::PreferRight),
+            Ok("日本abc語".into())
+        );
+        assert_eq!(
+            merge_overlap_ignore_ascii_case("日本ABC", "abC語", CasePolicy::ErrorOnConflict),
+            Err(CaseConflict {
+                left_index: 6,
+                right_index: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        for &policy in &[
+            CasePolicy::PreferLeft,
+            CasePolicy::PreferRight,
+            CasePolicy::ErrorOnConflict,
+        ] {
+            assert_eq!(
+                merge_overlap_ignore_ascii_case("abc", "DEF", policy),
+                Ok("abcDEF".into())
+            );
+            assert_eq!(
+                merge_overlap_ignore_ascii_case("", "", policy),
+                Ok("".into())
+            );
+        }
+    }
+
+    #[test]
+    fn conflict_display() {
+        use alloc::string::String;
+        use core::fmt::Write;
+
+        let mut message = String::new();
+        write!(
+            message,
+            "{}",
+            CaseConflict {
+                left_index: 3,
+                right_index: 0,
+            }
+        )
+        .unwrap();
+        assert_eq!(
+            message,
+            "overlap is cased differently at byte 3 of left and byte 0 of right"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! Overlaps of strings whose characters are compared by a user-provided closure.
+
+use core::ops::Range;
+
+/// The byte ranges of an overlap within both of the strings it was found in.
+///
+/// This struct is created by [`StrOverlap::overlap_start_by`] and [`StrOverlap::overlap_end_by`].
+/// Since the characters of each string are only compared as equal by a closure, the overlapping
+/// text may differ between the strings, and may even have a different length in each.
+///
+/// [`StrOverlap::overlap_start_by`]: ::StrOverlap::overlap_start_by
+/// [`StrOverlap::overlap_end_by`]: ::StrOverlap::overlap_end_by
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverlapRanges {
+    self_range: Range<usize>,
+    other_range: Range<usize>,
+}
+
+impl OverlapRanges {
+    /// Returns the byte range of the overlap within `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let s = "ABC";
+    /// let ranges = s.overlap_end_by("bcd", |a, b| {
+    ///     a.to_lowercase().next() == b.to_lowercase().next()
+    /// });
+    ///
+    /// assert_eq!(&s[ranges.self_range()], "BC");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn self_range(&self) -> Range<usize> {
+        self.self_range.clone()
+    }
+
+    /// Returns the byte range of the overlap within `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let other = "bcd";
+    /// let ranges = "ABC".overlap_end_by(other, |a, b| {
+    ///     a.to_lowercase().next() == b.to_lowercase().next()
+    /// });
+    ///
+    /// assert_eq!(&other[ranges.other_range()], "bc");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn other_range(&self) -> Range<usize> {
+        self.other_range.clone()
+    }
+
+    /// Returns whether the overlap is empty.
+    ///
+    /// The overlap is empty in both strings or in neither.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.self_range.start == self.self_range.end
+    }
+}
+
+/// Returns the byte index of the `n`th character of `s`, or `s.len()` if `s` has only `n`
+/// characters.
+#[inline]
+fn char_byte_index(s: &str, n: usize) -> usize {
+    s.char_indices().nth(n).map_or(s.len(), |(index, _)| index)
+}
+
+/// Finds the overlap of `left` and `right`, comparing their characters using `eq`.
+///
+/// `eq` is called with a character of `left` and a character of `right`, in that order.
+///
+/// Returns the byte index at which the overlap begins in `left`, and the byte index at which it
+/// ends in `right`. Since characters compared as equal may be encoded with different lengths, the
+/// overlap may have a different length in each string.
+///
+/// Every candidate overlap is compared separately, from longest to shortest, so `eq` need not be
+/// an equivalence relation. This takes quadratic time in the worst case.
+#[inline]
+pub fn char_overlap_by<F>(left: &str, right: &str, mut eq: F) -> (usize, usize)
+where
+    F: FnMut(char, char) -> bool,
+{
+    for (index, _) in left.char_indices() {
+        let mut right_chars = right.chars();
+        let mut len = 0;
+        if left[index..].chars().all(|c| {
+            len += 1;
+            match right_chars.next() {
+                Some(right_char) => eq(c, right_char),
+                None => false,
+            }
+        }) {
+            return (index, char_byte_index(right, len));
+        }
+    }
+    (left.len(), 0)
+}
+
+/// Returns the ranges of the overlap found at the start of `s` and the end of `other`, comparing
+/// characters using `eq`.
+///
+/// `eq` is called with a character of `s` and a character of `other`, in that order.
+#[inline]
+pub fn overlap_start_by<F>(s: &str, other: &str, mut eq: F) -> OverlapRanges
+where
+    F: FnMut(char, char) -> bool,
+{
+    let (other_start, self_end) = char_overlap_by(other, s, |a, b| eq(b, a));
+    OverlapRanges {
+        self_range: 0..self_end,
+        other_range: other_start..other.len(),
+    }
+}
+
+/// Returns the ranges of the overlap found at the end of `s` and the start of `other`, comparing
+/// characters using `eq`.
+///
+/// `eq` is called with a character of `s` and a character of `other`, in that order.
+#[inline]
+pub fn overlap_end_by<F>(s: &str, other: &str, eq: F) -> OverlapRanges
+where
+    F: FnMut(char, char) -> bool,
+{
+    let (self_start, other_end) = char_overlap_by(s, other, eq);
+    OverlapRanges {
+        self_range: self_start..s.len(),
+        other_range: 0..other_end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::char_overlap_by;
+    use {Overlap, StrOverlap};
+
+    /// Compares characters as equal if they are equal after reading both `'0'` and `'O'` as
+    /// `'O'`, as OCR output often confuses them.
+    fn ocr_eq(a: char, b: char) -> bool {
+        let fold = |c| if c == '0' { 'O' } else { c };
+        fold(a) == fold(b)
+    }
+
+    /// Compares characters as equal if their first lowercase characters are equal.
+    fn case_insensitive_eq(a: char, b: char) -> bool {
+        a.to_lowercase().next() == b.to_lowercase().next()
+    }
+
+    #[test]
+    fn ocr_confusion() {
+        let left = "HELL0 W";
+        let right = "HELLO WORLD";
+
+        assert_eq!(left.overlap_end(right), "");
+        let ranges = left.overlap_end_by(right, ocr_eq);
+        assert_eq!(&left[ranges.self_range()], "HELL0 W");
+        assert_eq!(&right[ranges.other_range()], "HELLO W");
+
+        let ranges = "0RLD".overlap_start_by("HELLO WO", ocr_eq);
+        assert_eq!(ranges.self_range(), 0..1);
+        assert_eq!(ranges.other_range(), 7..8);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let left = "Straße ÜBER";
+        let right = "über alles";
+
+        let ranges = left.overlap_end_by(right, case_insensitive_eq);
+        assert_eq!(&left[ranges.self_range()], "ÜBER");
+        assert_eq!(&right[ranges.other_range()], "über");
+        let ranges = right.overlap_start_by(left, case_insensitive_eq);
+        assert_eq!(&right[ranges.self_range()], "über");
+        assert_eq!(&left[ranges.other_range()], "ÜBER");
+    }
+
+    #[test]
+    fn exact_eq_matches_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("a語b語", "語b語c"),
+        ];
+        for &(left, right) in &pairs {
+            let ranges = left.overlap_end_by(right, |a, b| a == b);
+            assert_eq!(ranges.self_range(), left.overlap_end_indices(right).0);
+            assert_eq!(ranges.other_range(), left.overlap_end_indices(right).1);
+            let ranges = left.overlap_start_by(right, |a, b| a == b);
+            assert_eq!(ranges.self_range(), left.overlap_start_indices(right).0);
+            assert_eq!(ranges.other_range(), left.overlap_start_indices(right).1);
+            assert_eq!(
+                &left[left.overlap_end_by(right, |a, b| a == b).self_range()],
+                left.overlap_end(right)
+            );
+        }
+    }
+
+    #[test]
+    fn different_encoded_lengths() {
+        // "é" is two bytes, while "e" is one.
+        let fold = |c| if c == 'é' { 'e' } else { c };
+        let eq = |a, b| fold(a) == fold(b);
+
+        assert_eq!(char_overlap_by("café", "cafes", eq), (0, 4));
+        assert_eq!(char_overlap_by("cafe", "cafés", eq), (0, 5));
+        let ranges = "café".overlap_end_by("cafes", eq);
+        assert_eq!(ranges.self_range(), 0..5);
+        assert_eq!(ranges.other_range(), 0..4);
+        let ranges = "cafes".overlap_start_by("café", eq);
+        assert_eq!(ranges.self_range(), 0..4);
+        assert_eq!(ranges.other_range(), 0..5);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(char_overlap_by("abc", "def", |a, b| a == b), (3, 0));
+        assert_eq!(char_overlap_by("", "abc", |a, b| a == b), (0, 0));
+        assert_eq!(char_overlap_by("abc", "", |a, b| a == b), (3, 0));
+        let ranges = "abc".overlap_end_by("def", |a, b| a == b);
+        assert!(ranges.is_empty());
+        assert_eq!(ranges.self_range(), 3..3);
+        assert_eq!(ranges.other_range(), 0..0);
+        let ranges = "abc".overlap_start_by("def", |a, b| a == b);
+        assert!(ranges.is_empty());
+        assert_eq!(ranges.self_range(), 0..0);
+        assert_eq!(ranges.other_range(), 3..3);
+    }
+
+    #[test]
+    fn by_key_lowercase() {
+        let left = "Straße ÜBER";
+        let right = "über alles";
+
+        let ranges = left.overlap_end_by_key(right, |c| c.to_lowercase().next());
+        assert_eq!(&left[ranges.self_range()], "ÜBER");
+        assert_eq!(&right[ranges.other_range()], "über");
+        let ranges = right.overlap_start_by_key(left, |c| c.to_lowercase().next());
+        assert_eq!(&right[ranges.self_range()], "über");
+        assert_eq!(&left[ranges.other_range()], "ÜBER");
+    }
+
+    #[test]
+    fn by_key_digit_buckets() {
+        // Every digit is mapped to the same key, so any digits match.
+        let key = |c| if "0123456789".contains(c) { '#' } else { c };
+
+        let ranges = "id-1234".overlap_end_by_key("id-9876-x", key);
+        assert_eq!(ranges.self_range(), 0..7);
+        assert_eq!(ranges.other_range(), 0..7);
+        let ranges = "v12.3".overlap_end_by_key("9.4", key);
+        assert_eq!(ranges.self_range(), 2..5);
+        assert_eq!(ranges.other_range(), 0..3);
+        assert!("12a".overlap_end_by_key("b12", key).is_empty());
+    }
+
+    #[test]
+    fn by_key_transliteration_multi_byte() {
+        // Greek letters are transliterated to the Latin letters they are written as.
+        let key = |c| match c {
+            'α' => 'a',
+            'β' => 'b',
+            'γ' => 'g',
+            c => c,
+        };
+
+        let left = "xαβγ";
+        let right = "abgy";
+        let ranges = left.overlap_end_by_key(right, key);
+        assert_eq!(&left[ranges.self_range()], "αβγ");
+        assert_eq!(&right[ranges.other_range()], "abg");
+        let ranges = right.overlap_start_by_key(left, key);
+        assert_eq!(ranges.self_range(), 0..3);
+        assert_eq!(ranges.other_range(), 1..7);
+    }
+
+    #[test]
+    fn by_key_identity_matches_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("a語b語", "語b語c"),
+        ];
+        for &(left, right) in &pairs {
+            let ranges = left.overlap_end_by_key(right, |c| c);
+            assert_eq!(ranges.self_range(), left.overlap_end_indices(right).0);
+            assert_eq!(ranges.other_range(), left.overlap_end_indices(right).1);
+            let ranges = left.overlap_start_by_key(right, |c| c);
+            assert_eq!(ranges.self_range(), left.overlap_start_indices(right).0);
+            assert_eq!(ranges.other_range(), left.overlap_start_indices(right).1);
+        }
+    }
+
+    #[test]
+    fn wildcard() {
+        // `'?'` matches any character, so this is not an equivalence relation.
+        let wild = |a, b| a == '?' || b == '?' || a == b;
+
+        let ranges = "ab?bb".overlap_end_by("?a?aa", wild);
+        assert_eq!(ranges.self_range(), 4..5);
+        assert_eq!(ranges.other_range(), 0..1);
+        let ranges = "?a?aa".overlap_start_by("ab?bb", wild);
+        assert_eq!(ranges.self_range(), 0..1);
+        assert_eq!(ranges.other_range(), 4..5);
+        assert_eq!("bab".overlap_end_by("b?a", wild).self_range(), 2..3);
+    }
+
+    #[test]
+    fn always_eq() {
+        // Every suffix of `self` is equal to a prefix of a long enough `other`.
+        assert_eq!("abc".overlap_end_by("xyz!", |_, _| true).self_range(), 0..3);
+        assert_eq!("abc".overlap_end_by("xy", |_, _| true).self_range(), 1..3);
+    }
+}
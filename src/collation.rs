@@ -0,0 +1,128 @@
+//! Overlaps of strings that are compared using locale-aware collation.
+//!
+//! A [`CollatorBorrowed`] from [`icu_collator`] compares strings according to the collation rules
+//! of a locale, including its tailorings. Sequences of different lengths may collate as equal,
+//! such as "aa" and "å" in Danish, so candidate overlaps are compared as whole strings rather than
+//! character by character.
+//!
+//! [`CollatorBorrowed`]: icu_collator::CollatorBorrowed
+
+use core::cmp::Ordering;
+use core::ops::Range;
+use icu_collator::CollatorBorrowed;
+use is_char_boundary;
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when compared using `collator`, within `left` and within `right` respectively.
+///
+/// The longest suffix of `left` that collates as equal to some non-empty prefix of `right` is
+/// found, along with the longest such prefix. If nothing overlaps, the ranges are empty and
+/// located at the end of `left` and the start of `right`.
+///
+/// Every pair of candidates is compared, which takes *O(n·m)* comparisons in the worst case.
+#[inline]
+pub fn collated_overlap_indices(
+    left: &str,
+    right: &str,
+    collator: &CollatorBorrowed,
+) -> (Range<usize>, Range<usize>) {
+    for (start, _) in left.char_indices() {
+        let suffix = &left[start..];
+        if let Some(end) = (1..(right.len() + 1))
+            .rev()
+            .filter(|&end| is_char_boundary(right, end))
+            .find(|&end| collator.compare(suffix, &right[..end]) == Ordering::Equal)
+        {
+            return (start..left.len(), 0..end);
+        }
+    }
+    (left.len()..left.len(), 0..0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collated_overlap_indices;
+    use icu_collator::options::{CollatorOptions, Strength};
+    use icu_collator::{Collator, CollatorBorrowed};
+    use icu_locale_core::Locale;
+    use {Overlap, StrOverlap};
+
+    /// Returns a primary-strength collator for the locale `locale`.
+    fn primary(locale: &str) -> CollatorBorrowed<'static> {
+        let locale: Locale = locale.parse().unwrap();
+        let mut options = CollatorOptions::default();
+        options.strength = Some(Strength::Primary);
+        Collator::try_new(locale.into(), options).unwrap()
+    }
+
+    #[test]
+    fn danish_tailoring() {
+        let root = primary("und");
+        let danish = primary("da");
+
+        // Compared byte by byte, nothing overlaps.
+        assert_eq!("Haa".overlap_end("ålborg"), "");
+        // At primary strength, the root collation treats "å" as "a" with a diacritic.
+        assert_eq!(
+            "Haa".overlap_end_collated_indices("ålborg", &root),
+            (2..3, 0..2)
+        );
+        // Danish tailors "å" as a separate letter, which "aa" is equal to.
+        assert_eq!(
+            "Haa".overlap_end_collated_indices("ålborg", &danish),
+            (1..3, 0..2)
+        );
+        assert_eq!(
+            "ålborg".overlap_start_collated_indices("Haa", &danish),
+            (0..2, 1..3)
+        );
+    }
+
+    #[test]
+    fn ligature_expansion() {
+        let collator = primary("fr");
+
+        assert_eq!(
+            "des œ".overlap_end_collated_indices("OEuvres", &collator),
+            (4..6, 0..2)
+        );
+        assert_eq!(
+            "la coe".overlap_end_collated_indices("œur", &collator),
+            (4..6, 0..2)
+        );
+    }
+
+    #[test]
+    fn case_and_accents_ignored() {
+        let collator = primary("en");
+
+        assert_eq!(
+            "a CAFE".overlap_end_collated_indices("café au lait", &collator),
+            (2..6, 0..5)
+        );
+    }
+
+    #[test]
+    fn longest_prefix_of_other() {
+        // A trailing combining mark is ignored at primary strength, but is still included in the
+        // range within `other` so that it isn't split from its base character.
+        let collator = primary("en");
+
+        assert_eq!(
+            "xa".overlap_end_collated_indices("a\u{301}b", &collator),
+            (1..2, 0..3)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        let collator = primary("en");
+
+        assert_eq!(
+            collated_overlap_indices("abc", "def", &collator),
+            (3..3, 0..0)
+        );
+        assert_eq!(collated_overlap_indices("", "abc", &collator), (0..0, 0..0));
+        assert_eq!(collated_overlap_indices("abc", "", &collator), (3..3, 0..0));
+    }
+}
@@ -0,0 +1,114 @@
+//! Overlap functions usable in `const` contexts.
+//!
+//! These functions are evaluated at compile time when called within a `const` context, such as
+//! when defining a `const` or `static`. Since iterators and trait methods can't be called in a
+//! `const fn`, candidate overlaps are compared using plain index loops.
+//!
+//! Every candidate is compared directly, which is *O(n·m)* in the worst case. At runtime, the
+//! methods on [`Overlap`](::Overlap) should be preferred.
+
+/// Returns whether the suffix of `left` beginning at `index` is a prefix of `right`.
+///
+/// `right` must be at least as long as that suffix.
+#[inline]
+const fn is_overlap(left: &[u8], right: &[u8], index: usize) -> bool {
+    let mut i = 0;
+    while index + i < left.len() {
+        if left[index + i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the length in bytes of the overlap found at the end of `left` and the start of
+/// `right`.
+///
+/// This returns the same value as [`overlap_end_len`](::Overlap::overlap_end_len), but can be
+/// called in `const` contexts.
+///
+/// This function requires Rust 1.46.0 or newer.
+///
+/// # Example
+/// ```
+/// use str_overlap::overlap_end_len;
+///
+/// const LEN: usize = overlap_end_len("abc", "bcd");
+///
+/// assert_eq!(LEN, 2);
+/// ```
+#[must_use]
+pub const fn overlap_end_len(left: &str, right: &str) -> usize {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    let mut index = if left.len() > right.len() {
+        left.len() - right.len()
+    } else {
+        0
+    };
+    while index < left.len() {
+        // A non-empty overlap always begins on a character bound. See `overlap_index()`
+        // for details.
+        if is_overlap(left, right, index) {
+            return left.len() - index;
+        }
+        index += 1;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::overlap_end_len;
+    use Overlap;
+
+    #[test]
+    fn const_context() {
+        const LEN: usize = overlap_end_len("abc", "bcd");
+        static LENS: [usize; 2] = [overlap_end_len("ab", "b"), overlap_end_len("ab", "c")];
+
+        assert_eq!(LEN, 2);
+        assert_eq!(LENS, [1, 0]);
+    }
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!(overlap_end_len("abc", "bcd"), 2);
+    }
+
+    #[test]
+    fn full_overlap() {
+        assert_eq!(overlap_end_len("abc", "abc"), 3);
+        assert_eq!(overlap_end_len("bc", "bcd"), 2);
+        assert_eq!(overlap_end_len("abc", "bc"), 2);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(overlap_end_len("abc", "def"), 0);
+        assert_eq!(overlap_end_len("", "abc"), 0);
+        assert_eq!(overlap_end_len("abc", ""), 0);
+        assert_eq!(overlap_end_len("", ""), 0);
+    }
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(overlap_end_len("a語b語", "語b語c"), "語b語".len());
+        assert_eq!(overlap_end_len("語", "語語"), "語".len());
+    }
+
+    #[test]
+    fn matches_overlap_end_len() {
+        let strings = [
+            "", "a", "ab", "aba", "abab", "abaab", "baba", "aaaa", "xaaaa", "aaab", "語", "a語",
+            "語a", "語語",
+        ];
+        for left in &strings {
+            for right in &strings {
+                assert_eq!(overlap_end_len(left, right), left.overlap_end_len(right));
+            }
+        }
+    }
+}
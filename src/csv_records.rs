@@ -0,0 +1,205 @@
+//! Overlap support for CSV data, using the [`csv`] crate.
+//!
+//! Comparing CSV data line by line is unreliable, since a quoted field may contain newlines. The
+//! data is instead parsed into records, and overlaps are found between whole records.
+//!
+//! [`csv`]: https://docs.rs/csv
+
+use csv::{ByteRecord, ReaderBuilder, Writer};
+use std::io::{Read, Write};
+use std::vec::Vec;
+use Overlap;
+
+/// Parses every record of `reader`, including its header.
+#[inline]
+fn read_records<R>(reader: R) -> csv::Result<Vec<ByteRecord>>
+where
+    R: Read,
+{
+    ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(reader)
+        .into_byte_records()
+        .collect()
+}
+
+/// Writes the records of `first` followed by the records of `second`, omitting the records at the
+/// start of `second` that overlap with the end of `first`.
+///
+/// Both inputs are parsed as CSV with a header row. The header of `first` is written, and the
+/// header of `second` is skipped if it is equal to it. The largest number of overlapping records is
+/// then found by comparing whole records field by field, so quoted fields containing newlines or
+/// delimiters are compared by their contents. Every field is compared as raw bytes, so neither
+/// input needs to be valid UTF-8.
+///
+/// Records are written using the default [`Writer`] settings. The fields of every record are
+/// preserved exactly, but fields may be quoted differently than in the inputs.
+///
+/// Returns the number of records found in the overlap, not counting the header.
+///
+/// This function is only available when the `csv` and `std` features are enabled.
+///
+/// # Errors
+/// Returns an error if either input can't be read or parsed, or if the output can't be written.
+/// As with [`Writer`], records with differing numbers of fields are rejected.
+///
+/// # Example
+/// ```
+/// use str_overlap::stitch_csv;
+///
+/// let first = b"id,name\n1,a\n2,b\n3,c\n";
+/// let second = b"id,name\n2,b\n3,c\n4,d\n";
+/// let mut output = Vec::new();
+///
+/// assert_eq!(stitch_csv(&first[..], &second[..], &mut output).unwrap(), 2);
+/// assert_eq!(output, b"id,name\n1,a\n2,b\n3,c\n4,d\n");
+/// ```
+///
+/// [`Writer`]: csv::Writer
+pub fn stitch_csv<R, S, W>(first: R, second: S, output: W) -> csv::Result<usize>
+where
+    R: Read,
+    S: Read,
+    W: Write,
+{
+    let first = read_records(first)?;
+    let mut second = read_records(second)?;
+    if let (Some(first_header), Some(second_header)) = (first.first(), second.first()) {
+        if first_header == second_header {
+            second.remove(0);
+        }
+    }
+    // The header of `first` can never be part of an overlap.
+    let first_records = if first.is_empty() {
+        &first[..]
+    } else {
+        &first[1..]
+    };
+    let overlap_len = first_records.overlap_end_len(&second);
+
+    let mut writer = Writer::from_writer(output);
+    for record in first.iter().chain(&second[overlap_len..]) {
+        writer.write_byte_record(record)?;
+    }
+    writer.flush()?;
+    Ok(overlap_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stitch_csv;
+    use std::vec::Vec;
+
+    /// Stitches `first` and `second`, returning the overlap length and the output.
+    fn stitch(first: &[u8], second: &[u8]) -> (usize, Vec<u8>) {
+        let mut output = Vec::new();
+        let overlap_len = stitch_csv(first, second, &mut output).unwrap();
+        (overlap_len, output)
+    }
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!(
+            stitch(b"id,name\n1,a\n2,b\n", b"id,name\n2,b\n3,c\n"),
+            (1, b"id,name\n1,a\n2,b\n3,c\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(
+            stitch(b"id,name\n1,a\n", b"id,name\n2,b\n"),
+            (0, b"id,name\n1,a\n2,b\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn full_overlap() {
+        assert_eq!(
+            stitch(b"id,name\n1,a\n2,b\n", b"id,name\n1,a\n2,b\n"),
+            (2, b"id,name\n1,a\n2,b\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn header_is_not_part_of_overlap() {
+        // The second header is skipped, rather than overlapping with the first.
+        assert_eq!(
+            stitch(b"id,name\n", b"id,name\n1,a\n"),
+            (0, b"id,name\n1,a\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn second_without_header() {
+        assert_eq!(
+            stitch(b"id,name\n1,a\n2,b\n", b"2,b\n3,c\n"),
+            (1, b"id,name\n1,a\n2,b\n3,c\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn empty_inputs() {
+        assert_eq!(stitch(b"", b""), (0, Vec::new()));
+        assert_eq!(
+            stitch(b"", b"id,name\n1,a\n"),
+            (0, b"id,name\n1,a\n".to_vec())
+        );
+        assert_eq!(
+            stitch(b"id,name\n1,a\n", b""),
+            (0, b"id,name\n1,a\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn quoted_newlines() {
+        assert_eq!(
+            stitch(
+                b"id,note\n1,\"x\ny\"\n2,\"z\n\"\n",
+                b"id,note\n2,\"z\n\"\n3,w\n"
+            ),
+            (1, b"id,note\n1,\"x\ny\"\n2,\"z\n\"\n3,w\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn quoted_newlines_do_not_split_records() {
+        // Comparing lines, the last line of `first` would match the first record of `second`.
+        assert_eq!(
+            stitch(b"id,note\n1,\"x\n2,y\"\n", b"id,note\n2,y\n3,z\n"),
+            (0, b"id,note\n1,\"x\n2,y\"\n2,y\n3,z\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn fields_compared_by_contents() {
+        // Quoting is not significant, so these records are equal.
+        assert_eq!(
+            stitch(b"id,name\n1,\"a\"\n", b"id,name\n1,a\n2,b\n"),
+            (1, b"id,name\n1,a\n2,b\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn repeated_records() {
+        assert_eq!(
+            stitch(b"n\n1\n1\n1\n", b"n\n1\n1\n2\n"),
+            (2, b"n\n1\n1\n1\n2\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn non_utf8_fields() {
+        assert_eq!(
+            stitch(b"id,data\n1,\xFF\n", b"id,data\n1,\xFF\n2,\xFE\n"),
+            (1, b"id,data\n1,\xFF\n2,\xFE\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn mismatched_field_counts() {
+        let mut output = Vec::new();
+
+        assert!(stitch_csv(&b"a,b\n1,2\n"[..], &b"a,b,c\n3,4,5\n"[..], &mut output).is_err());
+    }
+}
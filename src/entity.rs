@@ -0,0 +1,183 @@
+//! Overlaps of strings that treat HTML character references as equal to the characters they
+//! stand for.
+//!
+//! Only a small set of named references is recognized, along with all decimal and hexadecimal
+//! numeric references. Anything else beginning with `'&'`, including a reference cut off at the
+//! edge of a string, is compared literally.
+
+use core::char;
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Returns whether `byte` may be part of the text between the `'&'` and `';'` of a character
+/// reference.
+#[inline]
+fn is_reference_byte(byte: u8) -> bool {
+    byte == b'#' || (byte < 0x80 && (byte as char).is_alphanumeric())
+}
+
+/// Returns the character referred to by `reference`, which is the text between the `'&'` and
+/// `';'` of a character reference.
+#[inline]
+fn decode(reference: &str) -> Option<char> {
+    if reference.as_bytes().first() == Some(&b'#') {
+        let digits = &reference[1..];
+        let (digits, radix) = match digits.as_bytes().first() {
+            Some(&b'x') | Some(&b'X') => (&digits[1..], 16),
+            _ => (digits, 10),
+        };
+        match u32::from_str_radix(digits, radix) {
+            Ok(code_point) => char::from_u32(code_point),
+            Err(_) => None,
+        }
+    } else {
+        match reference {
+            "amp" => Some('&'),
+            "apos" => Some('\''),
+            "gt" => Some('>'),
+            "lt" => Some('<'),
+            "nbsp" => Some('\u{a0}'),
+            "quot" => Some('"'),
+            _ => None,
+        }
+    }
+}
+
+/// Splits `s` into characters, where each character reference is read as the single character it
+/// refers to.
+#[inline]
+fn decode_all(s: &str) -> Mapped<char> {
+    let mut decoded = Mapped::new(s.len());
+    let bytes = s.as_bytes();
+    let mut index = 0;
+    while index < s.len() {
+        if bytes[index] == b'&' {
+            let end = index
+                + 1
+                + bytes[(index + 1)..]
+                    .iter()
+                    .take_while(|&&byte| is_reference_byte(byte))
+                    .count();
+            if end < s.len() && bytes[end] == b';' {
+                if let Some(c) = decode(&s[(index + 1)..end]) {
+                    decoded.push(c, index..(end + 1));
+                    index = end + 1;
+                    continue;
+                }
+            }
+        }
+        let c = s[index..].chars().next().unwrap();
+        decoded.push(c, index..(index + c.len_utf8()));
+        index += c.len_utf8();
+    }
+    decoded
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when character references are treated as equal to the characters they refer to, within `left`
+/// and within `right` respectively.
+///
+/// Character references are always matched whole, so neither range ever begins or ends within
+/// one. If nothing overlaps, the ranges are empty and located at the end of `left` and the start
+/// of `right`.
+#[inline]
+pub fn entity_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    decode_all(left).overlap_indices(&decode_all(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, entity_overlap_indices};
+    use {Overlap, StrOverlap};
+
+    #[test]
+    fn named_reference_and_literal() {
+        assert_eq!(
+            "salt &amp; pepper".overlap_end_html_entity_indices("& pepper mill"),
+            (5..17, 0..8)
+        );
+        assert_eq!(
+            "salt & pepper".overlap_end_html_entity_indices("&amp; pepper mill"),
+            (5..13, 0..12)
+        );
+        assert_eq!(
+            "&amp; pepper mill".overlap_start_html_entity_indices("salt & pepper"),
+            (0..12, 5..13)
+        );
+    }
+
+    #[test]
+    fn numeric_references() {
+        assert_eq!(
+            "a &#38; b".overlap_end_html_entity_indices("&#x26; b c"),
+            (2..9, 0..8)
+        );
+        assert_eq!(
+            "x &lt;p&gt;".overlap_end_html_entity_indices("<p>y"),
+            (2..11, 0..3)
+        );
+        assert_eq!(
+            "\u{e9}t\u{e9}".overlap_end_html_entity_indices("&#XE9;s"),
+            (3..5, 0..6)
+        );
+    }
+
+    #[test]
+    fn seam_within_reference() {
+        let left = "x&amp;&am";
+        let right = "&amp;&amp;y";
+
+        // Compared byte by byte, the overlap ends partway through a reference in `right`.
+        assert_eq!(left.overlap_end(right), "&amp;&am");
+        assert_eq!(left.overlap_end_html_entity_indices(right), (9..9, 0..0));
+        assert_eq!(
+            "x &am".overlap_end_html_entity_indices("p; y"),
+            (5..5, 0..0)
+        );
+    }
+
+    #[test]
+    fn incomplete_reference_at_edge_compared_literally() {
+        // "&am" at the end of `left` is not a reference, so it only matches the same text.
+        assert_eq!(
+            "x &am".overlap_end_html_entity_indices("&amp; y"),
+            (5..5, 0..0)
+        );
+        assert_eq!(
+            "x &am".overlap_end_html_entity_indices("&amx"),
+            (2..5, 0..3)
+        );
+        // Likewise "&amp" at the start of `right` is missing its ';'.
+        assert_eq!(
+            "a &".overlap_end_html_entity_indices("&amp b"),
+            (2..3, 0..1)
+        );
+        assert_eq!(
+            "a &".overlap_end_html_entity_indices("&amp; b"),
+            (2..3, 0..5)
+        );
+    }
+
+    #[test]
+    fn unknown_references_compared_literally() {
+        assert_eq!(decode("copy"), None);
+        assert_eq!(decode("#"), None);
+        assert_eq!(decode("#x"), None);
+        assert_eq!(decode("#xD800"), None);
+        assert_eq!(
+            "a &copy;".overlap_end_html_entity_indices("&copy; b"),
+            (2..8, 0..6)
+        );
+        assert_eq!(
+            "a &copy;".overlap_end_html_entity_indices("\u{a9} b"),
+            (8..8, 0..0)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(entity_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(entity_overlap_indices("", "&amp;"), (0..0, 0..0));
+        assert_eq!(entity_overlap_indices("&amp;", ""), (5..5, 0..0));
+    }
+}
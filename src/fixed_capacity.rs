@@ -0,0 +1,150 @@
+//! Merging overlapping values into fixed-capacity containers from the [`heapless`] crate.
+//!
+//! These functions are the equivalent of [`merge_end`] for environments without an allocator.
+//! Rather than growing to fit the merged value, they fail if it doesn't fit within the capacity of
+//! the container, without panicking.
+//!
+//! [`heapless`]: https://docs.rs/heapless
+//! [`merge_end`]: ::StrOverlap::merge_end
+
+use heapless::{CapacityError, String, Vec};
+use Overlap;
+
+/// Concatenates `left` and `right` into a [`heapless::String`] with capacity `N`, including the
+/// overlap at the end of `left` and the start of `right` only once.
+///
+/// The merged string is the same as the one returned by [`merge_end`]. If it is longer than `N`
+/// bytes, a [`CapacityError`] is returned instead.
+///
+/// This function is only available when the `heapless` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate heapless;
+/// extern crate str_overlap;
+///
+/// use str_overlap::merge_overlap_into;
+///
+/// # fn main() {
+/// assert_eq!(merge_overlap_into::<4>("abc", "bcd").unwrap(), "abcd");
+/// assert!(merge_overlap_into::<3>("abc", "bcd").is_err());
+/// # }
+/// ```
+///
+/// [`CapacityError`]: heapless::CapacityError
+/// [`heapless::String`]: heapless::String
+/// [`merge_end`]: ::StrOverlap::merge_end
+pub fn merge_overlap_into<const N: usize>(
+    left: &str,
+    right: &str,
+) -> Result<String<N>, CapacityError> {
+    let overlap_len = left.overlap_end_len(right);
+    // Each push either appends all of its bytes or fails without modifying `merged`.
+    let mut merged = String::new();
+    merged.push_str(left)?;
+    // `overlap_len` is always on a character bound of `right`, since the overlap is a prefix of
+    // `right`.
+    merged.push_str(&right[overlap_len..])?;
+    Ok(merged)
+}
+
+/// Concatenates `left` and `right` into a [`heapless::Vec`] with capacity `N`, including the
+/// overlap at the end of `left` and the start of `right` only once.
+///
+/// This is the slice equivalent of [`merge_overlap_into`]. If the merged slice is longer than `N`
+/// elements, a [`CapacityError`] is returned instead.
+///
+/// This function is only available when the `heapless` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate heapless;
+/// extern crate str_overlap;
+///
+/// use str_overlap::merge_overlap_into_vec;
+///
+/// # fn main() {
+/// assert_eq!(
+///     merge_overlap_into_vec::<u8, 4>(b"abc", b"bcd").unwrap(),
+///     b"abcd"
+/// );
+/// assert!(merge_overlap_into_vec::<u8, 3>(b"abc", b"bcd").is_err());
+/// # }
+/// ```
+///
+/// [`CapacityError`]: heapless::CapacityError
+/// [`heapless::Vec`]: heapless::Vec
+pub fn merge_overlap_into_vec<T, const N: usize>(
+    left: &[T],
+    right: &[T],
+) -> Result<Vec<T, N>, CapacityError>
+where
+    T: Clone + PartialEq,
+{
+    let overlap_len = left.overlap_end_len(right);
+    let mut merged = Vec::new();
+    merged.extend_from_slice(left)?;
+    merged.extend_from_slice(&right[overlap_len..])?;
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_overlap_into, merge_overlap_into_vec};
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!(merge_overlap_into::<8>("abc", "bcd").unwrap(), "abcd");
+        assert_eq!(
+            merge_overlap_into_vec::<u8, 8>(b"abc", b"bcd").unwrap(),
+            b"abcd"
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(merge_overlap_into::<8>("abc", "def").unwrap(), "abcdef");
+        assert_eq!(merge_overlap_into::<8>("", "").unwrap(), "");
+        assert_eq!(
+            merge_overlap_into_vec::<u8, 8>(b"abc", b"def").unwrap(),
+            b"abcdef"
+        );
+    }
+
+    #[test]
+    fn exact_fit() {
+        assert_eq!(merge_overlap_into::<4>("abc", "bcd").unwrap(), "abcd");
+        assert_eq!(merge_overlap_into::<6>("abc", "def").unwrap(), "abcdef");
+        assert_eq!(merge_overlap_into::<0>("", "").unwrap(), "");
+        assert_eq!(
+            merge_overlap_into_vec::<u8, 4>(b"abc", b"bcd").unwrap(),
+            b"abcd"
+        );
+    }
+
+    #[test]
+    fn one_over_capacity() {
+        assert!(merge_overlap_into::<3>("abc", "bcd").is_err());
+        assert!(merge_overlap_into::<5>("abc", "def").is_err());
+        assert!(merge_overlap_into_vec::<u8, 3>(b"abc", b"bcd").is_err());
+    }
+
+    #[test]
+    fn left_alone_over_capacity() {
+        assert!(merge_overlap_into::<2>("abc", "c").is_err());
+    }
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(merge_overlap_into::<9>("a語", "語b").unwrap(), "a語b");
+        assert!(merge_overlap_into::<4>("a語", "語b").is_err());
+    }
+
+    #[test]
+    fn non_byte_elements() {
+        assert_eq!(
+            merge_overlap_into_vec::<u32, 4>(&[1, 2, 3], &[2, 3, 4]).unwrap(),
+            [1, 2, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Overlap support for extended grapheme clusters, using the [`unicode-segmentation`] crate.
+//!
+//! A user-perceived character may consist of multiple `char`s, such as a letter followed by a
+//! combining accent. Overlaps that are only required to fall on character bounds can separate
+//! these, leaving a combining accent at the start of an overlap without the letter it modifies.
+//!
+//! [`unicode-segmentation`]: https://docs.rs/unicode-segmentation
+
+use slice_overlap_index_at_boundary;
+use unicode_segmentation::GraphemeCursor;
+
+/// Returns whether `index` is on an extended grapheme cluster bound of `s`.
+///
+/// `index` must be on a character bound of `s`.
+#[inline]
+fn is_grapheme_boundary(s: &str, index: usize) -> bool {
+    // The whole string is provided as the chunk, so no further context can ever be requested.
+    GraphemeCursor::new(index, s.len(), true).is_boundary(s, 0) == Ok(true)
+}
+
+/// Finds the index at which two strings overlap, without splitting any extended grapheme
+/// clusters.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+///
+/// Every candidate overlap is on character bounds of both strings, for the reasons described on
+/// [`overlap_index`](::overlap_index). Candidates that aren't also on grapheme
+/// cluster bounds of both strings are skipped in favor of shorter ones.
+#[inline]
+pub fn grapheme_overlap_index(left: &str, right: &str) -> usize {
+    slice_overlap_index_at_boundary(left.as_bytes(), right.as_bytes(), |index| {
+        is_grapheme_boundary(left, index) && is_grapheme_boundary(right, left.len() - index)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_grapheme_boundary;
+    use {Overlap, StrOverlap};
+
+    /// "e" followed by a combining acute accent, which together form the single grapheme "é".
+    const E_ACUTE: &str = "e\u{301}";
+
+    #[test]
+    fn boundaries() {
+        assert!(is_grapheme_boundary(E_ACUTE, 0));
+        assert!(!is_grapheme_boundary(E_ACUTE, 1));
+        assert!(is_grapheme_boundary(E_ACUTE, E_ACUTE.len()));
+    }
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!("abc".overlap_end_graphemes("bcd"), "bc");
+        assert_eq!("bcd".overlap_start_graphemes("abc"), "bc");
+    }
+
+    #[test]
+    fn combining_mark_at_start_of_right() {
+        // Comparing chars alone, `left` ends with the accent that `right` begins with.
+        let left = "cafe\u{301}";
+        let right = "\u{301}s";
+
+        assert_eq!(left.overlap_end_char_count(right), 1);
+        assert_eq!(left.overlap_end_graphemes(right), "");
+        assert_eq!(right.overlap_start_graphemes(left), "");
+    }
+
+    #[test]
+    fn base_char_at_end_of_left() {
+        // Comparing chars alone, `right` begins with the "e" that `left` ends with, but in
+        // `right` it is combined with an accent.
+        let left = "the";
+        let right = "e\u{301}t\u{e9}";
+
+        assert_eq!(left.overlap_end_char_count(right), 1);
+        assert_eq!(left.overlap_end_graphemes(right), "");
+        assert_eq!(right.overlap_start_graphemes(left), "");
+    }
+
+    #[test]
+    fn shrinks_to_grapheme_bound() {
+        // The longest overlap by chars is "aa", but the second "a" is combined with an accent in
+        // `right`. The next longest, "a", doesn't split any graphemes.
+        let left = "xaa";
+        let right = "aa\u{301}";
+
+        assert_eq!(left.overlap_end(right), "aa");
+        assert_eq!(left.overlap_end_graphemes(right), "a");
+        assert_eq!(right.overlap_start_graphemes(left), "a");
+    }
+
+    #[test]
+    fn whole_graphemes() {
+        let left = ["x", E_ACUTE].concat();
+        let right = [E_ACUTE, "y"].concat();
+
+        assert_eq!(left.overlap_end_graphemes(&right), E_ACUTE);
+        assert_eq!(right.overlap_start_graphemes(&left), E_ACUTE);
+    }
+
+    #[test]
+    fn regional_indicators() {
+        // Two flags, 🇺🇸 and 🇸🇪, share the regional indicator "🇸" at the seam.
+        let left = "\u{1F1FA}\u{1F1F8}";
+        let right = "\u{1F1F8}\u{1F1EA}";
+
+        assert_eq!(left.overlap_end(right), "\u{1F1F8}");
+        assert_eq!(left.overlap_end_graphemes(right), "");
+        assert_eq!(right.overlap_start_graphemes(left), "");
+    }
+
+    #[test]
+    fn whole_flags() {
+        // 🇸🇪 followed by 🇺🇸, overlapping with 🇺🇸 followed by 🇫🇷.
+        let left = "\u{1F1F8}\u{1F1EA}\u{1F1FA}\u{1F1F8}";
+        let right = "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}";
+
+        assert_eq!(left.overlap_end_graphemes(right), "\u{1F1FA}\u{1F1F8}");
+    }
+
+    #[test]
+    fn family_emoji() {
+        // 👨‍👩‍👧 is a single grapheme, made of three emoji joined by zero width
+        // joiners.
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let left = ["x", family].concat();
+
+        // The girl at the end of the family matches alone.
+        assert_eq!(left.overlap_end("\u{1F467}y"), "\u{1F467}");
+        assert_eq!(left.overlap_end_graphemes("\u{1F467}y"), "");
+        // So does the end of the sequence, beginning with the woman.
+        let partial = "\u{1F469}\u{200D}\u{1F467}y";
+        assert_eq!(left.overlap_end(partial), &partial[..(partial.len() - 1)]);
+        assert_eq!(left.overlap_end_graphemes(partial), "");
+        // The whole family is a valid overlap.
+        assert_eq!(left.overlap_end_graphemes(&[family, "y"].concat()), family);
+    }
+
+    #[test]
+    fn family_emoji_at_start_of_right() {
+        // The man at the start of the family matches the end of `left` alone.
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+        assert_eq!("x\u{1F468}".overlap_end(family), "\u{1F468}");
+        assert_eq!("x\u{1F468}".overlap_end_graphemes(family), "");
+        assert_eq!(family.overlap_start_graphemes("x\u{1F468}"), "");
+    }
+
+    #[test]
+    fn combining_marks_shrink_overlap() {
+        // By bytes, the overlap is "ae\u{301}e", but the final "e" takes an accent in `right`.
+        let left = "xae\u{301}e";
+        let right = "ae\u{301}e\u{300}";
+
+        assert_eq!(left.overlap_end(right), &left[1..]);
+        assert_eq!(left.overlap_end_graphemes(right), "");
+        assert_eq!(
+            "e\u{301}ae\u{301}".overlap_end_graphemes("ae\u{301}e\u{301}"),
+            "ae\u{301}"
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!("abc".overlap_end_graphemes("def"), "");
+        assert_eq!("".overlap_start_graphemes(""), "");
+    }
+}
@@ -0,0 +1,148 @@
+//! Overlaps of strings that skip over ignored characters.
+
+use core::ops::Range;
+
+/// Returns the byte range of the overlap found at the end of `left` and the start of `right`,
+/// skipping over the characters for which `ignore` returns `true`, within `left`.
+///
+/// Both strings are compared as if every ignored character had been removed from them. The range
+/// runs from the first to the last compared character of `left` in the overlap, meaning any
+/// ignored characters at either edge are not part of the overlap, while any between compared
+/// characters are. If no characters overlap, an empty range at the end of `left` is returned.
+///
+/// Every candidate overlap is compared separately, which takes quadratic time in the worst case.
+#[inline]
+#[must_use]
+pub fn ignoring_overlap_range<F>(left: &str, right: &str, ignore: F) -> Range<usize>
+where
+    F: Fn(char) -> bool,
+{
+    let start = left
+        .char_indices()
+        .filter(|&(_, c)| !ignore(c))
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let mut right_compared = right.chars().filter(|&c| !ignore(c));
+            left[index..]
+                .chars()
+                .filter(|&c| !ignore(c))
+                .all(|c| right_compared.next() == Some(c))
+        });
+    match start {
+        Some(start) => start..compared_end(left, &ignore),
+        None => left.len()..left.len(),
+    }
+}
+
+/// Returns the index just past the last character of `s` that is not ignored.
+#[inline]
+fn compared_end<F>(s: &str, ignore: &F) -> usize
+where
+    F: Fn(char) -> bool,
+{
+    s.char_indices()
+        .rev()
+        .find(|&(_, c)| !ignore(c))
+        .map_or(0, |(index, c)| index + c.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ignoring_overlap_range;
+    use StrOverlap;
+
+    #[test]
+    fn spaces() {
+        assert_eq!("a b c".overlap_end_ignoring("b c d", |c| c == ' '), "b c");
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(
+            "foo, bar.".overlap_end_ignoring("bar! baz", |c| ",.! ".contains(c)),
+            "bar"
+        );
+    }
+
+    #[test]
+    fn different_placements() {
+        assert_eq!("a bc".overlap_end_ignoring("b  c d", |c| c == ' '), "bc");
+        assert_eq!("ab c".overlap_end_ignoring("bcd", |c| c == ' '), "b c");
+    }
+
+    #[test]
+    fn leading_ignored_chars_excluded() {
+        // The space before "b" is not part of the overlap, since it precedes the first match.
+        assert_eq!("a  b".overlap_end_ignoring(" b", |c| c == ' '), "b");
+    }
+
+    #[test]
+    fn trailing_ignored_chars_excluded() {
+        // The space and quote after "b" are not part of the overlap, since they follow the last
+        // match.
+        assert_eq!(
+            "a b \"".overlap_end_ignoring("\"b c", |c| c == ' ' || c == '"'),
+            "b"
+        );
+    }
+
+    #[test]
+    fn runs_of_ignored_chars_at_seam() {
+        assert_eq!(
+            "x, foo!?! ...".overlap_end_ignoring("... ?!foo, y", |c| !c.is_alphanumeric()),
+            "foo"
+        );
+        assert_eq!(
+            ignoring_overlap_range("x, foo!?! ...", "... ?!foo, y", |c| !c.is_alphanumeric()),
+            3..6
+        );
+    }
+
+    #[test]
+    fn quotes_and_whitespace() {
+        assert_eq!(
+            "x,\"foo\", \"bar\"".overlap_end_ignoring("foo,bar,baz", |c| c == ' ' || c == '"'),
+            "foo\", \"bar"
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!("a b".overlap_end_ignoring("c d", |c| c == ' '), "");
+        assert_eq!("".overlap_end_ignoring("a", |c| c == ' '), "");
+        assert_eq!("a".overlap_end_ignoring("", |c| c == ' '), "");
+        assert_eq!(ignoring_overlap_range("a b", "c d", |c| c == ' '), 3..3);
+    }
+
+    #[test]
+    fn only_ignored_chars() {
+        // Ignored characters alone never form an overlap.
+        assert_eq!("a  ".overlap_end_ignoring("  b", |c| c == ' '), "");
+        assert_eq!("   ".overlap_end_ignoring("   ", |c| c == ' '), "");
+        assert_eq!("a".overlap_end_ignoring("a", |_| true), "");
+        assert_eq!(ignoring_overlap_range("   ", "   ", |c| c == ' '), 3..3);
+    }
+
+    #[test]
+    fn nothing_ignored() {
+        assert_eq!("a b c".overlap_end_ignoring("b c d", |_| false), "b c");
+        assert_eq!("a b c".overlap_end_ignoring("bc d", |_| false), "");
+    }
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(
+            "日 本 語".overlap_end_ignoring("本語!", |c| c == ' '),
+            "本 語"
+        );
+        assert_eq!(
+            "é·ö·".overlap_end_ignoring("é ö", |c| c == '·' || c == ' '),
+            "é·ö"
+        );
+    }
+
+    #[test]
+    fn repeated() {
+        assert_eq!("a a a".overlap_end_ignoring("aa b", |c| c == ' '), "a a");
+    }
+}
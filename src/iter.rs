@@ -0,0 +1,332 @@
+//! Iterators over overlaps.
+
+use {slice_overlap_count, slice_overlap_index_at_boundary};
+
+/// Creates an iterator over every overlap found at the end of `s` and the start of `other`.
+#[inline]
+pub fn overlaps_end<'a>(s: &'a str, other: &'a str) -> OverlapIter<'a> {
+    OverlapIter {
+        left: s,
+        right: other,
+        // Suffixes of `s` that are longer than `other` can never overlap, so they are skipped.
+        index: s.len().saturating_sub(other.len()),
+    }
+}
+
+/// Counts every non-empty overlap found at the end of `s` and the start of `other`.
+///
+/// This is the number of items yielded by [`overlaps_end`]. Since every byte overlap begins on a
+/// character bound, the bytes of both strings can be compared directly.
+#[inline]
+pub fn overlap_end_count(s: &str, other: &str) -> usize {
+    slice_overlap_count(s.as_bytes(), other.as_bytes())
+}
+
+/// Returns the `n`th longest non-empty overlap found at the end of `s` and the start of `other`,
+/// counting from zero.
+///
+/// This is the same as the `n`th item yielded by [`overlaps_end`], but stops as soon as it is
+/// found.
+#[inline]
+pub fn overlap_end_nth<'a>(s: &'a str, other: &str, n: usize) -> Option<&'a str> {
+    let mut remaining = n;
+    // Candidates are offered from longest to shortest, so the `n` before the one accepted are
+    // all longer.
+    let index = slice_overlap_index_at_boundary(s.as_bytes(), other.as_bytes(), |_| {
+        if remaining == 0 {
+            true
+        } else {
+            remaining -= 1;
+            false
+        }
+    });
+    if index == s.len() {
+        None
+    } else {
+        Some(&s[index..])
+    }
+}
+
+/// Returns the shortest non-empty overlap found at the start of `s` and the end of `other`, or an
+/// empty string slice if there is none.
+///
+/// This is the same as [`shortest_overlap_end`] with `s` and `other` swapped, except that the
+/// overlap is sliced from `s`.
+#[inline]
+pub fn shortest_overlap_start<'a>(s: &'a str, other: &str) -> &'a str {
+    let len = other.len() - shortest_overlap_index(other, s);
+    &s[..len]
+}
+
+/// Returns the shortest non-empty overlap found at the end of `s` and the start of `other`, or an
+/// empty string slice if there is none.
+///
+/// This is the same as the last item yielded by [`overlaps_end`].
+#[inline]
+pub fn shortest_overlap_end<'a>(s: &'a str, other: &str) -> &'a str {
+    &s[shortest_overlap_index(s, other)..]
+}
+
+/// Returns the index into `left` of the shortest non-empty overlap found at the end of `left` and
+/// the start of `right`, or `left.len()` if there is none.
+fn shortest_overlap_index(left: &str, right: &str) -> usize {
+    let left_bytes = left.as_bytes();
+    let right_bytes = right.as_bytes();
+    // Candidates are checked from shortest to longest, starting on each character bound from the
+    // end of `left`, so the first match is the shortest overlap.
+    for (index, _) in left.char_indices().rev() {
+        let len = left.len() - index;
+        if len > right.len() {
+            break;
+        }
+        if left_bytes[index..] == right_bytes[..len] {
+            return index;
+        }
+    }
+    left.len()
+}
+
+/// An iterator over every non-empty overlap found at the end of one string slice and the start of
+/// another, from longest to shortest.
+///
+/// This struct is created by the [`overlaps_end`] method. See its documentation for more.
+///
+/// [`overlaps_end`]: ::StrOverlap::overlaps_end
+#[derive(Clone, Debug)]
+pub struct OverlapIter<'a> {
+    left: &'a str,
+    right: &'a str,
+    /// The index into `left` of the next candidate overlap.
+    index: usize,
+}
+
+impl<'a> Iterator for OverlapIter<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let left = self.left.as_bytes();
+        let right = self.right.as_bytes();
+        while self.index < left.len() {
+            let index = self.index;
+            self.index += 1;
+            if left[index..] == right[..(left.len() - index)] {
+                // A non-empty overlap always begins on a character bound. See
+                // `overlap_index()` for details.
+                return Some(&self.left[index..]);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining candidate may or may not be an overlap.
+        (0, Some(self.left.len() - self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use StrOverlap;
+
+    #[test]
+    fn yields_longest_first() {
+        let mut overlaps = "aba".overlaps_end("aba");
+
+        assert_eq!(overlaps.next(), Some("aba"));
+        assert_eq!(overlaps.next(), Some("a"));
+        assert_eq!(overlaps.next(), None);
+    }
+
+    #[test]
+    fn periodic() {
+        let mut overlaps = "xaaaa".overlaps_end("aaab");
+
+        assert_eq!(overlaps.next(), Some("aaa"));
+        assert_eq!(overlaps.next(), Some("aa"));
+        assert_eq!(overlaps.next(), Some("a"));
+        assert_eq!(overlaps.next(), None);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!("abc".overlaps_end("def").next(), None);
+        assert_eq!("".overlaps_end("abc").next(), None);
+        assert_eq!("abc".overlaps_end("").next(), None);
+    }
+
+    #[test]
+    fn first_is_overlap_end() {
+        use Overlap;
+
+        assert_eq!(
+            "abcab".overlaps_end("abcabd").next(),
+            Some("abcab".overlap_end("abcabd"))
+        );
+    }
+
+    #[test]
+    fn multi_byte() {
+        let mut overlaps = "a語b語".overlaps_end("語b語c");
+
+        assert_eq!(overlaps.next(), Some("語b語"));
+        assert_eq!(overlaps.next(), Some("語"));
+        assert_eq!(overlaps.next(), None);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut overlaps = "aba".overlaps_end("ab");
+
+        assert_eq!(overlaps.size_hint(), (0, Some(2)));
+        assert_eq!(overlaps.next(), Some("a"));
+        assert_eq!(overlaps.size_hint(), (0, Some(0)));
+        assert_eq!(overlaps.next(), None);
+        assert_eq!(overlaps.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!("aaa".overlap_end_count("aaa"), 3);
+        assert_eq!("aba".overlap_end_count("aba"), 2);
+        assert_eq!("xaaaa".overlap_end_count("aaab"), 3);
+        assert_eq!("abc".overlap_end_count("def"), 0);
+        assert_eq!("".overlap_end_count(""), 0);
+    }
+
+    #[test]
+    fn count_multi_byte() {
+        // Byte suffixes of "語" are never byte prefixes of it, so only whole characters count.
+        assert_eq!("語語".overlap_end_count("語語"), 2);
+        assert_eq!("a語b語".overlap_end_count("語b語c"), 2);
+    }
+
+    #[test]
+    fn count_matches_iterator() {
+        let pairs = [
+            ("aba", "aba"),
+            ("aaaa", "aaaa"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("ab", "abab"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.overlap_end_count(right),
+                left.overlaps_end(right).count()
+            );
+        }
+    }
+
+    #[test]
+    fn nth() {
+        assert_eq!("aba".overlap_end_nth("aba", 0), Some("aba"));
+        assert_eq!("aba".overlap_end_nth("aba", 1), Some("a"));
+        assert_eq!("aba".overlap_end_nth("aba", 2), None);
+        assert_eq!("xaaaa".overlap_end_nth("aaab", 2), Some("a"));
+        assert_eq!("abc".overlap_end_nth("def", 0), None);
+        assert_eq!("".overlap_end_nth("", 0), None);
+    }
+
+    #[test]
+    fn nth_matches_iterator() {
+        let pairs = [
+            ("aba", "aba"),
+            ("aaaa", "aaaa"),
+            ("abcab", "abcabd"),
+            ("a語b語", "語b語c"),
+            ("abc", "def"),
+        ];
+        for &(left, right) in &pairs {
+            for n in 0..6 {
+                assert_eq!(
+                    left.overlap_end_nth(right, n),
+                    left.overlaps_end(right).nth(n)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nth_borrows_from_self() {
+        let s = "abab";
+
+        assert_eq!(
+            s.overlap_end_nth("ababc", 1).map(str::as_ptr),
+            Some(s[2..].as_ptr())
+        );
+    }
+
+    #[test]
+    fn shortest() {
+        assert_eq!("aba".shortest_overlap_end("aba"), "a");
+        assert_eq!("abcabc".shortest_overlap_end("abcabcx"), "abc");
+        assert_eq!("xaaaa".shortest_overlap_end("aaab"), "a");
+        assert_eq!("abc".shortest_overlap_end("bcd"), "bc");
+        assert_eq!("abc".shortest_overlap_end("def"), "");
+        assert_eq!("".shortest_overlap_end(""), "");
+    }
+
+    #[test]
+    fn shortest_differs_from_longest() {
+        use Overlap;
+
+        assert_eq!("aba".overlap_end("aba"), "aba");
+        assert_eq!("aba".shortest_overlap_end("aba"), "a");
+        assert_eq!("abab".overlap_start("xabab"), "abab");
+        assert_eq!("abab".shortest_overlap_start("xabab"), "ab");
+    }
+
+    #[test]
+    fn shortest_start() {
+        assert_eq!("aba".shortest_overlap_start("aba"), "a");
+        assert_eq!("bcd".shortest_overlap_start("abc"), "bc");
+        assert_eq!("def".shortest_overlap_start("abc"), "");
+        assert_eq!("語b語c".shortest_overlap_start("a語b語"), "語");
+    }
+
+    #[test]
+    fn shortest_multi_byte() {
+        assert_eq!("a語b語".shortest_overlap_end("語b語c"), "語");
+        assert_eq!("語語".shortest_overlap_end("語語"), "語");
+    }
+
+    #[test]
+    fn shortest_matches_iterator() {
+        let pairs = [
+            ("aba", "aba"),
+            ("aaaa", "aaaa"),
+            ("abcab", "abcabd"),
+            ("a語b語", "語b語c"),
+            ("abc", "def"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.shortest_overlap_end(right),
+                left.overlaps_end(right).last().unwrap_or("")
+            );
+        }
+    }
+
+    #[test]
+    fn shortest_borrows_from_self() {
+        let s = "abab";
+
+        assert_eq!(s.shortest_overlap_end("ababc").as_ptr(), s[2..].as_ptr());
+        assert_eq!(s.shortest_overlap_start("xabab").as_ptr(), s.as_ptr());
+    }
+
+    #[test]
+    fn size_hint_bounds_count() {
+        let pairs = [("aba", "aba"), ("aaaa", "aaaa"), ("abc", "def"), ("", "")];
+        for &(left, right) in &pairs {
+            let overlaps = left.overlaps_end(right);
+            let (lower, upper) = overlaps.size_hint();
+            let count = overlaps.count();
+
+            assert!(lower <= count);
+            assert!(count <= upper.unwrap());
+        }
+    }
+}
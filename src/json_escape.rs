@@ -0,0 +1,217 @@
+//! Overlaps of strings that treat JSON string escapes as equal to the characters they stand for.
+//!
+//! The escapes `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t`, and `\uXXXX` are recognized,
+//! including surrogate pairs written as two consecutive `\uXXXX` escapes. Anything else beginning
+//! with `'\\'`, including an escape cut off at the edge of a string, is compared literally.
+
+use core::char;
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Returns the UTF-16 code unit written as four hexadecimal digits at the start of `bytes`.
+#[inline]
+fn code_unit(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() < 4 {
+        return None;
+    }
+    let mut unit = 0;
+    for &byte in &bytes[..4] {
+        match (byte as char).to_digit(16) {
+            Some(digit) => unit = unit * 16 + digit,
+            None => return None,
+        }
+    }
+    Some(unit)
+}
+
+/// Returns the character written by the escape at the start of `bytes`, along with the length of
+/// the escape in bytes, or `None` if `bytes` doesn't begin with a valid escape.
+#[inline]
+fn unescape(bytes: &[u8]) -> Option<(char, usize)> {
+    if bytes.len() < 2 || bytes[0] != b'\\' {
+        return None;
+    }
+    let c = match bytes[1] {
+        b'"' => '"',
+        b'\\' => '\\',
+        b'/' => '/',
+        b'b' => '\u{8}',
+        b'f' => '\u{c}',
+        b'n' => '\n',
+        b'r' => '\r',
+        b't' => '\t',
+        b'u' => return code_unit(&bytes[2..]).and_then(|unit| unescape_unit(unit, &bytes[6..])),
+        _ => return None,
+    };
+    Some((c, 2))
+}
+
+/// Returns the character written by a `\\uXXXX` escape of the code unit `unit`, along with the
+/// length of the escape in bytes, where `rest` is everything following the escape.
+///
+/// A high surrogate is only valid when followed by an escaped low surrogate, and a lone low
+/// surrogate is never valid.
+#[inline]
+fn unescape_unit(unit: u32, rest: &[u8]) -> Option<(char, usize)> {
+    if unit & 0xfc00 != 0xd800 {
+        return char::from_u32(unit).map(|c| (c, 6));
+    }
+    if rest.len() < 2 || rest[0] != b'\\' || rest[1] != b'u' {
+        return None;
+    }
+    match code_unit(&rest[2..]) {
+        Some(low) if low & 0xfc00 == 0xdc00 => {
+            char::from_u32(0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00)).map(|c| (c, 12))
+        }
+        _ => None,
+    }
+}
+
+/// Splits `s` into characters, where each escape is read as the single character it stands for.
+#[inline]
+fn unescape_all(s: &str) -> Mapped<char> {
+    let mut unescaped = Mapped::new(s.len());
+    let mut index = 0;
+    while index < s.len() {
+        if let Some((c, len)) = unescape(&s.as_bytes()[index..]) {
+            unescaped.push(c, index..(index + len));
+            index += len;
+            continue;
+        }
+        let c = s[index..].chars().next().unwrap();
+        unescaped.push(c, index..(index + c.len_utf8()));
+        index += c.len_utf8();
+    }
+    unescaped
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when JSON string escapes are treated as equal to the characters they stand for, within `left`
+/// and within `right` respectively.
+///
+/// Escapes are always matched whole, so neither range ever begins or ends within one. If nothing
+/// overlaps, the ranges are empty and located at the end of `left` and the start of `right`.
+#[inline]
+pub fn json_escape_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    unescape_all(left).overlap_indices(&unescape_all(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_escape_overlap_indices, unescape};
+    use {Overlap, StrOverlap};
+
+    #[test]
+    fn escaped_quote_and_literal() {
+        assert_eq!(
+            r#"say \"hi\""#.overlap_end_json_escape_indices(r#""hi" back"#),
+            (4..10, 0..4)
+        );
+        assert_eq!(
+            r#""hi" back"#.overlap_start_json_escape_indices(r#"say \"hi\""#),
+            (0..4, 4..10)
+        );
+    }
+
+    #[test]
+    fn control_escapes() {
+        assert_eq!(
+            r"a\tb\n".overlap_end_json_escape_indices("b\nc"),
+            (3..6, 0..2)
+        );
+        assert_eq!(
+            "a\tb\n".overlap_end_json_escape_indices(r"\tb\nc"),
+            (1..4, 0..5)
+        );
+        assert_eq!(
+            r"x\/\\\b\f\r".overlap_end_json_escape_indices("/\\\u{8}\u{c}\r"),
+            (1..11, 0..5)
+        );
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(
+            r"caf\u00e9".overlap_end_json_escape_indices("\u{e9}s"),
+            (3..9, 0..2)
+        );
+        assert_eq!(
+            "caf\u{e9}".overlap_end_json_escape_indices(r"\u00e9s"),
+            (3..5, 0..6)
+        );
+        // A surrogate pair is a single character.
+        assert_eq!(
+            r"a\ud83d\ude00".overlap_end_json_escape_indices("\u{1f600}b"),
+            (1..13, 0..4)
+        );
+    }
+
+    #[test]
+    fn seam_within_escape() {
+        let left = r"x caf\u00";
+        let right = r"\u00e9 y";
+
+        // Compared byte by byte, the overlap ends partway through an escape in `right`.
+        assert_eq!(left.overlap_end(right), r"\u00");
+        // "\u00" at the end of `left` is compared literally, so it can't match "é".
+        assert_eq!(left.overlap_end_json_escape_indices(right), (9..9, 0..0));
+        assert_eq!(
+            r"x caf\u00".overlap_end_json_escape_indices("e9 y"),
+            (9..9, 0..0)
+        );
+        // Likewise, half of a surrogate pair is never matched.
+        assert_eq!(
+            r"a\ud83d".overlap_end_json_escape_indices(r"\ud83d\ude00"),
+            (7..7, 0..0)
+        );
+    }
+
+    #[test]
+    fn incomplete_escape_at_edge_compared_literally() {
+        assert_eq!(
+            r"x caf\u00".overlap_end_json_escape_indices(r"\u00x"),
+            (5..9, 0..4)
+        );
+        // A backslash at the very end of `left` is compared literally.
+        assert_eq!("a \\".overlap_end_json_escape_indices(r"\ b"), (2..3, 0..1));
+        assert_eq!(
+            "a \\".overlap_end_json_escape_indices(r#"\" b"#),
+            (3..3, 0..0)
+        );
+    }
+
+    #[test]
+    fn invalid_escapes_compared_literally() {
+        assert_eq!(unescape(br"\x"), None);
+        assert_eq!(unescape(br"\u+041"), None);
+        assert_eq!(unescape(br"\u12G4"), None);
+        assert_eq!(unescape(br"\udc00"), None);
+        assert_eq!(unescape(br"\ud800x"), None);
+        assert_eq!(unescape(br"\ud800A"), None);
+        assert_eq!(
+            r"a \x".overlap_end_json_escape_indices(r"\x b"),
+            (2..4, 0..2)
+        );
+        assert_eq!(r"a \x".overlap_end_json_escape_indices("x b"), (3..4, 0..1));
+    }
+
+    #[test]
+    fn escaped_backslash_before_u() {
+        // The escaped backslash is followed by a literal "u0041", not a unicode escape.
+        assert_eq!(
+            r"x\\u0041".overlap_end_json_escape_indices(r"\\u0041 y"),
+            (1..8, 0..7)
+        );
+        assert_eq!(
+            r"x\\u0041".overlap_end_json_escape_indices("A"),
+            (8..8, 0..0)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(json_escape_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(json_escape_overlap_indices("", r"\n"), (0..0, 0..0));
+        assert_eq!(json_escape_overlap_indices(r"\n", ""), (2..2, 0..0));
+    }
+}
@@ -0,0 +1,252 @@
+//! Overlap support for text in legacy encodings, using the [`encoding_rs`] crate.
+//!
+//! In many legacy encodings, such as Shift_JIS, a character may be encoded as multiple bytes, and
+//! the trailing bytes of a character can have the same values as whole characters. Whether a byte
+//! begins a character therefore can't be told from the byte alone, and must instead be found by
+//! decoding everything before it. The functions in this module decode both sides to find their
+//! character bounds, and only accept overlaps that begin and end on them.
+//!
+//! [`encoding_rs`]: https://docs.rs/encoding_rs
+
+use alloc::vec::Vec;
+use encoding_rs::{DecoderResult, Encoding};
+use slice_overlap_index_at_boundary;
+
+/// Returns whether each index of `bytes`, from `0` to `bytes.len()` inclusive, is on a character
+/// bound when decoded as `encoding`.
+///
+/// An index is a bound if decoding the bytes before it leaves no partially decoded character.
+/// Malformed sequences are considered to end wherever the decoder gives up on them. Bytes that
+/// decode to nothing, such as the escape sequences of ISO-2022-JP, are conservatively not
+/// considered to end on a bound.
+fn char_bounds(bytes: &[u8], encoding: &'static Encoding) -> Vec<bool> {
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let mut bounds = Vec::with_capacity(bytes.len() + 1);
+    bounds.push(true);
+    // Large enough for everything a single byte can cause any decoder to output.
+    let mut output = [0; 16];
+    let mut index = 0;
+    while index < bytes.len() {
+        // Bytes are decoded one at a time, so that the decoder's state can be observed after each.
+        let (result, read, written) = decoder.decode_to_utf8_without_replacement(
+            &bytes[index..(index + 1)],
+            &mut output,
+            false,
+        );
+        let malformed = match result {
+            DecoderResult::Malformed(..) => true,
+            DecoderResult::InputEmpty | DecoderResult::OutputFull => false,
+        };
+        if read == 0 {
+            // The byte ended an earlier malformed sequence without being consumed, and will be
+            // decoded again on its own. The malformed sequence therefore ended before it.
+            if let Some(bound) = bounds.last_mut() {
+                *bound |= malformed;
+            }
+            continue;
+        }
+        bounds.push(written != 0 || malformed);
+        index += 1;
+    }
+    bounds
+}
+
+/// Finds the index at which two byte slices encoded as `encoding` overlap, without splitting any
+/// characters.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+fn encoded_overlap_index(left: &[u8], right: &[u8], encoding: &'static Encoding) -> usize {
+    let left_bounds = char_bounds(left, encoding);
+    let right_bounds = char_bounds(right, encoding);
+    slice_overlap_index_at_boundary(left, right, |index| {
+        left_bounds[index] && right_bounds[left.len() - index]
+    })
+}
+
+/// Returns the overlap found at the start of `left` and the end of `right`, where both are encoded
+/// as `encoding`, without splitting any characters.
+///
+/// If the largest overlap of bytes would split a character in either slice, the overlap shrinks to
+/// the next one that doesn't. Both slices are decoded from their start to find their character
+/// bounds, which requires them to begin on a character bound.
+///
+/// This function is only available when the `encoding_rs` and `alloc` features are enabled.
+///
+/// # Example
+/// ```
+/// extern crate encoding_rs;
+/// extern crate str_overlap;
+///
+/// use encoding_rs::SHIFT_JIS;
+/// use str_overlap::encoded_overlap_start;
+///
+/// # fn main() {
+/// // "\ab" and "xソ", where the second byte of "ソ" is the same as "\".
+/// let left = b"\x5Cab";
+/// let right = b"x\x83\x5C";
+///
+/// assert_eq!(encoded_overlap_start(left, right, SHIFT_JIS), b"");
+/// # }
+/// ```
+#[must_use]
+pub fn encoded_overlap_start<'a>(
+    left: &'a [u8],
+    right: &[u8],
+    encoding: &'static Encoding,
+) -> &'a [u8] {
+    &left[..(right.len() - encoded_overlap_index(right, left, encoding))]
+}
+
+/// Returns the overlap found at the end of `left` and the start of `right`, where both are encoded
+/// as `encoding`, without splitting any characters.
+///
+/// If the largest overlap of bytes would split a character in either slice, the overlap shrinks to
+/// the next one that doesn't. Both slices are decoded from their start to find their character
+/// bounds, which requires them to begin on a character bound.
+///
+/// This function is only available when the `encoding_rs` and `alloc` features are enabled.
+///
+/// # Example
+/// ```
+/// extern crate encoding_rs;
+/// extern crate str_overlap;
+///
+/// use encoding_rs::SHIFT_JIS;
+/// use str_overlap::encoded_overlap_end;
+///
+/// # fn main() {
+/// // "xソ" and "\ab", where the second byte of "ソ" is the same as "\".
+/// let left = b"x\x83\x5C";
+/// let right = b"\x5Cab";
+///
+/// assert_eq!(encoded_overlap_end(left, right, SHIFT_JIS), b"");
+/// # }
+/// ```
+#[must_use]
+pub fn encoded_overlap_end<'a>(
+    left: &'a [u8],
+    right: &[u8],
+    encoding: &'static Encoding,
+) -> &'a [u8] {
+    &left[encoded_overlap_index(left, right, encoding)..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_bounds, encoded_overlap_end, encoded_overlap_start};
+    use encoding_rs::{EUC_JP, SHIFT_JIS, UTF_16LE, WINDOWS_1252};
+    use Overlap;
+
+    /// "ソ" in Shift_JIS, whose second byte is the same as "\".
+    const SO: &[u8] = b"\x83\x5C";
+
+    #[test]
+    fn shift_jis_bounds() {
+        assert_eq!(
+            char_bounds(b"a\x83\x5Cb", SHIFT_JIS),
+            [true, true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn shift_jis_whole_characters() {
+        let left = [b"x", SO].concat();
+        let right = [SO, b"y"].concat();
+
+        assert_eq!(encoded_overlap_end(&left, &right, SHIFT_JIS), SO);
+        assert_eq!(encoded_overlap_start(&right, &left, SHIFT_JIS), SO);
+    }
+
+    #[test]
+    fn shift_jis_trail_byte_in_left() {
+        // The bytes overlap by one, but only by splitting "ソ" in `left`.
+        let left = [b"x", SO].concat();
+        let right = b"\x5Cab";
+
+        assert_eq!(left.overlap_end(right), b"\x5C");
+        assert_eq!(encoded_overlap_end(&left, right, SHIFT_JIS), b"");
+        assert_eq!(encoded_overlap_start(right, &left, SHIFT_JIS), b"");
+    }
+
+    #[test]
+    fn shift_jis_lead_byte_in_right() {
+        // `left` ends with an incomplete lead byte, which only matches the start of "ソ" in
+        // `right`.
+        let left = b"ab\x83";
+        let right = [SO, b"c"].concat();
+
+        assert_eq!(left.overlap_end(&right[..]), b"\x83");
+        assert_eq!(encoded_overlap_end(left, &right, SHIFT_JIS), b"");
+    }
+
+    #[test]
+    fn shift_jis_shrinks_to_shorter_overlap() {
+        // The longest byte overlap begins within "ソ", but a shorter one doesn't.
+        let left = [SO, b"\x5C"].concat();
+        let right = b"\x5C\x5Cz";
+
+        assert_eq!(left.overlap_end(right), b"\x5C\x5C");
+        assert_eq!(encoded_overlap_end(&left, right, SHIFT_JIS), b"\x5C");
+    }
+
+    #[test]
+    fn shift_jis_malformed() {
+        // A lead byte followed by a byte that can't trail it is malformed, and the second byte is
+        // decoded on its own.
+        assert_eq!(encoded_overlap_end(b"\x830", b"0a", SHIFT_JIS), b"0");
+        // Unlike "0", "a" can trail a lead byte, so the two bytes form a single character.
+        assert_eq!(encoded_overlap_end(b"\x83a", b"ab", SHIFT_JIS), b"");
+    }
+
+    #[test]
+    fn euc_jp() {
+        // "あ" is "\xA4\xA2" in EUC-JP, and "い" is "\xA4\xA4".
+        let left = b"\xA4\xA2\xA4\xA4";
+        let right = b"\xA4\xA4\xA4\xA2";
+
+        assert_eq!(encoded_overlap_end(left, right, EUC_JP), b"\xA4\xA4");
+        assert_eq!(
+            encoded_overlap_end(b"\xA4\xA2\xA4", b"\xA4\xA4", EUC_JP),
+            b""
+        );
+    }
+
+    #[test]
+    fn utf_16le() {
+        // The shared byte is the second half of "a" in `left`, but the first half of "\u{100}"
+        // in `right`.
+        assert_eq!(encoded_overlap_end(b"a\x00", b"\x00\x01", UTF_16LE), b"");
+        assert_eq!(
+            encoded_overlap_end(b"a\x00b\x00", b"b\x00c\x00", UTF_16LE),
+            b"b\x00"
+        );
+    }
+
+    #[test]
+    fn single_byte_matches_byte_overlap() {
+        let pairs: [(&[u8], &[u8]); 4] = [
+            (b"abc", b"bcd"),
+            (b"caf\xE9", b"\xE9s"),
+            (b"\x80\x81", b"\x81\x80"),
+            (b"abc", b"def"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                encoded_overlap_end(left, right, WINDOWS_1252),
+                left.overlap_end(right)
+            );
+            assert_eq!(
+                encoded_overlap_start(left, right, WINDOWS_1252),
+                left.overlap_start(right)
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(encoded_overlap_end(b"", b"abc", SHIFT_JIS), b"");
+        assert_eq!(encoded_overlap_end(b"abc", b"", SHIFT_JIS), b"");
+        assert_eq!(encoded_overlap_start(b"", b"", SHIFT_JIS), b"");
+    }
+}
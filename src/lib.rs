@@ -3,8 +3,9 @@
 //! An overlap is here defined as the largest substring contained both at the end of one string
 //! slice and the beginning of another string slice.
 //!
-//! The implementation is provided through the [`Overlap`] trait, which is implemented on [`str`].
-//! This allows the user to simply pull the trait into scope and use its methods:
+//! The implementation is provided through the [`Overlap`] trait, which is implemented on [`str`]
+//! and on slices. This allows the user to simply pull the trait into scope and use its
+//! methods:
 //!
 //! ```
 //! use str_overlap::Overlap;
@@ -18,241 +19,4858 @@
 //! to allow the user to specify ownership of the resulting subvalue, regardless of its overlap
 //! position.
 //!
+//! Methods which only make sense for string slices, such as counting the characters within an
+//! overlap, are provided separately through the [`StrOverlap`] trait. Overlaps between string
+//! slices and raw bytes are found using the [`OverlapWith`] trait, and overlaps that can be
+//! modified in place are found using the [`OverlapMut`] trait.
+//!
+//! The index at which an overlap begins can also be found directly using [`overlap_index()`],
+//! which the trait methods on string slices are built on.
+//!
+//! On Rust 1.46.0 or newer, the length of an overlap between string slices can also be found at
+//! compile time using the `const fn` `overlap_end_len()`.
+//!
+//! # `no_std`
+//! This crate is `no_std` on Rust 1.6.0 or newer. The methods of [`Overlap`], and the methods of
+//! [`StrOverlap`] not documented as requiring a feature, are available with neither the `std` nor
+//! the `alloc` feature enabled, so they don't require an allocator. Methods returning owned values
+//! are only available when `alloc` is enabled.
+//!
+//! # Features
+//! - `std`: Implements the traits on types from the standard library, such as [`OsStr`], and
+//!   provides the [`CStrOverlap`] trait. Implies `alloc`.
+//! - `alloc`: Enables methods which return owned values, such as [`merge_end`], overlap functions
+//!   for [`VecDeque`]s, and [`overlap_index_linear()`], which finds overlaps in linear time. On
+//!   Rust 1.21.0 or newer, it also enables functions returning overlaps of an `Arc<str>` that
+//!   share its allocation. This can be used in `no_std` environments with an allocator, and
+//!   requires Rust 1.36.0 or newer when enabled without `std`.
+//! - `ascii`: Implements [`Overlap`] on [`ascii`]'s `AsciiStr`.
+//! - `bitvec`: Implements [`Overlap`] on [`bitvec`]'s `BitSlice`, finding overlaps of individual
+//!   bits.
+//! - `bstr`: Implements [`Overlap`] on [`bstr`]'s `BStr`, comparing bytes without UTF-8 validation.
+//! - `bytes`: Provides the `BytesOverlap` trait for [`bytes`]'s `Bytes`, whose overlaps share the
+//!   original allocation instead of being copied.
+//! - `camino`: Provides component-wise overlap functions for [`camino`]'s UTF-8 paths.
+//! - `caseless`: Provides [`StrOverlap`] methods which compare strings under full Unicode case
+//!   folding, using [`caseless`]. `unicode-case` is an alias for this feature. Requires `alloc`.
+//! - `csv`: Provides a function for merging overlapping CSV data, which uses [`csv`] to compare
+//!   whole records. Requires `std`.
+//! - `encoding_rs`: Provides overlap functions for bytes in any encoding supported by
+//!   [`encoding_rs`], which never split a multi-byte character of that encoding. Requires `alloc`.
+//! - `heapless`: Provides functions for merging overlapping values into [`heapless`]'s
+//!   fixed-capacity `String` and `Vec`, for use without an allocator.
+//! - `icu_collator`: Provides [`StrOverlap`] methods which compare strings using a collator from
+//!   [`icu_collator`], so that text which is equal under the collation rules of a locale, such as
+//!   "aa" and "å" in Danish, overlaps. `icu` enables this feature along with `icu_locale_core`,
+//!   which is needed to specify the locale of a collator.
+//! - `memmap2`: Provides a function for finding the overlap between two files, which uses
+//!   [`memmap2`] to map only the parts of the files that can overlap. Requires `std`.
+//! - `ropey`: Provides overlap functions for [`ropey`]'s ropes, which don't require the rope to be
+//!   copied into a contiguous buffer. Requires `alloc`.
+//! - `safe`: Replaces all `unsafe` code with checked equivalents, and forbids `unsafe` within the
+//!   crate. Overlaps are unchanged, but string slices are bounds checked and types such as `CStr`
+//!   are validated when they are sliced, which has a small cost. `OsStr` can only be sliced safely
+//!   on Unix, so [`Overlap`] is not implemented on it elsewhere.
+//! - `unicode-normalization`: Provides [`StrOverlap`] methods which normalize both strings using
+//!   [`unicode-normalization`] before comparing them, so that canonically or compatibility
+//!   equivalent text overlaps, or text overlaps regardless of diacritics or character width.
+//!   Requires `alloc`.
+//! - `unicode-security`: Provides [`StrOverlap`] methods which compare the confusable skeletons
+//!   of both strings, as defined by [Unicode Technical Standard #39][uts39], using
+//!   [`unicode-security`], so that visually confusable text such as "раypal" in Cyrillic and
+//!   Latin overlaps with "paypal". Requires `unicode-normalization` and `alloc`.
+//! - `unicode-segmentation`: Provides [`StrOverlap`] methods whose overlaps never split an
+//!   extended grapheme cluster, using [`unicode-segmentation`]. Also makes the word-aligned
+//!   methods, such as [`StrOverlap::overlap_end_words`], use Unicode word bounds rather than ASCII
+//!   whitespace and punctuation. `unicode-graphemes` is an alias for this feature.
+//! - `widestring`: Implements [`Overlap`] on [`widestring`]'s `U16Str` and `U32Str`, and provides
+//!   the `WideCStrOverlap` trait for their NUL-terminated equivalents.
+//!
+//! Enabling features that depend on external crates raises the MSRV to that of those crates.
+//!
+//! [`ascii`]: https://docs.rs/ascii
+//! [`bitvec`]: https://docs.rs/bitvec
+//! [`bstr`]: https://docs.rs/bstr
+//! [`bytes`]: https://docs.rs/bytes
+//! [`camino`]: https://docs.rs/camino
+//! [`caseless`]: https://docs.rs/caseless
+//! [`csv`]: https://docs.rs/csv
+//! [`encoding_rs`]: https://docs.rs/encoding_rs
+//! [`heapless`]: https://docs.rs/heapless
+//! [`icu_collator`]: https://docs.rs/icu_collator
+//! [`memmap2`]: https://docs.rs/memmap2
+//! [`merge_end`]: StrOverlap::merge_end
+//! [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
 //! [`overlap_end`]: Overlap::overlap_end
 //! [`overlap_start`]: Overlap::overlap_start
+//! [`ropey`]: https://docs.rs/ropey
+//! [`unicode-normalization`]: https://docs.rs/unicode-normalization
+//! [`unicode-security`]: https://docs.rs/unicode-security
+//! [`unicode-segmentation`]: https://docs.rs/unicode-segmentation
+//! [uts39]: https://www.unicode.org/reports/tr39/
+//! [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+//! [`widestring`]: https://docs.rs/widestring
+
+// Since the MSRV is 1.0.0, allowing usage of deprecated items is ok, as the replacements are likely
+// not available in early versions.
+#![allow(deprecated)]
+#![cfg_attr(rustc_1_6, no_std)]
+#![cfg_attr(feature = "safe", forbid(unsafe_code))]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+#[cfg(feature = "ascii")]
+extern crate ascii;
+#[cfg(feature = "bitvec")]
+extern crate bitvec;
+#[cfg(feature = "bstr")]
+extern crate bstr;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "camino")]
+extern crate camino;
+#[cfg(feature = "caseless")]
+extern crate caseless;
+#[cfg(feature = "csv")]
+extern crate csv;
+#[cfg(feature = "encoding_rs")]
+extern crate encoding_rs;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "icu_collator")]
+extern crate icu_collator;
+#[cfg(all(test, feature = "icu_locale_core"))]
+extern crate icu_locale_core;
+#[cfg(feature = "memmap2")]
+extern crate memmap2;
+#[cfg(feature = "ropey")]
+extern crate ropey;
+#[cfg(all(rustc_1_6, feature = "std"))]
+extern crate std;
+#[cfg(feature = "unicode-normalization")]
+extern crate unicode_normalization;
+#[cfg(feature = "unicode-security")]
+extern crate unicode_security;
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+#[cfg(feature = "widestring")]
+extern crate widestring;
+// When `std` is available, its re-exports of the `alloc` crate's items are used instead. This
+// keeps the MSRV with `std` enabled at 1.0.0, since the `alloc` crate was not stable until
+// 1.36.0.
+#[cfg(feature = "std")]
+extern crate std as alloc;
+#[cfg(not(rustc_1_6))]
+extern crate std as core;
+
+#[cfg(feature = "alloc")]
+mod ansi;
+#[cfg(feature = "ascii")]
+mod ascii_str;
+#[cfg(feature = "bstr")]
+mod b_str;
+#[cfg(feature = "bitvec")]
+mod bit_slice;
+mod bom;
+mod byte_slice;
+#[cfg(feature = "std")]
+mod c_str;
+#[cfg(all(feature = "caseless", feature = "alloc"))]
+mod case_fold;
+#[cfg(feature = "alloc")]
+mod case_policy;
+mod char_eq;
+#[cfg(feature = "icu_collator")]
+mod collation;
+#[cfg(rustc_1_46)]
+mod const_overlap;
+#[cfg(all(feature = "csv", feature = "std"))]
+mod csv_records;
+#[cfg(feature = "alloc")]
+mod entity;
+#[cfg(feature = "heapless")]
+mod fixed_capacity;
+#[cfg(feature = "unicode-segmentation")]
+mod grapheme;
+mod ignoring;
+mod iter;
+#[cfg(feature = "alloc")]
+mod json_escape;
+#[cfg(all(feature = "encoding_rs", feature = "alloc"))]
+mod legacy_encoding;
+#[cfg(feature = "alloc")]
+mod mapped;
+#[cfg(all(feature = "memmap2", feature = "std"))]
+mod mapped_file;
+#[cfg(feature = "alloc")]
+mod newline;
+#[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+mod normalization;
+mod options;
+#[cfg(all(feature = "std", any(unix, all(rustc_1_74, not(feature = "safe")))))]
+mod os_str;
+mod overlap;
+mod overlap_mut;
+mod overlap_result;
+mod overlap_with;
+mod pairwise;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "alloc")]
+mod percent;
+#[cfg(all(feature = "ropey", feature = "alloc"))]
+mod rope;
+#[cfg(feature = "bytes")]
+mod shared_bytes;
+#[cfg(all(feature = "alloc", rustc_1_21))]
+mod shared_str;
+#[cfg(feature = "alloc")]
+mod tab;
+#[cfg(feature = "alloc")]
+mod token;
+mod translated;
+mod utf16;
+#[cfg(feature = "camino")]
+mod utf8_path;
+#[cfg(feature = "alloc")]
+mod vec_deque;
+#[cfg(feature = "alloc")]
+mod whitespace;
+#[cfg(feature = "widestring")]
+mod wide_str;
+mod wildcard;
+mod word;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+pub use byte_slice::ByteSliceOverlap;
+#[cfg(feature = "std")]
+pub use c_str::CStrOverlap;
+#[cfg(feature = "alloc")]
+pub use case_policy::{merge_overlap_ignore_ascii_case, CaseConflict, CasePolicy};
+pub use char_eq::OverlapRanges;
+#[cfg(rustc_1_46)]
+pub use const_overlap::overlap_end_len;
+#[cfg(feature = "alloc")]
+use core::cmp;
+use core::ops::Range;
+#[cfg(all(feature = "csv", feature = "std"))]
+pub use csv_records::stitch_csv;
+#[cfg(feature = "heapless")]
+pub use fixed_capacity::{merge_overlap_into, merge_overlap_into_vec};
+#[cfg(feature = "icu_collator")]
+use icu_collator::CollatorBorrowed;
+pub use iter::OverlapIter;
+#[cfg(all(feature = "encoding_rs", feature = "alloc"))]
+pub use legacy_encoding::{encoded_overlap_end, encoded_overlap_start};
+#[cfg(all(feature = "memmap2", feature = "std"))]
+pub use mapped_file::file_overlap_end;
+pub use options::OverlapOptions;
+pub use overlap::{Direction, Overlap};
+pub use overlap_mut::OverlapMut;
+pub use overlap_result::OverlapResult;
+pub use overlap_with::OverlapWith;
+#[cfg(feature = "alloc")]
+pub use pairwise::assemble;
+pub use pairwise::longest_common_overlap;
+#[cfg(feature = "std")]
+pub use path::{join_dedup, path_overlap_end, PathOverlap};
+#[cfg(all(feature = "ropey", feature = "alloc"))]
+pub use rope::{rope_overlap_end_len, rope_overlap_start_len};
+#[cfg(feature = "bytes")]
+pub use shared_bytes::BytesOverlap;
+#[cfg(all(feature = "alloc", rustc_1_21))]
+pub use shared_str::{shared_overlap_end, shared_overlap_start, SharedOverlap};
+pub use translated::{overlap_end_translated, overlap_start_translated};
+pub use utf16::{utf16_overlap_end, utf16_overlap_start};
+#[cfg(feature = "camino")]
+pub use utf8_path::{utf8_join_dedup, utf8_path_overlap_end};
+#[cfg(feature = "alloc")]
+pub use vec_deque::{split_overlap_end_len, vec_deque_overlap_end_len};
+#[cfg(feature = "widestring")]
+pub use wide_str::WideCStrOverlap;
+
+/// Builds the failure table of `pattern` used by the Knuth-Morris-Pratt algorithm.
+///
+/// The value at each index `i` is the length of the longest proper prefix of `pattern[..(i + 1)]`
+/// that is also a suffix of it.
+#[cfg(feature = "alloc")]
+fn failure_table<T, F>(pattern: &[T], eq: &mut F) -> Vec<usize>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut failure = Vec::with_capacity(pattern.len());
+    let mut len = 0;
+    for (index, element) in pattern.iter().enumerate() {
+        if index != 0 {
+            loop {
+                if eq(element, &pattern[len]) {
+                    len += 1;
+                    break;
+                }
+                if len == 0 {
+                    break;
+                }
+                len = failure[len - 1];
+            }
+        }
+        failure.push(len);
+    }
+    failure
+}
+
+/// Returns the length of the longest overlap of the suffix of `left` and the prefix of `right` in
+/// linear time, using the Knuth-Morris-Pratt algorithm.
+#[cfg(feature = "alloc")]
+fn kmp_overlap_len<T>(left: &[T], right: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    let mut eq = |left_element: &T, right_element: &T| left_element == right_element;
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    let len = cmp::min(left.len(), right.len());
+    let pattern = &right[..len];
+    let failure = failure_table(pattern, &mut eq);
+    kmp_matched_len(
+        left[(left.len() - len)..].iter(),
+        pattern,
+        &failure,
+        &mut eq,
+    )
+}
+
+/// Returns the length of the longest suffix of `text` that is a prefix of `pattern`, using the
+/// Knuth-Morris-Pratt algorithm.
+///
+/// `failure` must be the failure table of `pattern`, and `text` must yield no more elements than
+/// `pattern` contains.
+#[cfg(feature = "alloc")]
+fn kmp_matched_len<'a, T, I, F>(text: I, pattern: &[T], failure: &[usize], eq: &mut F) -> usize
+where
+    T: 'a,
+    I: Iterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+    // Since the searched text is no longer than `pattern`, `matched` can only reach the full
+    // length of `pattern` on the final element.
+    let mut matched = 0;
+    for element in text {
+        loop {
+            if eq(element, &pattern[matched]) {
+                matched += 1;
+                break;
+            }
+            if matched == 0 {
+                break;
+            }
+            matched = failure[matched - 1];
+        }
+    }
+    matched
+}
+
+/// Shared logic for finding the index at which two slices overlap.
+///
+/// The `left` and `right` parameters are, conceptually, defined as follows:
+/// - `left` is the parameter whose suffix will be overlapping
+/// - `right` is the parameter whose prefix will be overlapping
+///
+/// If no overlap exists, the returned index will be the length of `left`. This allows the result to
+/// be used to create an empty slice.
+///
+/// Candidate overlaps are compared as whole subslices, from longest to shortest. This takes
+/// quadratic time in the worst case, but never allocates, and allows specialized slice comparisons
+/// (such as those for bytes) to be used.
+#[inline]
+#[must_use]
+fn slice_overlap_index<T>(left: &[T], right: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    (left.len().saturating_sub(right.len())..left.len())
+        .find(|&index| left[index..] == right[..(left.len() - index)])
+        .unwrap_or(left.len())
+}
+
+/// Shared logic for finding the index at which two slices overlap, only accepting overlaps whose
+/// bounds satisfy `is_boundary`.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`].
+///
+/// `is_boundary` is called with each candidate index into `left` whose suffix is equal to a prefix
+/// of `right`. The length of the candidate overlap is therefore `left.len() - index`. Candidates
+/// are checked from longest to shortest, and the first one accepted is returned. The empty
+/// overlap is always accepted.
+#[inline]
+#[must_use]
+fn slice_overlap_index_at_boundary<T, F>(left: &[T], right: &[T], mut is_boundary: F) -> usize
+where
+    T: PartialEq,
+    F: FnMut(usize) -> bool,
+{
+    (left.len().saturating_sub(right.len())..left.len())
+        .find(|&index| left[index..] == right[..(left.len() - index)] && is_boundary(index))
+        .unwrap_or(left.len())
+}
+
+/// Shared logic for counting the non-empty overlaps of two slices.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`].
+#[inline]
+#[must_use]
+fn slice_overlap_count<T>(left: &[T], right: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    (left.len().saturating_sub(right.len())..left.len())
+        .filter(|&index| left[index..] == right[..(left.len() - index)])
+        .count()
+}
+
+/// Shared logic for finding the index at which two slices overlap, comparing elements using `eq`.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`]. Every
+/// candidate overlap is compared directly, so `eq` need not be an equivalence relation.
+#[inline]
+#[must_use]
+fn slice_overlap_index_by<T, F>(left: &[T], right: &[T], mut eq: F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    (left.len().saturating_sub(right.len())..left.len())
+        .find(|&index| {
+            left[index..]
+                .iter()
+                .zip(right)
+                .all(|(left_element, right_element)| eq(left_element, right_element))
+        })
+        .unwrap_or(left.len())
+}
+
+/// Returns whether two bytes are equal, ignoring ASCII case.
+///
+/// This is the same as `u8::eq_ignore_ascii_case()`, which is not available on the MSRV.
+#[inline]
+#[must_use]
+fn eq_ignore_ascii_case(a: u8, b: u8) -> bool {
+    /// Converts `byte` to ASCII lowercase, leaving non-ASCII-uppercase bytes unchanged.
+    #[inline]
+    fn to_ascii_lowercase(byte: u8) -> u8 {
+        if byte.wrapping_sub(b'A') < 26 {
+            byte | 0x20
+        } else {
+            byte
+        }
+    }
+
+    to_ascii_lowercase(a) == to_ascii_lowercase(b)
+}
+
+/// Shared logic for finding the index at which two strings overlap, ignoring ASCII case.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`].
+///
+/// The returned index is always on a UTF-8 character bound of `left`, for the same reason as in
+/// [`overlap_index`]: ASCII bytes only ever compare equal to ASCII bytes, and all other bytes are
+/// compared exactly, so the first byte of `right` can never match a continuation byte.
+#[inline]
+#[must_use]
+fn string_overlap_index_ignore_ascii_case(left: &str, right: &str) -> usize {
+    slice_overlap_index_by(left.as_bytes(), right.as_bytes(), |&a, &b| {
+        eq_ignore_ascii_case(a, b)
+    })
+}
+
+/// Returns whether `index` is on a UTF-8 character bound of `s`.
+///
+/// This is the same as `str::is_char_boundary()`, which is not available on the MSRV.
+#[inline]
+#[must_use]
+fn is_char_boundary(s: &str, index: usize) -> bool {
+    // Continuation bytes are of the form `0b10xxxxxx`.
+    index == s.len() || s.as_bytes()[index] & 0xC0 != 0x80
+}
+
+/// Returns the byte index at which the overlap of `left` and `right` begins in `left`.
+///
+/// The parameters are, conceptually, defined as follows:
+/// - `left` is the string slice whose suffix will be overlapping
+/// - `right` is the string slice whose prefix will be overlapping
+///
+/// The overlap is therefore `&left[overlap_index(left, right)..]`, which is equal to
+/// `&right[..(left.len() - overlap_index(left, right))]`. If no overlap exists, the returned index
+/// is `left.len()`, so that the same expressions produce empty string slices. This is the
+/// primitive that the methods of [`Overlap`] and [`StrOverlap`] on [`str`] are built on, exposed
+/// for building custom slicing without either trait.
+///
+/// The returned index is always on a UTF-8 character bound of `left`. Any non-empty overlap must
+/// begin with the first byte of `right`, which is the first byte of a UTF-8 character. Since that
+/// byte can never be a continuation byte, the matching byte of `left` must also begin a character.
+///
+/// Candidate overlaps are compared from longest to shortest without allocating. This is fastest
+/// for short inputs, but takes quadratic time when many long candidates nearly match, such as
+/// `"aaaa…aab"` and `"aaaa…aac"`. [`overlap_index_linear`] avoids this worst case.
+///
+/// # Example
+/// ```
+/// use str_overlap::overlap_index;
+///
+/// assert_eq!(overlap_index("abc", "bcd"), 1);
+/// assert_eq!(overlap_index("abc", "def"), 3);
+/// assert_eq!(overlap_index("a語b", "語bc"), 1);
+/// ```
+#[inline]
+#[must_use]
+pub fn overlap_index(left: &str, right: &str) -> usize {
+    slice_overlap_index(left.as_bytes(), right.as_bytes())
+}
+
+/// Returns the byte index into `left` at which its overlap with the start of `right` begins, in
+/// linear time.
+///
+/// The returned index is always the same as that returned by [`overlap_index()`], but it is found
+/// using the Knuth-Morris-Pratt algorithm, which takes *O(n + m)* time, where *n* and *m* are the
+/// lengths of `left` and `right`. This requires allocating a table as long as the shorter of the
+/// two, so it is only faster for long inputs with many partial matches.
+///
+/// This function is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// use str_overlap::overlap_index_linear;
+///
+/// assert_eq!(overlap_index_linear("abc", "bcd"), 1);
+/// assert_eq!(overlap_index_linear("abc", "def"), 3);
+/// assert_eq!(overlap_index_linear("a語b", "語bc"), 1);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn overlap_index_linear(left: &str, right: &str) -> usize {
+    left.len() - kmp_overlap_len(left.as_bytes(), right.as_bytes())
+}
+
+/// Shared logic for finding the index at which two strings overlap by whole lines.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`]. Both are
+/// split into lines on `'\n'`, where a trailing `'\n'` terminates the final line rather than
+/// beginning an empty one. Lines are compared without their terminators.
+///
+/// The returned index is always the start of a line in `left`, or `left.len()` if no lines
+/// overlap.
+#[inline]
+#[must_use]
+fn line_overlap_index(left: &str, right: &str) -> usize {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+    // Candidates are checked from longest to shortest, so the first match is the largest overlap.
+    let mut index = 0;
+    while index < left.len() {
+        let suffix = &left[index..];
+        let content_len = if suffix[suffix.len() - 1] == b'\n' {
+            suffix.len() - 1
+        } else {
+            suffix.len()
+        };
+        // The lines only match if the last one also ends at a line end in `right`. An empty
+        // `right` contains no lines at all, not even an empty one.
+        if content_len <= right.len()
+            && suffix[..content_len] == right[..content_len]
+            && match right.get(content_len) {
+                Some(&byte) => byte == b'\n',
+                None => !right.is_empty(),
+            }
+        {
+            return index;
+        }
+        index = match suffix.iter().position(|&byte| byte == b'\n') {
+            Some(line_len) => index + line_len + 1,
+            None => left.len(),
+        };
+    }
+    left.len()
+}
+
+/// Returns whether `index` is at the start of a line in `bytes`.
+///
+/// The start of `bytes` is the start of its first line, and every `'\n'` begins another line.
+#[inline]
+#[must_use]
+fn is_line_start(bytes: &[u8], index: usize) -> bool {
+    index == 0 || bytes[index - 1] == b'\n'
+}
+
+/// Returns whether `index` is at the end of a line in `bytes`.
+///
+/// A line ends either before its terminator, which is `"\r\n"` or `'\n'`, or after it. The end of
+/// `bytes` always ends a line, even if it has no terminator.
+#[inline]
+#[must_use]
+fn is_line_end(bytes: &[u8], index: usize) -> bool {
+    match bytes.get(index) {
+        None => true,
+        Some(&b'\n') => index == 0 || bytes[index - 1] != b'\r',
+        Some(&b'\r') => bytes.get(index + 1) == Some(&b'\n'),
+        Some(_) => is_line_start(bytes, index),
+    }
+}
+
+/// Shared logic for finding the index at which two strings overlap, only accepting overlaps that
+/// begin at the start of a line in `left` and end at the end of a line in `right`.
+///
+/// The `left` and `right` parameters are defined the same as in [`slice_overlap_index`]. Unlike
+/// [`line_overlap_index`], the bytes of the overlap are compared exactly, including any `"\r\n"`
+/// terminators.
+#[inline]
+#[must_use]
+fn aligned_line_overlap_index(left: &str, right: &str) -> usize {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+    slice_overlap_index_at_boundary(left, right, |index| {
+        is_line_start(left, index) && is_line_end(right, left.len() - index)
+    })
+}
+
+/// Provides overlap methods that are specific to string slices.
+///
+/// While [`Overlap`] provides methods that apply to any kind of overlapping values, the methods on
+/// this trait are concerned with the characters making up a string slice. It is made available by
+/// pulling it into scope:
+///
+/// ```
+/// use str_overlap::StrOverlap;
+/// ```
+///
+/// This trait is sealed, and is only implemented on [`str`]. Since no other crate can implement
+/// it, enabling a feature that adds methods to it never breaks code elsewhere in a dependency
+/// graph.
+///
+/// ```compile_fail,E0277
+/// use str_overlap::StrOverlap;
+///
+/// struct Text;
+///
+/// impl StrOverlap for Text {}
+/// ```
+pub trait StrOverlap: private::Sealed {
+    /// Returns the number of characters in the overlap found at the start of `self` and the end of
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("語a日bc本".overlap_start_char_count("b日本語a"), 2);
+    /// ```
+    #[must_use]
+    fn overlap_start_char_count(&self, other: &Self) -> usize;
+
+    /// Returns the number of characters in the overlap found at the end of `self` and the start of
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b日本語a".overlap_end_char_count("語a日bc本"), 2);
+    /// ```
+    #[must_use]
+    fn overlap_end_char_count(&self, other: &Self) -> usize;
+
+    /// Returns the byte range within `self` of the overlap found at the start of `self` and the
+    /// end of `other`.
+    ///
+    /// Indexing `self` with the returned range gives the same string slice as [`overlap_start`].
+    /// Both endpoints of the range are guaranteed to lie on UTF-8 character bounds of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_range("abc"), 0..2);
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn overlap_start_range(&self, other: &Self) -> Range<usize>;
+
+    /// Returns the byte range within `self` of the overlap found at the end of `self` and the
+    /// start of `other`.
+    ///
+    /// Indexing `self` with the returned range gives the same string slice as [`overlap_end`].
+    /// Both endpoints of the range are guaranteed to lie on UTF-8 character bounds of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".overlap_end_range("bcd"), 1..3);
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn overlap_end_range(&self, other: &Self) -> Range<usize>;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`,
+    /// within `self` and within `other` respectively.
+    ///
+    /// Both ranges have the same length, and the endpoints of each are guaranteed to lie on UTF-8
+    /// character bounds of their respective strings.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_indices("abc"), (0..2, 1..3));
+    /// ```
+    #[must_use]
+    fn overlap_start_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`,
+    /// within `self` and within `other` respectively.
+    ///
+    /// Both ranges have the same length, and the endpoints of each are guaranteed to lie on UTF-8
+    /// character bounds of their respective strings.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".overlap_end_indices("bcd"), (1..3, 0..2));
+    /// ```
+    #[must_use]
+    fn overlap_end_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, ignoring ASCII
+    /// case.
+    ///
+    /// ASCII letters are compared case-insensitively, while all other characters must match
+    /// exactly. The returned string slice is a reference to the substring contained in `self`,
+    /// and therefore keeps the casing of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("BCD".overlap_start_ignore_ascii_case("abc"), "BC");
+    /// ```
+    #[must_use]
+    fn overlap_start_ignore_ascii_case(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, ignoring ASCII
+    /// case.
+    ///
+    /// ASCII letters are compared case-insensitively, while all other characters must match
+    /// exactly. The returned string slice is a reference to the substring contained in `self`,
+    /// and therefore keeps the casing of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("ABC".overlap_end_ignore_ascii_case("bcd"), "BC");
+    /// // Non-ASCII characters must match exactly.
+    /// assert_eq!("aÄ".overlap_end_ignore_ascii_case("äb"), "");
+    /// ```
+    #[must_use]
+    fn overlap_end_ignore_ascii_case(&self, other: &Self) -> &Self;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of
+    /// `other`, comparing characters using `eq`.
+    ///
+    /// `eq` is called with a character of `self` and a character of `other`, in that order. Every
+    /// candidate overlap is compared directly, from longest to shortest, so `eq` need not be an
+    /// equivalence relation, and may treat a character as a wildcard matching any other. This can
+    /// express character-wise comparison modes such as [`overlap_start_ignore_ascii_case`], but
+    /// not modes that match sequences of characters, like ignoring whitespace.
+    ///
+    /// Since characters compared as equal may differ, and may even be encoded with different
+    /// lengths, the overlap is found by aligning the characters of both strings rather than their
+    /// bytes, and its range is returned within both `self` and `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // Treat '0' and 'O' as equal, as OCR output often confuses them.
+    /// let fold = |c| if c == '0' { 'O' } else { c };
+    /// let ranges = "0RLD".overlap_start_by("HELLO WO", |a, b| fold(a) == fold(b));
+    ///
+    /// assert_eq!(ranges.self_range(), 0..1);
+    /// assert_eq!(ranges.other_range(), 7..8);
+    ///
+    /// // Compare case-insensitively.
+    /// let other = "Straße ÜBER";
+    /// let ranges = "über alles".overlap_start_by(other, |a, b| {
+    ///     a.to_lowercase().next() == b.to_lowercase().next()
+    /// });
+    ///
+    /// assert_eq!(&other[ranges.other_range()], "ÜBER");
+    /// ```
+    ///
+    /// [`overlap_start_ignore_ascii_case`]: StrOverlap::overlap_start_ignore_ascii_case
+    #[must_use]
+    fn overlap_start_by<F>(&self, other: &Self, eq: F) -> OverlapRanges
+    where
+        F: FnMut(char, char) -> bool;
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of
+    /// `other`, comparing characters using `eq`.
+    ///
+    /// `eq` is called with a character of `self` and a character of `other`, in that order. Every
+    /// candidate overlap is compared directly, from longest to shortest, so `eq` need not be an
+    /// equivalence relation, and may treat a character as a wildcard matching any other. This can
+    /// express character-wise comparison modes such as [`overlap_end_ignore_ascii_case`], but
+    /// not modes that match sequences of characters, like ignoring whitespace.
+    ///
+    /// Since characters compared as equal may differ, and may even be encoded with different
+    /// lengths, the overlap is found by aligning the characters of both strings rather than their
+    /// bytes, and its range is returned within both `self` and `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // Treat '0' and 'O' as equal, as OCR output often confuses them.
+    /// let fold = |c| if c == '0' { 'O' } else { c };
+    /// let other = "HELLO WORLD";
+    /// let ranges = "HELL0 W".overlap_end_by(other, |a, b| fold(a) == fold(b));
+    ///
+    /// assert_eq!(ranges.self_range(), 0..7);
+    /// assert_eq!(&other[ranges.other_range()], "HELLO W");
+    ///
+    /// // Compare case-insensitively.
+    /// let s = "Straße ÜBER";
+    /// let ranges = s.overlap_end_by("über alles", |a, b| {
+    ///     a.to_lowercase().next() == b.to_lowercase().next()
+    /// });
+    ///
+    /// assert_eq!(&s[ranges.self_range()], "ÜBER");
+    ///
+    /// // Treat '?' in either string as matching any character.
+    /// let ranges = "ab?".overlap_end_by("bcd", |a, b| a == '?' || b == '?' || a == b);
+    ///
+    /// assert_eq!(ranges.self_range(), 1..3);
+    /// ```
+    ///
+    /// [`overlap_end_ignore_ascii_case`]: StrOverlap::overlap_end_ignore_ascii_case
+    #[must_use]
+    fn overlap_end_by<F>(&self, other: &Self, eq: F) -> OverlapRanges
+    where
+        F: FnMut(char, char) -> bool;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of
+    /// `other`, comparing the keys that `key` maps their characters to.
+    ///
+    /// Two characters are compared as equal if `key` maps them to equal values. This is the same
+    /// as [`overlap_start_by`] with `|a, b| key(a) == key(b)`, and is therefore always an
+    /// equivalence relation.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let other = "Straße ÜBER";
+    /// let ranges = "über alles".overlap_start_by_key(other, |c| c.to_lowercase().next());
+    ///
+    /// assert_eq!(&other[ranges.other_range()], "ÜBER");
+    /// ```
+    ///
+    /// [`overlap_start_by`]: StrOverlap::overlap_start_by
+    #[must_use]
+    fn overlap_start_by_key<F, K>(&self, other: &Self, key: F) -> OverlapRanges
+    where
+        F: FnMut(char) -> K,
+        K: PartialEq;
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of
+    /// `other`, comparing the keys that `key` maps their characters to.
+    ///
+    /// Two characters are compared as equal if `key` maps them to equal values. This is the same
+    /// as [`overlap_end_by`] with `|a, b| key(a) == key(b)`, and is therefore always an
+    /// equivalence relation.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let s = "Straße ÜBER";
+    /// let ranges = s.overlap_end_by_key("über alles", |c| c.to_lowercase().next());
+    ///
+    /// assert_eq!(&s[ranges.self_range()], "ÜBER");
+    /// ```
+    ///
+    /// [`overlap_end_by`]: StrOverlap::overlap_end_by
+    #[must_use]
+    fn overlap_end_by_key<F, K>(&self, other: &Self, key: F) -> OverlapRanges
+    where
+        F: FnMut(char) -> K,
+        K: PartialEq;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, where `wildcard`
+    /// in either string matches any character.
+    ///
+    /// The returned string slice is a reference to the substring contained in `self`, including
+    /// any wildcards within it.
+    ///
+    /// A wildcard matches any single character, regardless of its encoded length in UTF-8, so the
+    /// one-byte `'?'` matches the two-byte `'é'`. The overlap therefore always has the same number
+    /// of characters in both strings, but may span a different number of bytes in `other`.
+    ///
+    /// Candidate overlaps are always anchored at the end of `self` and the start of `other`, so the
+    /// longest match is still well-defined, even if the overlap consists only of wildcards. Since
+    /// matching with a wildcard is not transitive, every candidate overlap is compared separately,
+    /// which takes quadratic time in the worst case.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("ab?".overlap_end_wildcard("bcd", '?'), "b?");
+    /// assert_eq!("abc".overlap_end_wildcard("?cd", '?'), "bc");
+    /// // The one-byte '?' matches the two-byte 'é'.
+    /// assert_eq!("caf?".overlap_end_wildcard("\u{e9}s", '?'), "?");
+    /// ```
+    #[must_use]
+    fn overlap_end_wildcard(&self, other: &Self, wildcard: char) -> &Self;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// under full Unicode case folding, within `self` and within `other` respectively.
+    ///
+    /// Characters are compared after applying the default (non-Turkic) full case folding, which
+    /// may change their number, such as folding "ß" to "ss". The ranges may therefore have
+    /// different lengths. Overlaps only ever begin and end on bounds between the original
+    /// characters, so a character is never partially part of the overlap.
+    ///
+    /// This method is only available when the `caseless` and `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("straße und".overlap_start_fold("AN DER STRASSE"), (0..7, 7..14));
+    /// ```
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_fold(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// under full Unicode case folding, within `self` and within `other` respectively.
+    ///
+    /// Characters are compared after applying the default (non-Turkic) full case folding, which
+    /// may change their number, such as folding "ß" to "ss". The ranges may therefore have
+    /// different lengths. Overlaps only ever begin and end on bounds between the original
+    /// characters, so a character is never partially part of the overlap.
+    ///
+    /// This method is only available when the `caseless` and `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("AN DER STRASSE".overlap_end_fold("straße und"), (7..14, 0..7));
+    /// // Half of a folded "ß" is never part of the overlap.
+    /// assert_eq!("MAS".overlap_end_fold("ßen"), (3..3, 0..0));
+    /// ```
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_fold(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the overlap found at the start of `self` and the end of `other` under full Unicode
+    /// case folding, copied from the original text of `self`.
+    ///
+    /// Overlaps are found the same as in [`overlap_start_fold`]. Since folding can change the
+    /// length of the text, such as folding "ß" to "ss", the overlapping text of `self` may differ
+    /// in length from that of `other`. The original text of `self` is returned, rather than its
+    /// folded form, and a character whose folded form only partially overlaps is never included,
+    /// so the overlap is shrunk to the longest one covering whole characters of both strings.
+    ///
+    /// This method is only available when the `caseless` and `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("straße und".overlap_start_folded("AN DER STRASSE"), "straße");
+    /// ```
+    ///
+    /// [`overlap_start_fold`]: StrOverlap::overlap_start_fold
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_folded(&self, other: &Self) -> String;
+
+    /// Returns the overlap found at the end of `self` and the start of `other` under full Unicode
+    /// case folding, copied from the original text of `self`.
+    ///
+    /// Overlaps are found the same as in [`overlap_end_fold`]. Since folding can change the
+    /// length of the text, such as folding "ß" to "ss", the overlapping text of `self` may differ
+    /// in length from that of `other`. The original text of `self` is returned, rather than its
+    /// folded form, and a character whose folded form only partially overlaps is never included,
+    /// so the overlap is shrunk to the longest one covering whole characters of both strings.
+    ///
+    /// This method is only available when the `caseless` and `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("AN DER STRASSE".overlap_end_folded("straße und"), "STRASSE");
+    /// // Half of a folded "ß" is never part of the overlap.
+    /// assert_eq!("MAS".overlap_end_folded("ßen"), "");
+    /// ```
+    ///
+    /// [`overlap_end_fold`]: StrOverlap::overlap_end_fold
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_folded(&self, other: &Self) -> String;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, skipping over the
+    /// characters for which `ignore` returns `true`.
+    ///
+    /// Both strings are compared as if every ignored character had been removed from them, so the
+    /// ignored characters may be placed differently in each string. The returned string slice is
+    /// a reference to the substring contained in `self`, and runs from the first to the last
+    /// character of `self` that was compared as part of the overlap. This means ignored
+    /// characters at either edge of the overlap are excluded, while those between compared
+    /// characters are included. Note that the result is therefore not necessarily a suffix of
+    /// `self`.
+    ///
+    /// If no characters other than ignored ones overlap, an empty string slice is returned.
+    ///
+    /// This takes quadratic time in the worst case.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a b c".overlap_end_ignoring("b c d", |c| c == ' '), "b c");
+    /// assert_eq!(
+    ///     "foo, bar.".overlap_end_ignoring("bar! baz", |c| ",.!".contains(c)),
+    ///     "bar"
+    /// );
+    /// ```
+    #[must_use]
+    fn overlap_end_ignoring<F>(&self, other: &Self, ignore: F) -> &Self
+    where
+        F: Fn(char) -> bool;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, ignoring a byte
+    /// order mark at the start of `self` and at the end of `other`.
+    ///
+    /// Text exported by some tools begins with a UTF-8 byte order mark (BOM), U+FEFF, which would
+    /// otherwise prevent the text from overlapping with whatever it is joined after. A BOM is
+    /// ignored at the end of the string that comes first and at the start of the string that
+    /// comes second, but is compared as usual anywhere else. The returned string slice is a
+    /// reference to the substring contained in `self`, and includes its ignored BOM unless it is
+    /// empty. BOMs alone are never an overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("\u{feff}bcd".overlap_start_ignore_bom("abc"), "\u{feff}bc");
+    /// ```
+    #[must_use]
+    fn overlap_start_ignore_bom(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, ignoring a byte
+    /// order mark at the end of `self` and at the start of `other`.
+    ///
+    /// Text exported by some tools begins with a UTF-8 byte order mark (BOM), U+FEFF, which would
+    /// otherwise prevent the text from overlapping with whatever it is joined after. A BOM is
+    /// ignored at the end of the string that comes first and at the start of the string that
+    /// comes second, but is compared as usual anywhere else. The returned string slice is a
+    /// reference to the substring contained in `self`, and includes its ignored BOM unless it is
+    /// empty. BOMs alone are never an overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".overlap_end_ignore_bom("\u{feff}bcd"), "bc");
+    /// assert_eq!("abc\u{feff}".overlap_end_ignore_bom("bcd"), "bc\u{feff}");
+    /// ```
+    #[must_use]
+    fn overlap_end_ignore_bom(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, truncated to at
+    /// most `max_chars` characters.
+    ///
+    /// This is the same as [`Overlap::overlap_start_max`], except that the overlap is measured in
+    /// characters rather than bytes. If the overlap is longer, only its leading `max_chars`
+    /// characters are returned, so that it is still a prefix of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("語a日bc本".overlap_start_limit_chars("b日本語a", 1), "語");
+    /// ```
+    #[must_use]
+    fn overlap_start_limit_chars(&self, other: &Self, max_chars: usize) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, truncated to at
+    /// most `max_chars` characters.
+    ///
+    /// This is the same as [`Overlap::overlap_end_max`], except that the overlap is measured in
+    /// characters rather than bytes. If the overlap is longer, only its trailing `max_chars`
+    /// characters are returned, so that it is still a suffix of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b日本語a".overlap_end_limit_chars("日本語a日bc本", 3), "本語a");
+    /// ```
+    #[must_use]
+    fn overlap_end_limit_chars(&self, other: &Self, max_chars: usize) -> &Self;
+
+    /// Returns the longest overlap found at the start of `self` and the end of any of `others`,
+    /// along with the index of that candidate within `others`.
+    ///
+    /// If multiple candidates share the longest overlap, the lowest index is returned. If none of
+    /// them overlap, including when `others` is empty, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("cde".overlap_start_multi(&["xc", "abcd", "bcd"]), Some((1, "cd")));
+    /// assert_eq!("cde".overlap_start_multi(&["x", "y"]), None);
+    /// ```
+    #[must_use]
+    fn overlap_start_multi(&self, others: &[&Self]) -> Option<(usize, &Self)>;
+
+    /// Returns the longest overlap found at the end of `self` and the start of any of `others`,
+    /// along with the index of that candidate within `others`.
+    ///
+    /// If multiple candidates share the longest overlap, the lowest index is returned. If none of
+    /// them overlap, including when `others` is empty, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".overlap_end_multi(&["cx", "bcd", "bce"]), Some((1, "bc")));
+    /// assert_eq!("abc".overlap_end_multi(&["x", "y"]), None);
+    /// ```
+    #[must_use]
+    fn overlap_end_multi(&self, others: &[&Self]) -> Option<(usize, &Self)>;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of
+    /// `other` when compared using `collator`, within `self` and within `other` respectively.
+    ///
+    /// This is the same as [`overlap_end_collated_indices`] with `self` and `other` swapped, and
+    /// the returned ranges swapped to match.
+    ///
+    /// This method is only available when the `icu_collator` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate icu_collator;
+    /// extern crate icu_locale_core;
+    /// # extern crate str_overlap;
+    ///
+    /// use icu_collator::options::{CollatorOptions, Strength};
+    /// use icu_collator::Collator;
+    /// use icu_locale_core::Locale;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// # fn main() {
+    /// let locale: Locale = "da".parse().unwrap();
+    /// let mut options = CollatorOptions::default();
+    /// options.strength = Some(Strength::Primary);
+    /// let collator = Collator::try_new(locale.into(), options).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "ålborg".overlap_start_collated_indices("Haa", &collator),
+    ///     (0..2, 1..3)
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [`overlap_end_collated_indices`]: StrOverlap::overlap_end_collated_indices
+    #[cfg(feature = "icu_collator")]
+    #[must_use]
+    fn overlap_start_collated_indices(
+        &self,
+        other: &Self,
+        collator: &CollatorBorrowed,
+    ) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when compared using `collator`, within `self` and within `other` respectively.
+    ///
+    /// Candidate overlaps are compared as whole strings using [`CollatorBorrowed::compare`], so
+    /// that sequences of different lengths which collate as equal, such as "aa" and "å" in
+    /// Danish or "œ" and "oe", may overlap. The longest suffix of `self` that is equal to a
+    /// non-empty prefix of `other` is found, and within `other` the longest such prefix is used,
+    /// so that characters ignored by the collator, such as combining marks, aren't split from
+    /// the character before them. If nothing overlaps, the ranges are empty and located at the
+    /// end of `self` and the start of `other`.
+    ///
+    /// The comparison uses `collator` as configured. To compare primary collation elements only,
+    /// ignoring differences of case and accents as is usually intended, create `collator` with
+    /// [`Strength::Primary`].
+    ///
+    /// Every pair of candidates may be compared, so this takes *O(n·m)* comparisons in the worst
+    /// case, where *n* and *m* are the lengths of `self` and `other`.
+    ///
+    /// This method is only available when the `icu_collator` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate icu_collator;
+    /// extern crate icu_locale_core;
+    /// # extern crate str_overlap;
+    ///
+    /// use icu_collator::options::{CollatorOptions, Strength};
+    /// use icu_collator::Collator;
+    /// use icu_locale_core::Locale;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// # fn main() {
+    /// let locale: Locale = "da".parse().unwrap();
+    /// let mut options = CollatorOptions::default();
+    /// options.strength = Some(Strength::Primary);
+    /// let collator = Collator::try_new(locale.into(), options).unwrap();
+    ///
+    /// assert_eq!("Haa".overlap_end_collated_indices("ålborg", &collator), (1..3, 0..2));
+    /// # }
+    /// ```
+    ///
+    /// [`CollatorBorrowed::compare`]: icu_collator::CollatorBorrowed::compare
+    /// [`Strength::Primary`]: icu_collator::options::Strength::Primary
+    #[cfg(feature = "icu_collator")]
+    #[must_use]
+    fn overlap_end_collated_indices(
+        &self,
+        other: &Self,
+        collator: &CollatorBorrowed,
+    ) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when every run of whitespace is treated as equal, within `self` and within `other`
+    /// respectively.
+    ///
+    /// Any run of one or more whitespace characters, as defined by [`char::is_whitespace`], is
+    /// compared as equal to any other run, regardless of which characters it contains or how long
+    /// it is. A run is never equal to no whitespace at all. Runs are matched whole, so a run at
+    /// either bound of the overlap is included entirely in both ranges, and the ranges may have
+    /// different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b\nc d".overlap_start_whitespace_indices("a b  c"), (0..3, 2..6));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_whitespace_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when every run of whitespace is treated as equal, within `self` and within `other`
+    /// respectively.
+    ///
+    /// Any run of one or more whitespace characters, as defined by [`char::is_whitespace`], is
+    /// compared as equal to any other run, regardless of which characters it contains or how long
+    /// it is. A run is never equal to no whitespace at all. Runs are matched whole, so a run at
+    /// either bound of the overlap is included entirely in both ranges, and the ranges may have
+    /// different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a b  c".overlap_end_whitespace_indices("b\nc d"), (2..6, 0..3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_whitespace_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when ANSI escape sequences are skipped, within `self` and within `other` respectively.
+    ///
+    /// This is the same as [`overlap_end_ansi_indices`] with `self` and `other` swapped, and the
+    /// returned ranges swapped to match.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!(
+    ///     "b\u{1b}[32mcd".overlap_start_ansi_indices("ab\u{1b}[0mc"),
+    ///     (0..7, 1..7)
+    /// );
+    /// ```
+    ///
+    /// [`overlap_end_ansi_indices`]: StrOverlap::overlap_end_ansi_indices
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_ansi_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when ANSI escape sequences are skipped, within `self` and within `other` respectively.
+    ///
+    /// Only the visible characters of both strings are compared, so text captured from a terminal
+    /// overlaps even if its color and cursor codes differ between captures. CSI sequences, such
+    /// as `ESC [ 31 m`, and OSC sequences terminated by `BEL` or `ESC \\`, are skipped. An
+    /// escape sequence that is cut off at either end of a string, or is otherwise malformed, is
+    /// compared as literal text.
+    ///
+    /// The range within `self` is a suffix running from its first compared character, and the
+    /// range within `other` is a prefix running to its last compared character, so any escape
+    /// sequences within either span are included in it. If nothing overlaps, the ranges are empty
+    /// and located at the end of `self` and the start of `other`.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!(
+    ///     "ab\u{1b}[0mc".overlap_end_ansi_indices("b\u{1b}[32mcd"),
+    ///     (1..7, 0..7)
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_ansi_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when HTML character references are treated as equal to the characters they refer to,
+    /// within `self` and within `other` respectively.
+    ///
+    /// The named references `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`, and `&nbsp;` are
+    /// recognized, along with decimal and hexadecimal numeric references such as `&#38;` and
+    /// `&#x26;`. Any other text, including a reference that is missing its terminating `';'`
+    /// because it was cut off at the edge of a string, is compared literally. References are
+    /// matched whole, so neither range ever begins or ends within one, and the ranges may have
+    /// different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("&lt;b&gt;c".overlap_start_html_entity_indices("a<b"), (0..5, 1..3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_html_entity_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when HTML character references are treated as equal to the characters they refer to,
+    /// within `self` and within `other` respectively.
+    ///
+    /// The named references `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`, and `&nbsp;` are
+    /// recognized, along with decimal and hexadecimal numeric references such as `&#38;` and
+    /// `&#x26;`. Any other text, including a reference that is missing its terminating `';'`
+    /// because it was cut off at the edge of a string, is compared literally. References are
+    /// matched whole, so neither range ever begins or ends within one, and the ranges may have
+    /// different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("Tom &amp; J".overlap_end_html_entity_indices("& Jerry"), (4..11, 0..3));
+    /// // A reference is never split.
+    /// assert_eq!("Tom &am".overlap_end_html_entity_indices("p; Jerry"), (7..7, 0..0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_html_entity_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when JSON string escapes are treated as equal to the characters they stand for, within
+    /// `self` and within `other` respectively.
+    ///
+    /// This is the same as [`overlap_end_json_escape_indices`] with `self` and `other` swapped,
+    /// and the returned ranges swapped to match.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!(r#""b" c"#.overlap_start_json_escape_indices(r#"a \"b\""#), (0..3, 2..7));
+    /// ```
+    ///
+    /// [`overlap_end_json_escape_indices`]: StrOverlap::overlap_end_json_escape_indices
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_json_escape_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when JSON string escapes are treated as equal to the characters they stand for, within
+    /// `self` and within `other` respectively.
+    ///
+    /// The escapes `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t`, and `\uXXXX` are recognized,
+    /// including surrogate pairs written as two consecutive `\uXXXX` escapes. Any other text,
+    /// including an invalid escape or one cut off at the edge of a string, is compared literally.
+    /// Escapes are matched whole, so neither range ever begins or ends within one, and the ranges
+    /// may have different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!(r"a\tb".overlap_end_json_escape_indices("\tbc"), (1..4, 0..2));
+    /// // An escape is never split.
+    /// assert_eq!(r"caf\u00".overlap_end_json_escape_indices("e9"), (7..7, 0..0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_json_escape_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when each `%XY` escape is treated as equal to the byte it encodes, within `self` and within
+    /// `other` respectively.
+    ///
+    /// An escape is decoded when it is followed by two hex digits, in either case. Any other
+    /// `'%'`, including one whose escape is cut off at the edge of a string, is compared
+    /// literally. Escapes are matched whole, so neither range ever begins or ends within one, and
+    /// the ranges may have different lengths. The decoded bytes are compared, but a raw multi-byte
+    /// character is never split.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b%2Fc/d".overlap_start_percent_decoded_indices("a/b/c"), (0..5, 2..5));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when each `%XY` escape is treated as equal to the byte it encodes, within `self` and within
+    /// `other` respectively.
+    ///
+    /// An escape is decoded when it is followed by two hex digits, in either case. Any other
+    /// `'%'`, including one whose escape is cut off at the edge of a string, is compared
+    /// literally. Escapes are matched whole, so neither range ever begins or ends within one, and
+    /// the ranges may have different lengths. The decoded bytes are compared, but a raw multi-byte
+    /// character is never split.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a/b/c".overlap_end_percent_decoded_indices("b%2fc/d"), (2..5, 0..5));
+    /// // An escape is never split.
+    /// assert_eq!("a/b%2".overlap_end_percent_decoded_indices("%2Fc"), (5..5, 0..0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// after expanding tabs, within `self` and within `other` respectively.
+    ///
+    /// Tabs are expanded to spaces while comparing, with tab stops every `tab_width` columns, so a
+    /// tab is equal to the spaces that reach the same tab stop. Columns are counted in characters
+    /// from the start of each string and of each line. A tab is always matched whole, so an
+    /// overlap that would begin or end partway through the spaces a tab expands to is shrunk
+    /// until it doesn't. The ranges may have different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Panics
+    /// Panics if `tab_width` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("    b".overlap_start_tab_expanded_indices("a\n\t", 4), (0..4, 2..3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_tab_expanded_indices(
+        &self,
+        other: &Self,
+        tab_width: usize,
+    ) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// after expanding tabs, within `self` and within `other` respectively.
+    ///
+    /// Tabs are expanded to spaces while comparing, with tab stops every `tab_width` columns, so a
+    /// tab is equal to the spaces that reach the same tab stop. Columns are counted in characters
+    /// from the start of each string and of each line. A tab is always matched whole, so an
+    /// overlap that would begin or end partway through the spaces a tab expands to is shrunk
+    /// until it doesn't. The ranges may have different lengths.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Panics
+    /// Panics if `tab_width` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a\n\tb".overlap_end_tab_expanded_indices("    b", 4), (2..4, 0..5));
+    /// // Part of a tab is never part of the overlap.
+    /// assert_eq!("a\n\t".overlap_end_tab_expanded_indices("  b", 4), (3..3, 0..0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_tab_expanded_indices(
+        &self,
+        other: &Self,
+        tab_width: usize,
+    ) -> (Range<usize>, Range<usize>);
+
+    /// Returns the whole lines found at the start of `self` that are equal to the lines at the
+    /// end of `other`.
+    ///
+    /// Both strings are split into lines on `'\n'`. A trailing `'\n'` ends the final line, and
+    /// does not begin another empty one. Lines are compared without their terminating `'\n'`, but
+    /// the returned string slice includes the terminator of the last overlapping line if `self`
+    /// has one.
+    ///
+    /// The largest number of overlapping lines is always returned, even when lines are repeated.
+    ///
+    /// To compare lines that are already split, use [`Overlap`] on a slice of them instead.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b\nc\nd\n".overlap_start_lines("a\nb\nc"), "b\nc\n");
+    /// // Partial lines are never part of the overlap.
+    /// assert_eq!("bc\nd".overlap_start_lines("a\nc"), "");
+    /// ```
+    #[must_use]
+    fn overlap_start_lines(&self, other: &Self) -> &Self;
+
+    /// Returns the whole lines found at the end of `self` that are equal to the lines at the start
+    /// of `other`.
+    ///
+    /// Both strings are split into lines on `'\n'`. A trailing `'\n'` ends the final line, and
+    /// does not begin another empty one. Lines are compared without their terminating `'\n'`, but
+    /// the returned string slice includes the terminator of the last overlapping line if `self`
+    /// has one.
+    ///
+    /// The largest number of overlapping lines is always returned, even when lines are repeated.
+    ///
+    /// To compare lines that are already split, use [`Overlap`] on a slice of them instead.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a\nb\nc\n".overlap_end_lines("b\nc\nd\n"), "b\nc\n");
+    /// // Partial lines are never part of the overlap.
+    /// assert_eq!("a\nbc".overlap_end_lines("c\nd"), "");
+    /// ```
+    #[must_use]
+    fn overlap_end_lines(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, beginning at the
+    /// start of a line in `other` and ending at the end of a line in `self`.
+    ///
+    /// Lines are terminated by either `"\r\n"` or `'\n'`. A line ends both before and after its
+    /// terminator, so the overlap may end with or without it. The start and end of a string are
+    /// always line bounds, meaning the last line of `other` does not need a terminator. If the
+    /// overlap found by [`overlap_start`] splits a line, the overlap shrinks to the next one that
+    /// doesn't, or to an empty string slice if there is none.
+    ///
+    /// Unlike [`overlap_start_lines`], the overlap is compared byte for byte, including any
+    /// terminators.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b\r\nc\r\n".overlap_start_lines_aligned("a\r\nb"), "b");
+    /// // "c" is only part of the line "bc" in `other`.
+    /// assert_eq!("c\r\nd".overlap_start_lines_aligned("a\r\nbc"), "");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [`overlap_start_lines`]: StrOverlap::overlap_start_lines
+    #[must_use]
+    fn overlap_start_lines_aligned(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, beginning at the
+    /// start of a line in `self` and ending at the end of a line in `other`.
+    ///
+    /// Lines are terminated by either `"\r\n"` or `'\n'`. A line ends both before and after its
+    /// terminator, so the overlap may end with or without it. The start and end of a string are
+    /// always line bounds, meaning the last line of `self` does not need a terminator. If the
+    /// overlap found by [`overlap_end`] splits a line, the overlap shrinks to the next one that
+    /// doesn't, or to an empty string slice if there is none.
+    ///
+    /// Unlike [`overlap_end_lines`], the overlap is compared byte for byte, including any
+    /// terminators.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a\r\nb\r\n".overlap_end_lines_aligned("b\r\nc\r\n"), "b\r\n");
+    /// // The last line of `self` has no terminator.
+    /// assert_eq!("a\r\nb".overlap_end_lines_aligned("b\r\nc"), "b");
+    /// // "b" is only part of the line "bc" in `other`.
+    /// assert_eq!("a\r\nb".overlap_end_lines_aligned("bc\r\nd"), "");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`overlap_end_lines`]: StrOverlap::overlap_end_lines
+    #[must_use]
+    fn overlap_end_lines_aligned(&self, other: &Self) -> &Self;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when `"\r\n"` and `'\n'` line endings are treated as equal, within `self` and within
+    /// `other` respectively.
+    ///
+    /// Since the line endings may be encoded with different lengths, the ranges may have
+    /// different lengths. A `'\r'` at the end of `other` may match either a `'\r'` or a line
+    /// ending, since `other` may have been cut between the `'\r'` and `'\n'` of a `"\r\n"`, and
+    /// whichever gives the longer overlap is used. Otherwise, line endings are always matched
+    /// whole.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("b\nc\nd".overlap_start_newline_insensitive("a\r\nb\r\nc"), (0..3, 3..7));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_newline_insensitive(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when `"\r\n"` and `'\n'` line endings are treated as equal, within `self` and within
+    /// `other` respectively.
+    ///
+    /// Since the line endings may be encoded with different lengths, the ranges may have
+    /// different lengths. A `'\r'` at the end of `self` may match either a `'\r'` or a line
+    /// ending, since `self` may have been cut between the `'\r'` and `'\n'` of a `"\r\n"`, and
+    /// whichever gives the longer overlap is used. Otherwise, line endings are always matched
+    /// whole.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("a\r\nb\r\nc".overlap_end_newline_insensitive("b\nc\nd"), (3..7, 0..3));
+    /// // `self` ends partway through a line ending.
+    /// assert_eq!("a\nb\r".overlap_end_newline_insensitive("b\nc"), (2..4, 0..2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_newline_insensitive(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, beginning and
+    /// ending on word bounds.
+    ///
+    /// The overlap must begin on a word bound of `other` and end on a word bound of `self`. If the
+    /// overlap found by [`overlap_start`] splits a word in either string, the overlap shrinks to
+    /// the next one that doesn't, or to an empty string slice if there is none.
+    ///
+    /// The start and end of a string are always word bounds. When the `unicode-segmentation`
+    /// feature is enabled, the word bounds of [Unicode Standard Annex #29] are used. Otherwise,
+    /// words are separated by ASCII whitespace and punctuation.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("cat sat".overlap_start_words("the cat"), "cat");
+    /// // "he" ends in the middle of "herd".
+    /// assert_eq!("herd".overlap_start_words("he"), "");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/
+    #[must_use]
+    fn overlap_start_words(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, beginning and
+    /// ending on word bounds.
+    ///
+    /// The overlap must begin on a word bound of `self` and end on a word bound of `other`. If the
+    /// overlap found by [`overlap_end`] splits a word in either string, the overlap shrinks to the
+    /// next one that doesn't, or to an empty string slice if there is none.
+    ///
+    /// The start and end of a string are always word bounds. When the `unicode-segmentation`
+    /// feature is enabled, the word bounds of [Unicode Standard Annex #29] are used. Otherwise,
+    /// words are separated by ASCII whitespace and punctuation.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("the cat".overlap_end_words("cat sat"), "cat");
+    /// // "he" begins in the middle of "the".
+    /// assert_eq!("the".overlap_end_words("herd"), "");
+    /// // "overl" ends in the middle of "overlap".
+    /// assert_eq!("jumps overl".overlap_end_words("overlap"), "");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/
+    #[must_use]
+    fn overlap_end_words(&self, other: &Self) -> &Self;
+
+    /// Returns the whole tokens found at the end of `self` that are equal to the tokens at the
+    /// start of `other`.
+    ///
+    /// Both strings are split into tokens by `tokenizer`, which must return substrings of the
+    /// string it is given, in order. Tokens are compared by their contents, so any text the
+    /// tokenizer skips, such as whitespace, does not affect the overlap. The returned string slice
+    /// spans from the start of the first overlapping token in `self` to the end of the last one,
+    /// including any text between them.
+    ///
+    /// The largest number of overlapping tokens is always returned, even when tokens are repeated.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Panics
+    /// Panics if `tokenizer` returns a token of `self` that is not a substring of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!(
+    ///     "foo bar baz".overlap_end_tokens("bar  baz qux", |s| s.split_whitespace()),
+    ///     "bar baz"
+    /// );
+    /// // Partial tokens are never part of the overlap.
+    /// assert_eq!("foo bar".overlap_end_tokens("ar baz", |s| s.split_whitespace()), "");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_tokens<'a, F, I>(&'a self, other: &'a Self, tokenizer: F) -> &'a Self
+    where
+        F: Fn(&'a str) -> I,
+        I: Iterator<Item = &'a str>;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, without splitting
+    /// any extended grapheme clusters.
+    ///
+    /// If the overlap found by [`overlap_start`] would split a grapheme cluster in either string,
+    /// such as by separating a letter from a combining accent that follows it, the overlap shrinks
+    /// to the next one that doesn't.
+    ///
+    /// This method is only available when the `unicode-segmentation` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // The "e" at the end of `other` is combined with an accent in `self`.
+    /// assert_eq!("e\u{301}t".overlap_start_graphemes("the"), "");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    fn overlap_start_graphemes(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, without splitting
+    /// any extended grapheme clusters.
+    ///
+    /// If the overlap found by [`overlap_end`] would split a grapheme cluster in either string,
+    /// such as by separating a letter from a combining accent that follows it, the overlap shrinks
+    /// to the next one that doesn't.
+    ///
+    /// This method is only available when the `unicode-segmentation` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // The accent at the start of `other` belongs to the "e" before it in `self`.
+    /// assert_eq!("cafe\u{301}".overlap_end_graphemes("\u{301}s"), "");
+    /// assert_eq!("cafe\u{301}".overlap_end_graphemes("e\u{301}s"), "e\u{301}");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    fn overlap_end_graphemes(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, after normalizing
+    /// both to Unicode Normalization Form C (NFC).
+    ///
+    /// Canonically equivalent strings, such as a precomposed "é" and an "e" followed by a
+    /// combining acute accent, are therefore treated as equal. Unlike [`overlap_start`], this
+    /// method allocates and returns an owned [`String`], since normalizing can change the bytes and
+    /// length of the overlap, so it can't be borrowed from `self`. The returned overlap is always
+    /// in NFC.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("e\u{301}s".overlap_start_nfc("caf\u{e9}"), "\u{e9}");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [`String`]: alloc::string::String
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_nfc(&self, other: &Self) -> String;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, after normalizing
+    /// both to Unicode Normalization Form C (NFC).
+    ///
+    /// Canonically equivalent strings, such as a precomposed "é" and an "e" followed by a
+    /// combining acute accent, are therefore treated as equal. Unlike [`overlap_end`], this method
+    /// allocates and returns an owned [`String`], since normalizing can change the bytes and
+    /// length of the overlap, so it can't be borrowed from `self`. The returned overlap is always
+    /// in NFC.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("caf\u{e9}".overlap_end_nfc("e\u{301}s"), "\u{e9}");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`String`]: alloc::string::String
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_nfc(&self, other: &Self) -> String;
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// under canonical equivalence, within `self` and within `other` respectively.
+    ///
+    /// Both strings are normalized to NFC while comparing, as in [`overlap_start_nfc`], but the
+    /// overlap is reported as ranges within the original strings. The ranges may have different
+    /// lengths, since canonically equivalent text may be encoded differently. Their bounds never
+    /// lie within a combining character sequence of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("e\u{301}s".overlap_start_nfc_indices("caf\u{e9}"), (0..3, 3..5));
+    /// ```
+    ///
+    /// [`overlap_start_nfc`]: StrOverlap::overlap_start_nfc
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_nfc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// under canonical equivalence, within `self` and within `other` respectively.
+    ///
+    /// Both strings are normalized to NFC while comparing, as in [`overlap_end_nfc`], but the
+    /// overlap is reported as ranges within the original strings. The ranges may have different
+    /// lengths, since canonically equivalent text may be encoded differently. Their bounds never
+    /// lie within a combining character sequence of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("caf\u{e9}".overlap_end_nfc_indices("e\u{301}s"), (3..5, 0..3));
+    /// // The overlap never ends within a combining character sequence.
+    /// assert_eq!("cafe".overlap_end_nfc_indices("e\u{301}s"), (4..4, 0..0));
+    /// ```
+    ///
+    /// [`overlap_end_nfc`]: StrOverlap::overlap_end_nfc
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_nfc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// under compatibility equivalence, within `self` and within `other` respectively.
+    ///
+    /// Both strings are normalized to Unicode Normalization Form KC (NFKC) while comparing, so
+    /// compatibility equivalent text, such as the ligature "ﬁ" and "fi", or fullwidth and plain
+    /// Latin letters, is treated as equal. This includes all canonically equivalent text. The
+    /// ranges may have very different lengths, and their bounds never lie within a combining
+    /// character sequence, or within a single character, of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("\u{fb01}le".overlap_start_nfkc_indices("profi"), (0..3, 3..5));
+    /// ```
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_nfkc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// under compatibility equivalence, within `self` and within `other` respectively.
+    ///
+    /// Both strings are normalized to Unicode Normalization Form KC (NFKC) while comparing, so
+    /// compatibility equivalent text, such as the ligature "ﬁ" and "fi", or fullwidth and plain
+    /// Latin letters, is treated as equal. This includes all canonically equivalent text. The
+    /// ranges may have very different lengths, and their bounds never lie within a combining
+    /// character sequence, or within a single character, of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("pro\u{fb01}".overlap_end_nfkc_indices("file"), (3..6, 0..2));
+    /// // Part of a ligature is never part of the overlap.
+    /// assert_eq!("pro\u{fb01}".overlap_end_nfkc_indices("ile"), (6..6, 0..0));
+    /// ```
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_nfkc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// regardless of diacritics, within `self` and within `other` respectively.
+    ///
+    /// Both strings are decomposed to Unicode Normalization Form D (NFD), and every combining mark
+    /// is removed, while comparing. Accented and unaccented letters, such as "é" and "e", are
+    /// therefore treated as equal, whether the accent is precomposed or not. The ranges may have
+    /// different lengths, and their bounds never lie within a combining character sequence of
+    /// either string, so any marks following the last letter of the overlap are included.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let resume = "resume draft";
+    ///
+    /// assert_eq!(
+    ///     resume.overlap_start_ignore_diacritics_indices("r\u{e9}sum\u{e9}"),
+    ///     (0..6, 0..8)
+    /// );
+    /// ```
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_ignore_diacritics_indices(&self, other: &Self)
+        -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// regardless of diacritics, within `self` and within `other` respectively.
+    ///
+    /// Both strings are decomposed to Unicode Normalization Form D (NFD), and every combining mark
+    /// is removed, while comparing. Accented and unaccented letters, such as "é" and "e", are
+    /// therefore treated as equal, whether the accent is precomposed or not. The ranges may have
+    /// different lengths, and their bounds never lie within a combining character sequence of
+    /// either string, so any marks following the last letter of the overlap are included.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let resume = "my r\u{e9}sum\u{e9}";
+    ///
+    /// assert_eq!(resume.overlap_end_ignore_diacritics_indices("resume"), (3..11, 0..6));
+    /// ```
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_ignore_diacritics_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// regardless of character width, within `self` and within `other` respectively.
+    ///
+    /// Fullwidth and halfwidth forms, such as the fullwidth "Ａ" and the halfwidth katakana "ｶ",
+    /// are mapped to their usual width while comparing, and both strings are normalized to Unicode
+    /// Normalization Form C (NFC). A halfwidth voiced sound mark therefore combines with the kana
+    /// before it, so "ｶﾞ" is equal to "ガ". Unlike [`overlap_start_nfkc_indices`], no other
+    /// compatibility mappings are applied. The ranges may have different lengths, and their
+    /// bounds never lie within a combining character sequence of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("BCD".overlap_start_width_insensitive_indices("\u{ff21}\u{ff22}"), (0..1, 3..6));
+    /// ```
+    ///
+    /// [`overlap_start_nfkc_indices`]: StrOverlap::overlap_start_nfkc_indices
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_start_width_insensitive_indices(&self, other: &Self)
+        -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// regardless of character width, within `self` and within `other` respectively.
+    ///
+    /// Fullwidth and halfwidth forms, such as the fullwidth "Ａ" and the halfwidth katakana "ｶ",
+    /// are mapped to their usual width while comparing, and both strings are normalized to Unicode
+    /// Normalization Form C (NFC). A halfwidth voiced sound mark therefore combines with the kana
+    /// before it, so "ｶﾞ" is equal to "ガ". Unlike [`overlap_end_nfkc_indices`], no other
+    /// compatibility mappings are applied. The ranges may have different lengths, and their
+    /// bounds never lie within a combining character sequence of either string.
+    ///
+    /// This method is only available when the `unicode-normalization` and `alloc` features are
+    /// enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // The halfwidth "ｶﾞ" and the fullwidth "ガ".
+    /// let halfwidth = "x\u{ff76}\u{ff9e}";
+    ///
+    /// assert_eq!(halfwidth.overlap_end_width_insensitive_indices("\u{30ac}"), (1..7, 0..3));
+    /// ```
+    ///
+    /// [`overlap_end_nfkc_indices`]: StrOverlap::overlap_end_nfkc_indices
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[must_use]
+    fn overlap_end_width_insensitive_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the start of `self` and the end of `other`
+    /// when visually confusable characters are treated as equal, within `self` and within `other`
+    /// respectively.
+    ///
+    /// Both strings are mapped to their confusable skeletons, as defined by Unicode Technical
+    /// Standard #39, while comparing. Lookalike characters from different scripts, such as the
+    /// Latin "a", the Cyrillic "а", and the Greek "α", are therefore treated as equal, as are
+    /// some sequences, such as "rn" and "m". The ranges may have different lengths, and their
+    /// bounds never lie within a combining character sequence, or within a single character, of
+    /// either string.
+    ///
+    /// This method is only available when the `unicode-security`, `unicode-normalization`, and
+    /// `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // "pal" with a Cyrillic "р" and "а".
+    /// assert_eq!("\u{440}\u{430}l".overlap_start_confusable_indices("paypa"), (0..4, 3..5));
+    /// ```
+    #[cfg(all(
+        feature = "unicode-security",
+        feature = "unicode-normalization",
+        feature = "alloc"
+    ))]
+    #[must_use]
+    fn overlap_start_confusable_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of `other`
+    /// when visually confusable characters are treated as equal, within `self` and within `other`
+    /// respectively.
+    ///
+    /// Both strings are mapped to their confusable skeletons, as defined by Unicode Technical
+    /// Standard #39, while comparing. Lookalike characters from different scripts, such as the
+    /// Latin "a", the Cyrillic "а", and the Greek "α", are therefore treated as equal, as are
+    /// some sequences, such as "rn" and "m". The ranges may have different lengths, and their
+    /// bounds never lie within a combining character sequence, or within a single character, of
+    /// either string.
+    ///
+    /// This method is only available when the `unicode-security`, `unicode-normalization`, and
+    /// `alloc` features are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // "pay" with a Cyrillic "р" and "а".
+    /// let lookalike = "my \u{440}\u{430}y";
+    ///
+    /// assert_eq!(lookalike.overlap_end_confusable_indices("paypal"), (3..8, 0..3));
+    /// // "m" is confusable with "rn", but only as a whole.
+    /// assert_eq!("arn".overlap_end_confusable_indices("me"), (1..3, 0..1));
+    /// assert_eq!("am".overlap_end_confusable_indices("ne"), (2..2, 0..0));
+    /// ```
+    #[cfg(all(
+        feature = "unicode-security",
+        feature = "unicode-normalization",
+        feature = "alloc"
+    ))]
+    #[must_use]
+    fn overlap_end_confusable_indices(&self, other: &Self) -> (Range<usize>, Range<usize>);
+
+    /// Returns an iterator over every non-empty overlap found at the end of `self` and the start
+    /// of `other`, from longest to shortest.
+    ///
+    /// Each yielded string slice is both a suffix of `self` and a prefix of `other`, and is a
+    /// reference to the substring contained in `self`. The first one yielded is the same as the
+    /// overlap returned by [`overlap_end`].
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let mut overlaps = "aba".overlaps_end("aba");
+    ///
+    /// assert_eq!(overlaps.next(), Some("aba"));
+    /// assert_eq!(overlaps.next(), Some("a"));
+    /// assert_eq!(overlaps.next(), None);
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn overlaps_end<'a>(&'a self, other: &'a Self) -> OverlapIter<'a>;
+
+    /// Returns the number of non-empty overlaps found at the end of `self` and the start of
+    /// `other`.
+    ///
+    /// This is the number of string slices yielded by [`overlaps_end`], without creating any of
+    /// them. Each counted overlap is on character bounds, so a match of only part of a multi-byte
+    /// character is never counted.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("aaa".overlap_end_count("aaa"), 3);
+    /// assert_eq!("aba".overlap_end_count("aba"), 2);
+    /// assert_eq!("abc".overlap_end_count("def"), 0);
+    /// ```
+    ///
+    /// [`overlaps_end`]: StrOverlap::overlaps_end
+    #[must_use]
+    fn overlap_end_count(&self, other: &Self) -> usize;
+
+    /// Returns the `n`th longest non-empty overlap found at the end of `self` and the start of
+    /// `other`, counting from zero, or `None` if there are no more than `n` of them.
+    ///
+    /// This is the same as calling `nth(n)` on [`overlaps_end`], but finds the overlap directly,
+    /// without creating any of the longer ones. The returned string slice is a reference to the
+    /// substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("aba".overlap_end_nth("aba", 0), Some("aba"));
+    /// assert_eq!("aba".overlap_end_nth("aba", 1), Some("a"));
+    /// assert_eq!("aba".overlap_end_nth("aba", 2), None);
+    /// ```
+    ///
+    /// [`overlaps_end`]: StrOverlap::overlaps_end
+    #[must_use]
+    fn overlap_end_nth(&self, other: &Self, n: usize) -> Option<&Self>;
+
+    /// Returns the shortest non-empty overlap found at the start of `self` and the end of `other`,
+    /// or an empty string slice if there is none.
+    ///
+    /// Unlike [`overlap_start`], which finds the longest overlap, this finds the shortest one.
+    /// The two only differ when `self` has a prefix that is also a suffix of the overlap, such as
+    /// "ab" in "abab". The returned string slice is a reference to the substring contained in
+    /// `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{Overlap, StrOverlap};
+    ///
+    /// assert_eq!("abab".overlap_start("xabab"), "abab");
+    /// assert_eq!("abab".shortest_overlap_start("xabab"), "ab");
+    /// assert_eq!("def".shortest_overlap_start("abc"), "");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn shortest_overlap_start(&self, other: &Self) -> &Self;
+
+    /// Returns the shortest non-empty overlap found at the end of `self` and the start of `other`,
+    /// or an empty string slice if there is none.
+    ///
+    /// Unlike [`overlap_end`], which finds the longest overlap, this finds the shortest one. This
+    /// is the same as the last item yielded by [`overlaps_end`]. The two only differ when the
+    /// longest overlap has a proper prefix that is also its suffix, such as "a" in "aba". The
+    /// returned string slice is a reference to the substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{Overlap, StrOverlap};
+    ///
+    /// assert_eq!("aba".overlap_end("aba"), "aba");
+    /// assert_eq!("aba".shortest_overlap_end("aba"), "a");
+    /// assert_eq!("abc".shortest_overlap_end("def"), "");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`overlaps_end`]: StrOverlap::overlaps_end
+    #[must_use]
+    fn shortest_overlap_end(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, along with its
+    /// byte range within each of them.
+    ///
+    /// The returned [`OverlapResult`] dereferences to the same string slice as [`overlap_start`],
+    /// so it can be used in its place.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let overlap = "bcd".overlap_start_result("abc");
+    ///
+    /// assert_eq!(&*overlap, "bc");
+    /// assert_eq!(overlap.self_range, 0..2);
+    /// assert_eq!(overlap.other_range, 1..3);
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn overlap_start_result<'a>(&'a self, other: &Self) -> OverlapResult<'a>;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, along with its
+    /// byte range within each of them.
+    ///
+    /// The returned [`OverlapResult`] dereferences to the same string slice as [`overlap_end`],
+    /// so it can be used in its place.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// let overlap = "abc".overlap_end_result("bcd");
+    ///
+    /// assert_eq!(&*overlap, "bc");
+    /// assert_eq!(overlap.self_range, 1..3);
+    /// assert_eq!(overlap.other_range, 0..2);
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn overlap_end_result<'a>(&'a self, other: &Self) -> OverlapResult<'a>;
+
+    /// Returns the overlap found at the start of `self` and the end of `other` as a [`Cow`].
+    ///
+    /// The overlap is found the same way as in [`overlap_start`], so the result is always
+    /// [`Cow::Borrowed`] and no allocation is performed. This method exists to share a return type
+    /// with comparison modes whose overlaps may need to be transformed, which return
+    /// [`Cow::Owned`] in that case.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_cow("abc"), Cow::Borrowed("bc"));
+    /// ```
+    ///
+    /// [`Cow`]: alloc::borrow::Cow
+    /// [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+    /// [`Cow::Owned`]: alloc::borrow::Cow::Owned
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_cow<'a>(&'a self, other: &Self) -> Cow<'a, str>;
+
+    /// Returns the overlap found at the end of `self` and the start of `other` as a [`Cow`].
+    ///
+    /// The overlap is found the same way as in [`overlap_end`], so the result is always
+    /// [`Cow::Borrowed`] and no allocation is performed. This method exists to share a return type
+    /// with comparison modes whose overlaps may need to be transformed, which return
+    /// [`Cow::Owned`] in that case.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".overlap_end_cow("bcd"), Cow::Borrowed("bc"));
+    /// ```
+    ///
+    /// [`Cow`]: alloc::borrow::Cow
+    /// [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+    /// [`Cow::Owned`]: alloc::borrow::Cow::Owned
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_cow<'a>(&'a self, other: &Self) -> Cow<'a, str>;
+
+    /// Returns an owned copy of the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// This is useful when `self` and `other` are temporaries, and the overlap must outlive them.
+    /// Exactly the length of the overlap is allocated.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // Both temporary `String`s are dropped at the end of the statement.
+    /// let overlap = String::from("bcd").overlap_start_owned(&String::from("abc"));
+    ///
+    /// assert_eq!(overlap, "bc");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_start_owned(&self, other: &Self) -> String;
+
+    /// Returns an owned copy of the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// This is useful when `self` and `other` are temporaries, and the overlap must outlive them.
+    /// Exactly the length of the overlap is allocated.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// // Both temporary `String`s are dropped at the end of the statement.
+    /// let overlap = String::from("abc").overlap_end_owned(&String::from("bcd"));
+    ///
+    /// assert_eq!(overlap, "bc");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn overlap_end_owned(&self, other: &Self) -> String;
+
+    /// Concatenates `self` and `other`, including the overlap at the end of `self` and the start
+    /// of `other` only once.
+    ///
+    /// The returned string consists of `self` followed by the part of `other` that follows the
+    /// overlap found by [`overlap_end`]. If there is no overlap, this is a plain concatenation.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abc".merge_end("bcd"), "abcd");
+    /// assert_eq!("abc".merge_end("def"), "abcdef");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn merge_end(&self, other: &Self) -> String;
+
+    /// Concatenates `other` and `self`, including the overlap at the start of `self` and the end
+    /// of `other` only once.
+    ///
+    /// The returned string consists of the part of `other` that precedes the overlap found by
+    /// [`overlap_start`], followed by `self`. If there is no overlap, this is a plain concatenation
+    /// of `other` and `self`.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("bcd".merge_start("abc"), "abcd");
+    /// assert_eq!("def".merge_start("abc"), "abcdef");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn merge_start(&self, other: &Self) -> String;
+
+    /// Concatenates `self` and `other`, including the overlap at the end of `self` and the start
+    /// of `other` only once, borrowing instead of allocating when possible.
+    ///
+    /// The result is the same as that of [`merge_end`], but no allocation is performed when it is
+    /// equal to one of the inputs:
+    /// - If `other` is entirely a suffix of `self` (including when `other` is empty),
+    ///   [`Cow::Borrowed`] of `self` is returned.
+    /// - Otherwise, if `self` is entirely a prefix of `other` (including when `self` is empty),
+    ///   [`Cow::Borrowed`] of `other` is returned.
+    ///
+    /// In every other case a concatenation is needed, and [`Cow::Owned`] is returned. Note that
+    /// `other` merely appearing somewhere within `self`, or vice versa, is not enough to borrow,
+    /// since the merge only joins the end of `self` to the start of `other`.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abcd".merge_end_cow("bcd"), Cow::Borrowed("abcd"));
+    /// assert_eq!("abc".merge_end_cow("bcd"), Cow::<str>::Owned("abcd".to_owned()));
+    /// ```
+    ///
+    /// [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+    /// [`Cow::Owned`]: alloc::borrow::Cow::Owned
+    /// [`merge_end`]: StrOverlap::merge_end
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn merge_end_cow<'a>(&'a self, other: &'a Self) -> Cow<'a, str>;
+
+    /// Concatenates `other` and `self`, including the overlap at the start of `self` and the end
+    /// of `other` only once, borrowing instead of allocating when possible.
+    ///
+    /// The result is the same as that of [`merge_start`], but no allocation is performed when it
+    /// is equal to one of the inputs:
+    /// - If `other` is entirely a prefix of `self` (including when `other` is empty),
+    ///   [`Cow::Borrowed`] of `self` is returned.
+    /// - Otherwise, if `self` is entirely a suffix of `other` (including when `self` is empty),
+    ///   [`Cow::Borrowed`] of `other` is returned.
+    ///
+    /// In every other case a concatenation is needed, and [`Cow::Owned`] is returned.
+    ///
+    /// This method is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use str_overlap::StrOverlap;
+    ///
+    /// assert_eq!("abcd".merge_start_cow("abc"), Cow::Borrowed("abcd"));
+    /// assert_eq!("bcd".merge_start_cow("abc"), Cow::<str>::Owned("abcd".to_owned()));
+    /// ```
+    ///
+    /// [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+    /// [`Cow::Owned`]: alloc::borrow::Cow::Owned
+    /// [`merge_start`]: StrOverlap::merge_start
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn merge_start_cow<'a>(&'a self, other: &'a Self) -> Cow<'a, str>;
+}
+
+/// Prevents [`StrOverlap`] from being implemented outside of this crate, so that methods may be
+/// added to it without breaking downstream code.
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+}
+
+/// String-specific overlap methods for string slices.
+impl StrOverlap for str {
+    #[inline]
+    fn overlap_start_char_count(&self, other: &Self) -> usize {
+        self.overlap_start(other).chars().count()
+    }
+
+    #[inline]
+    fn overlap_end_char_count(&self, other: &Self) -> usize {
+        self.overlap_end(other).chars().count()
+    }
+
+    #[inline]
+    fn overlap_start_range(&self, other: &Self) -> Range<usize> {
+        0..self.overlap_start_len(other)
+    }
+
+    #[inline]
+    fn overlap_end_range(&self, other: &Self) -> Range<usize> {
+        overlap_index(self, other)..self.len()
+    }
+
+    #[inline]
+    fn overlap_start_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let index = overlap_index(other, self);
+        (0..(other.len() - index), index..other.len())
+    }
+
+    #[inline]
+    fn overlap_end_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let index = overlap_index(self, other);
+        (index..self.len(), 0..(self.len() - index))
+    }
+
+    #[inline]
+    fn overlap_start_ignore_ascii_case(&self, other: &Self) -> &Self {
+        &self[..(other.len() - string_overlap_index_ignore_ascii_case(other, self))]
+    }
+
+    #[inline]
+    fn overlap_end_ignore_ascii_case(&self, other: &Self) -> &Self {
+        &self[string_overlap_index_ignore_ascii_case(self, other)..]
+    }
+
+    #[inline]
+    fn overlap_start_by<F>(&self, other: &Self, eq: F) -> OverlapRanges
+    where
+        F: FnMut(char, char) -> bool,
+    {
+        char_eq::overlap_start_by(self, other, eq)
+    }
+
+    #[inline]
+    fn overlap_end_by<F>(&self, other: &Self, eq: F) -> OverlapRanges
+    where
+        F: FnMut(char, char) -> bool,
+    {
+        char_eq::overlap_end_by(self, other, eq)
+    }
+
+    #[inline]
+    fn overlap_start_by_key<F, K>(&self, other: &Self, mut key: F) -> OverlapRanges
+    where
+        F: FnMut(char) -> K,
+        K: PartialEq,
+    {
+        char_eq::overlap_start_by(self, other, |a, b| key(a) == key(b))
+    }
+
+    #[inline]
+    fn overlap_end_by_key<F, K>(&self, other: &Self, mut key: F) -> OverlapRanges
+    where
+        F: FnMut(char) -> K,
+        K: PartialEq,
+    {
+        char_eq::overlap_end_by(self, other, |a, b| key(a) == key(b))
+    }
+
+    #[inline]
+    fn overlap_end_wildcard(&self, other: &Self, wildcard: char) -> &Self {
+        &self[wildcard::wildcard_overlap_index(self, other, wildcard)..]
+    }
+
+    #[inline]
+    fn overlap_start_lines(&self, other: &Self) -> &Self {
+        let index = line_overlap_index(other, self);
+        if index == other.len() {
+            return &self[..0];
+        }
+        // The overlapping lines of `self` have the same content as those of `other`, but their
+        // terminators may differ.
+        let overlap = &other.as_bytes()[index..];
+        let content_len = if overlap[overlap.len() - 1] == b'\n' {
+            overlap.len() - 1
+        } else {
+            overlap.len()
+        };
+        if self.as_bytes().get(content_len) == Some(&b'\n') {
+            &self[..(content_len + 1)]
+        } else {
+            &self[..content_len]
+        }
+    }
+
+    #[inline]
+    fn overlap_end_lines(&self, other: &Self) -> &Self {
+        &self[line_overlap_index(self, other)..]
+    }
+
+    #[inline]
+    fn overlap_start_lines_aligned(&self, other: &Self) -> &Self {
+        &self[..(other.len() - aligned_line_overlap_index(other, self))]
+    }
+
+    #[inline]
+    fn overlap_end_lines_aligned(&self, other: &Self) -> &Self {
+        &self[aligned_line_overlap_index(self, other)..]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_newline_insensitive(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = newline::newline_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_newline_insensitive(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        newline::newline_overlap_indices(self, other)
+    }
+
+    #[inline]
+    fn overlap_start_words(&self, other: &Self) -> &Self {
+        &self[..(other.len() - word::word_overlap_index(other, self))]
+    }
+
+    #[inline]
+    fn overlap_end_words(&self, other: &Self) -> &Self {
+        &self[word::word_overlap_index(self, other)..]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_tokens<'a, F, I>(&'a self, other: &'a Self, tokenizer: F) -> &'a Self
+    where
+        F: Fn(&'a str) -> I,
+        I: Iterator<Item = &'a str>,
+    {
+        &self[token::token_overlap_range(self, other, tokenizer)]
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[inline]
+    fn overlap_start_graphemes(&self, other: &Self) -> &Self {
+        &self[..(other.len() - grapheme::grapheme_overlap_index(other, self))]
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[inline]
+    fn overlap_end_graphemes(&self, other: &Self) -> &Self {
+        &self[grapheme::grapheme_overlap_index(self, other)..]
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_nfc(&self, other: &Self) -> String {
+        normalization::nfc_overlap_end(other, self)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_nfc(&self, other: &Self) -> String {
+        normalization::nfc_overlap_end(self, other)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_nfc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = normalization::nfc_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_nfc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        normalization::nfc_overlap_indices(self, other)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_nfkc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = normalization::nfkc_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_nfkc_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        normalization::nfkc_overlap_indices(self, other)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_ignore_diacritics_indices(
+        &self,
+        other: &Self,
+    ) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = normalization::base_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_ignore_diacritics_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        normalization::base_overlap_indices(self, other)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_width_insensitive_indices(
+        &self,
+        other: &Self,
+    ) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = normalization::width_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(feature = "unicode-normalization", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_width_insensitive_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        normalization::width_overlap_indices(self, other)
+    }
+
+    #[cfg(all(
+        feature = "unicode-security",
+        feature = "unicode-normalization",
+        feature = "alloc"
+    ))]
+    #[inline]
+    fn overlap_start_confusable_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = normalization::skeleton_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(
+        feature = "unicode-security",
+        feature = "unicode-normalization",
+        feature = "alloc"
+    ))]
+    #[inline]
+    fn overlap_end_confusable_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        normalization::skeleton_overlap_indices(self, other)
+    }
+
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_fold(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = case_fold::fold_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_fold(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        case_fold::fold_overlap_indices(self, other)
+    }
+
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[inline]
+    fn overlap_start_folded(&self, other: &Self) -> String {
+        String::from(&self[self.overlap_start_fold(other).0])
+    }
+
+    #[cfg(all(feature = "caseless", feature = "alloc"))]
+    #[inline]
+    fn overlap_end_folded(&self, other: &Self) -> String {
+        String::from(&self[self.overlap_end_fold(other).0])
+    }
+
+    #[inline]
+    fn overlap_end_ignoring<F>(&self, other: &Self, ignore: F) -> &Self
+    where
+        F: Fn(char) -> bool,
+    {
+        &self[ignoring::ignoring_overlap_range(self, other, ignore)]
+    }
+
+    #[inline]
+    fn overlap_start_ignore_bom(&self, other: &Self) -> &Self {
+        &self[..bom::bom_overlap_indices(other.as_bytes(), self.as_bytes()).1]
+    }
+
+    #[inline]
+    fn overlap_end_ignore_bom(&self, other: &Self) -> &Self {
+        &self[bom::bom_overlap_indices(self.as_bytes(), other.as_bytes()).0..]
+    }
+
+    #[inline]
+    fn overlap_start_limit_chars(&self, other: &Self, max_chars: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        match overlap.char_indices().nth(max_chars) {
+            Some((index, _)) => &overlap[..index],
+            None => overlap,
+        }
+    }
+
+    #[inline]
+    fn overlap_end_limit_chars(&self, other: &Self, max_chars: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if max_chars == 0 {
+            return &overlap[overlap.len()..];
+        }
+        match overlap.char_indices().rev().nth(max_chars - 1) {
+            Some((index, _)) => &overlap[index..],
+            None => overlap,
+        }
+    }
+
+    #[inline]
+    fn overlap_start_multi(&self, others: &[&Self]) -> Option<(usize, &Self)> {
+        let mut best = (0, 0);
+        for (candidate, other) in others.iter().enumerate() {
+            let len = self.overlap_start_len(other);
+            if len > best.1 {
+                best = (candidate, len);
+            }
+        }
+        if best.1 == 0 {
+            None
+        } else {
+            Some((best.0, &self[..best.1]))
+        }
+    }
+
+    #[cfg(feature = "icu_collator")]
+    #[inline]
+    fn overlap_start_collated_indices(
+        &self,
+        other: &Self,
+        collator: &CollatorBorrowed,
+    ) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = collation::collated_overlap_indices(other, self, collator);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "icu_collator")]
+    #[inline]
+    fn overlap_end_collated_indices(
+        &self,
+        other: &Self,
+        collator: &CollatorBorrowed,
+    ) -> (Range<usize>, Range<usize>) {
+        collation::collated_overlap_indices(self, other, collator)
+    }
+
+    #[inline]
+    fn overlap_end_multi(&self, others: &[&Self]) -> Option<(usize, &Self)> {
+        let mut best = (0, self.len());
+        for (candidate, other) in others.iter().enumerate() {
+            let index = overlap_index(self, other);
+            if index < best.1 {
+                best = (candidate, index);
+            }
+        }
+        if best.1 == self.len() {
+            None
+        } else {
+            Some((best.0, &self[best.1..]))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_whitespace_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = whitespace::whitespace_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_whitespace_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        whitespace::whitespace_overlap_indices(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_ansi_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = ansi::ansi_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_ansi_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        ansi::ansi_overlap_indices(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_html_entity_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = entity::entity_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_html_entity_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        entity::entity_overlap_indices(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_json_escape_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = json_escape::json_escape_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_json_escape_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        json_escape::json_escape_overlap_indices(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = percent::str_percent_overlap_indices(other, self);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_percent_decoded_indices(&self, other: &Self) -> (Range<usize>, Range<usize>) {
+        percent::str_percent_overlap_indices(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_tab_expanded_indices(
+        &self,
+        other: &Self,
+        tab_width: usize,
+    ) -> (Range<usize>, Range<usize>) {
+        let (other_range, self_range) = tab::tab_overlap_indices(other, self, tab_width);
+        (self_range, other_range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_tab_expanded_indices(
+        &self,
+        other: &Self,
+        tab_width: usize,
+    ) -> (Range<usize>, Range<usize>) {
+        tab::tab_overlap_indices(self, other, tab_width)
+    }
+
+    #[inline]
+    fn overlaps_end<'a>(&'a self, other: &'a Self) -> OverlapIter<'a> {
+        iter::overlaps_end(self, other)
+    }
+
+    #[inline]
+    fn overlap_end_count(&self, other: &Self) -> usize {
+        iter::overlap_end_count(self, other)
+    }
+
+    #[inline]
+    fn overlap_end_nth(&self, other: &Self, n: usize) -> Option<&Self> {
+        iter::overlap_end_nth(self, other, n)
+    }
+
+    #[inline]
+    fn shortest_overlap_start(&self, other: &Self) -> &Self {
+        iter::shortest_overlap_start(self, other)
+    }
+
+    #[inline]
+    fn shortest_overlap_end(&self, other: &Self) -> &Self {
+        iter::shortest_overlap_end(self, other)
+    }
+
+    #[inline]
+    fn overlap_start_result<'a>(&'a self, other: &Self) -> OverlapResult<'a> {
+        overlap_result::overlap_start_result(self, other)
+    }
+
+    #[inline]
+    fn overlap_end_result<'a>(&'a self, other: &Self) -> OverlapResult<'a> {
+        overlap_result::overlap_end_result(self, other)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_cow<'a>(&'a self, other: &Self) -> Cow<'a, str> {
+        Cow::Borrowed(self.overlap_start(other))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_cow<'a>(&'a self, other: &Self) -> Cow<'a, str> {
+        Cow::Borrowed(self.overlap_end(other))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_start_owned(&self, other: &Self) -> String {
+        let overlap = self.overlap_start(other);
+        let mut owned = String::with_capacity(overlap.len());
+        owned.push_str(overlap);
+        owned
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn overlap_end_owned(&self, other: &Self) -> String {
+        let overlap = self.overlap_end(other);
+        let mut owned = String::with_capacity(overlap.len());
+        owned.push_str(overlap);
+        owned
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn merge_end(&self, other: &Self) -> String {
+        let overlap_len = self.overlap_end_len(other);
+        let mut merged = String::with_capacity(self.len() + other.len() - overlap_len);
+        merged.push_str(self);
+        // `overlap_len` is always on a character bound of `other`, since the overlap is a prefix of
+        // `other`.
+        merged.push_str(&other[overlap_len..]);
+        merged
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn merge_start(&self, other: &Self) -> String {
+        let overlap_len = self.overlap_start_len(other);
+        let mut merged = String::with_capacity(other.len() + self.len() - overlap_len);
+        // `other.len() - overlap_len` is always on a character bound of `other`, since the overlap
+        // is a suffix of `other`.
+        merged.push_str(&other[..(other.len() - overlap_len)]);
+        merged.push_str(self);
+        merged
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn merge_end_cow<'a>(&'a self, other: &'a Self) -> Cow<'a, str> {
+        let overlap_len = self.overlap_end_len(other);
+        if overlap_len == other.len() {
+            Cow::Borrowed(self)
+        } else if overlap_len == self.len() {
+            Cow::Borrowed(other)
+        } else {
+            let mut merged = String::with_capacity(self.len() + other.len() - overlap_len);
+            merged.push_str(self);
+            merged.push_str(&other[overlap_len..]);
+            Cow::Owned(merged)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn merge_start_cow<'a>(&'a self, other: &'a Self) -> Cow<'a, str> {
+        let overlap_len = self.overlap_start_len(other);
+        if overlap_len == other.len() {
+            Cow::Borrowed(self)
+        } else if overlap_len == self.len() {
+            Cow::Borrowed(other)
+        } else {
+            let mut merged = String::with_capacity(other.len() + self.len() - overlap_len);
+            merged.push_str(&other[..(other.len() - overlap_len)]);
+            merged.push_str(self);
+            Cow::Owned(merged)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::ToOwned;
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+    use Direction;
+    use Overlap;
+    use StrOverlap;
+    use {is_line_end, is_line_start};
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!("bcd".overlap_start("abc"), "bc");
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!("abc".overlap_end("bcd"), "bc");
+    }
+
+    #[test]
+    fn full_overlap_start() {
+        assert_eq!("abc".overlap_start("abc"), "abc");
+    }
+
+    #[test]
+    fn full_overlap_end() {
+        assert_eq!("abc".overlap_end("abc"), "abc");
+    }
+
+    #[test]
+    fn no_overlap_start() {
+        assert_eq!("abc".overlap_start("def"), "");
+    }
+
+    #[test]
+    fn no_overlap_end() {
+        assert_eq!("abc".overlap_end("def"), "");
+    }
+
+    #[test]
+    fn other_substring_of_self_start() {
+        assert_eq!("abcd".overlap_start("abc"), "abc");
+    }
+
+    #[test]
+    fn other_substring_of_self_end() {
+        assert_eq!("abcd".overlap_end("bcd"), "bcd");
+    }
+
+    #[test]
+    fn self_substring_of_other_start() {
+        assert_eq!("bcd".overlap_start("abcd"), "bcd");
+    }
+
+    #[test]
+    fn self_substring_other_end() {
+        assert_eq!("abc".overlap_end("abcd"), "abc");
+    }
+
+    #[test]
+    fn only_checks_overlap_one_way_start() {
+        assert_eq!("abc".overlap_start("bcd"), "");
+    }
+
+    #[test]
+    fn only_checks_overlap_one_way_end() {
+        assert_eq!("bcd".overlap_end("abc"), "");
+    }
+
+    #[test]
+    fn self_empty_start() {
+        assert_eq!("".overlap_start("abc"), "");
+    }
+
+    #[test]
+    fn self_empty_end() {
+        assert_eq!("".overlap_end("abc"), "");
+    }
+
+    #[test]
+    fn other_empty_start() {
+        assert_eq!("abc".overlap_start(""), "");
+    }
+
+    #[test]
+    fn other_empty_end() {
+        assert_eq!("abc".overlap_end(""), "");
+    }
+
+    #[test]
+    fn all_empty_start() {
+        assert_eq!("".overlap_end(""), "");
+    }
+
+    #[test]
+    fn all_empty_end() {
+        assert_eq!("".overlap_start(""), "");
+    }
+
+    #[test]
+    fn multi_byte_start() {
+        assert_eq!("語a日bc本".overlap_start("b日本語a"), "語a");
+    }
+
+    #[test]
+    fn multi_byte_end() {
+        assert_eq!("b日本語a".overlap_end("語a日bc本"), "語a");
+    }
+
+    #[test]
+    fn partial_overlap_start_len() {
+        assert_eq!("bcd".overlap_start_len("abc"), 2);
+    }
+
+    #[test]
+    fn partial_overlap_end_len() {
+        assert_eq!("abc".overlap_end_len("bcd"), 2);
+    }
+
+    #[test]
+    fn full_overlap_start_len() {
+        assert_eq!("abc".overlap_start_len("abc"), 3);
+    }
+
+    #[test]
+    fn full_overlap_end_len() {
+        assert_eq!("abc".overlap_end_len("abc"), 3);
+    }
+
+    #[test]
+    fn no_overlap_start_len() {
+        assert_eq!("abc".overlap_start_len("def"), 0);
+    }
+
+    #[test]
+    fn no_overlap_end_len() {
+        assert_eq!("abc".overlap_end_len("def"), 0);
+    }
+
+    #[test]
+    fn all_empty_start_len() {
+        assert_eq!("".overlap_start_len(""), 0);
+    }
+
+    #[test]
+    fn all_empty_end_len() {
+        assert_eq!("".overlap_end_len(""), 0);
+    }
+
+    #[test]
+    fn multi_byte_start_len() {
+        assert_eq!(
+            "語a日bc本".overlap_start_len("b日本語a"),
+            "語a日bc本".overlap_start("b日本語a").len()
+        );
+        assert_eq!("語a日bc本".overlap_start_len("b日本語a"), 4);
+    }
+
+    #[test]
+    fn multi_byte_end_len() {
+        assert_eq!(
+            "b日本語a".overlap_end_len("語a日bc本"),
+            "b日本語a".overlap_end("語a日bc本").len()
+        );
+        assert_eq!("b日本語a".overlap_end_len("語a日bc本"), 4);
+    }
+
+    #[test]
+    fn bytes_partial_overlap_start() {
+        assert_eq!(b"bcd".overlap_start(b"abc"), b"bc");
+    }
+
+    #[test]
+    fn bytes_partial_overlap_end() {
+        assert_eq!(b"abc".overlap_end(b"bcd"), b"bc");
+    }
+
+    #[test]
+    fn bytes_full_overlap_start() {
+        assert_eq!(b"abc".overlap_start(b"abc"), b"abc");
+    }
+
+    #[test]
+    fn bytes_full_overlap_end() {
+        assert_eq!(b"abc".overlap_end(b"abc"), b"abc");
+    }
+
+    #[test]
+    fn bytes_no_overlap_start() {
+        assert_eq!(b"abc".overlap_start(b"def"), b"");
+    }
+
+    #[test]
+    fn bytes_no_overlap_end() {
+        assert_eq!(b"abc".overlap_end(b"def"), b"");
+    }
+
+    #[test]
+    fn bytes_self_empty_start() {
+        assert_eq!(b"".overlap_start(b"abc"), b"");
+    }
+
+    #[test]
+    fn bytes_self_empty_end() {
+        assert_eq!(b"".overlap_end(b"abc"), b"");
+    }
+
+    #[test]
+    fn bytes_other_empty_start() {
+        assert_eq!(b"abc".overlap_start(b""), b"");
+    }
+
+    #[test]
+    fn bytes_other_empty_end() {
+        assert_eq!(b"abc".overlap_end(b""), b"");
+    }
+
+    #[test]
+    fn bytes_all_empty_start() {
+        assert_eq!(b"".overlap_start(b""), b"");
+    }
+
+    #[test]
+    fn bytes_all_empty_end() {
+        assert_eq!(b"".overlap_end(b""), b"");
+    }
+
+    #[test]
+    fn bytes_invalid_utf8_start() {
+        assert_eq!(
+            b"\xFF\xFFa\xFE".overlap_start(b"a\xFF\xFF\xFF"),
+            b"\xFF\xFF"
+        );
+    }
+
+    #[test]
+    fn bytes_invalid_utf8_end() {
+        assert_eq!(b"a\xFF\xFF\xFF".overlap_end(b"\xFF\xFFa\xFE"), b"\xFF\xFF");
+    }
+
+    #[test]
+    fn bytes_embedded_nul_start() {
+        assert_eq!(b"\0b\0cd".overlap_start(b"a\0b\0"), b"\0b\0");
+    }
+
+    #[test]
+    fn bytes_embedded_nul_end() {
+        assert_eq!(b"a\0b\0".overlap_end(b"\0b\0cd"), b"\0b\0");
+    }
+
+    #[test]
+    fn bytes_len_start() {
+        assert_eq!(b"\0b\0cd".overlap_start_len(b"a\0b\0"), 3);
+    }
+
+    #[test]
+    fn bytes_len_end() {
+        assert_eq!(b"a\0b\0".overlap_end_len(b"\0b\0cd"), 3);
+    }
+
+    #[test]
+    fn bytes_overlap_borrows_from_self() {
+        let bytes: &[u8] = b"ab\xFFc";
+
+        assert_eq!(bytes.overlap_start(b"xab").as_ptr(), bytes.as_ptr());
+        assert_eq!(bytes.overlap_end(b"\xFFcd").as_ptr(), bytes[2..].as_ptr());
+    }
+
+    #[test]
+    fn partial_overlap_start_char_count() {
+        assert_eq!("bcd".overlap_start_char_count("abc"), 2);
+    }
+
+    #[test]
+    fn partial_overlap_end_char_count() {
+        assert_eq!("abc".overlap_end_char_count("bcd"), 2);
+    }
+
+    #[test]
+    fn no_overlap_start_char_count() {
+        assert_eq!("abc".overlap_start_char_count("def"), 0);
+    }
+
+    #[test]
+    fn no_overlap_end_char_count() {
+        assert_eq!("abc".overlap_end_char_count("def"), 0);
+    }
+
+    #[test]
+    fn all_empty_start_char_count() {
+        assert_eq!("".overlap_start_char_count(""), 0);
+    }
+
+    #[test]
+    fn all_empty_end_char_count() {
+        assert_eq!("".overlap_end_char_count(""), 0);
+    }
+
+    #[test]
+    fn multi_byte_start_char_count() {
+        assert_eq!("語a日bc本".overlap_start_char_count("b日本語a"), 2);
+    }
+
+    #[test]
+    fn multi_byte_end_char_count() {
+        assert_eq!("b日本語a".overlap_end_char_count("語a日bc本"), 2);
+    }
+
+    #[test]
+    fn partial_overlap_start_range() {
+        assert_eq!("bcd".overlap_start_range("abc"), 0..2);
+    }
+
+    #[test]
+    fn partial_overlap_end_range() {
+        assert_eq!("abc".overlap_end_range("bcd"), 1..3);
+    }
+
+    #[test]
+    fn no_overlap_range() {
+        assert_eq!("abc".overlap_start_range("def"), 0..0);
+        assert_eq!("abc".overlap_end_range("def"), 3..3);
+        assert_eq!("".overlap_end_range(""), 0..0);
+    }
+
+    #[test]
+    fn multi_byte_range() {
+        let s = "b日本語a";
+        let range = s.overlap_end_range("語a日bc本");
+
+        assert_eq!(range, 7..11);
+        assert_eq!(&s[range], "語a");
+
+        let s = "語a日bc本";
+        let range = s.overlap_start_range("b日本語a");
+
+        assert_eq!(range, 0..4);
+        assert_eq!(&s[range], "語a");
+    }
+
+    #[test]
+    fn partial_overlap_start_indices() {
+        assert_eq!("bcd".overlap_start_indices("abc"), (0..2, 1..3));
+    }
+
+    #[test]
+    fn partial_overlap_end_indices() {
+        assert_eq!("abc".overlap_end_indices("bcd"), (1..3, 0..2));
+    }
+
+    #[test]
+    fn no_overlap_indices() {
+        assert_eq!("abc".overlap_start_indices("def"), (0..0, 3..3));
+        assert_eq!("abc".overlap_end_indices("def"), (3..3, 0..0));
+        assert_eq!("".overlap_end_indices(""), (0..0, 0..0));
+    }
+
+    #[test]
+    fn multi_byte_indices() {
+        let s = "b日本語a";
+        let other = "語a日bc本";
+        let (self_range, other_range) = s.overlap_end_indices(other);
+
+        assert_eq!((self_range.clone(), other_range.clone()), (7..11, 0..4));
+        assert_eq!(&s[self_range], &other[other_range]);
+
+        let (self_range, other_range) = other.overlap_start_indices(s);
+
+        assert_eq!((self_range.clone(), other_range.clone()), (0..4, 7..11));
+        assert_eq!(&other[self_range], &s[other_range]);
+    }
+
+    #[test]
+    fn ignore_ascii_case_start() {
+        assert_eq!("BCD".overlap_start_ignore_ascii_case("abc"), "BC");
+    }
+
+    #[test]
+    fn ignore_ascii_case_end() {
+        assert_eq!("ABC".overlap_end_ignore_ascii_case("bcd"), "BC");
+    }
+
+    #[test]
+    fn ignore_ascii_case_mixed_case() {
+        assert_eq!(
+            "Content-TYPE".overlap_end_ignore_ascii_case("type: text/html"),
+            "TYPE"
+        );
+        assert_eq!(
+            "Type: text/html".overlap_start_ignore_ascii_case("content-tYpE"),
+            "Type"
+        );
+    }
+
+    #[test]
+    fn ignore_ascii_case_no_overlap() {
+        assert_eq!("abc".overlap_end_ignore_ascii_case("DEF"), "");
+        assert_eq!("abc".overlap_start_ignore_ascii_case("DEF"), "");
+        assert_eq!("".overlap_end_ignore_ascii_case(""), "");
+    }
+
+    #[test]
+    fn ignore_ascii_case_only_letters() {
+        // `@` and `` ` `` differ from `A` and `a` only by the case bit, but are not letters.
+        assert_eq!("a@".overlap_end_ignore_ascii_case("`b"), "");
+        assert_eq!("a[".overlap_end_ignore_ascii_case("{b"), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn overlap_start_matches_reversal() {
+        let pairs = [
+            ("語本日", "本語"),
+            ("ومرحبا", "سلامو"),
+            ("ab😀c", "x😀ba😀"),
+            ("😀😀a", "😀😀😀"),
+        ];
+        for &(s, other) in pairs.iter() {
+            let reversed_s: String = s.chars().rev().collect();
+            let reversed_other: String = other.chars().rev().collect();
+            let expected: String = reversed_s
+                .overlap_end(&reversed_other)
+                .chars()
+                .rev()
+                .collect();
+
+            assert_eq!(s.overlap_start(other), expected);
+        }
+    }
+
+    #[test]
+    fn ignore_ascii_case_dotted() {
+        assert_eq!(
+            "Foo.BAR".overlap_end_ignore_ascii_case("foo.bar.baz"),
+            "Foo.BAR"
+        );
+        assert_eq!(
+            "x.foo.BAR".overlap_end_ignore_ascii_case("Foo.bar.baz"),
+            "foo.BAR"
+        );
+    }
+
+    #[test]
+    fn ignore_ascii_case_non_ascii_exact() {
+        assert_eq!("aÄ".overlap_end_ignore_ascii_case("äb"), "");
+        assert_eq!("aÄ".overlap_end_ignore_ascii_case("Äb"), "Ä");
+        assert_eq!("語A".overlap_end_ignore_ascii_case("語ab"), "語A");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_start() {
+        assert_eq!("bcd".overlap_start_cow("abc"), "bc");
+        assert_eq!("abc".overlap_start_cow("def"), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_end() {
+        assert_eq!("abc".overlap_end_cow("bcd"), "bc");
+        assert_eq!("abc".overlap_end_cow("def"), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_plain_overlap_is_borrowed() {
+        let s = "abc";
+
+        match s.overlap_end_cow("bcd") {
+            Cow::Borrowed(overlap) => assert_eq!(overlap.as_ptr(), s[1..].as_ptr()),
+            Cow::Owned(_) => panic!("plain overlap allocated"),
+        }
+        match s.overlap_start_cow("xab") {
+            Cow::Borrowed(overlap) => assert_eq!(overlap.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("plain overlap allocated"),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_start() {
+        assert_eq!("bcd".overlap_start_owned("abc"), "bc");
+        assert_eq!("abc".overlap_start_owned("def"), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_end() {
+        assert_eq!("abc".overlap_end_owned("bcd"), "bc");
+        assert_eq!("abc".overlap_end_owned("def"), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_outlives_temporaries() {
+        let overlap = String::from("b日本").overlap_end_owned(&String::from("日本語"));
+
+        assert_eq!(overlap, "日本");
+        assert_eq!(overlap.capacity(), overlap.len());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_collect_from_temporaries() {
+        let pairs = [("abc", "bcd"), ("xyz", "yz!"), ("foo", "bar")];
+        let fragments = pairs
+            .iter()
+            .map(|&(left, right)| (left.to_owned(), right.to_owned()))
+            // Each pair of `String`s is dropped once its overlap is found.
+            .map(|(left, right)| left.overlap_end_owned(&right))
+            .collect::<Vec<String>>();
+
+        assert_eq!(fragments, ["bc", "yz", ""]);
+    }
+
+    #[test]
+    fn ignore_ascii_case_borrows_from_self() {
+        let s = "ABC";
+
+        assert_eq!(
+            s.overlap_end_ignore_ascii_case("bcd").as_ptr(),
+            s[1..].as_ptr()
+        );
+    }
+
+    #[test]
+    fn lines_partial_overlap_start() {
+        assert_eq!("b\nc\nd\n".overlap_start_lines("a\nb\nc\n"), "b\nc\n");
+    }
+
+    #[test]
+    fn lines_partial_overlap_end() {
+        assert_eq!("a\nb\nc\n".overlap_end_lines("b\nc\nd\n"), "b\nc\n");
+    }
+
+    #[test]
+    fn lines_full_overlap() {
+        assert_eq!("a\nb".overlap_end_lines("a\nb"), "a\nb");
+        assert_eq!("a\nb\n".overlap_start_lines("a\nb\n"), "a\nb\n");
+    }
+
+    #[test]
+    fn lines_no_overlap() {
+        assert_eq!("a\nb\n".overlap_end_lines("c\nd\n"), "");
+        assert_eq!("a\nb\n".overlap_start_lines("c\nd\n"), "");
+        assert_eq!("a\n".overlap_end_lines(""), "");
+        assert_eq!("".overlap_end_lines("a\n"), "");
+        assert_eq!("".overlap_start_lines(""), "");
+    }
+
+    #[test]
+    fn lines_partial_lines_excluded() {
+        // Overlapping characters that don't form whole lines on both sides are not an overlap.
+        assert_eq!("a\nbc".overlap_end_lines("c\nd"), "");
+        assert_eq!("a\nb".overlap_end_lines("bc\nd"), "");
+        assert_eq!("bc\nd".overlap_start_lines("a\nc"), "");
+    }
+
+    #[test]
+    fn lines_trailing_newline() {
+        // A trailing newline ends the last line, and does not add an empty line.
+        assert_eq!("x\ny\n".overlap_end_lines("y\nz\n"), "y\n");
+        assert_eq!("x\ny".overlap_end_lines("y\nz"), "y");
+        assert_eq!("x\ny\n".overlap_end_lines("y"), "y\n");
+        assert_eq!("y\nz".overlap_start_lines("x\ny\n"), "y\n");
+        assert_eq!("y".overlap_start_lines("x\ny\n"), "y");
+    }
+
+    #[test]
+    fn lines_empty_lines() {
+        assert_eq!("a\n\n".overlap_end_lines("\nb"), "\n");
+        assert_eq!("a\n\n".overlap_end_lines("b"), "");
+        assert_eq!("a\n\n".overlap_end_lines("\n"), "\n");
+        assert_eq!("\nb".overlap_start_lines("a\n\n"), "\n");
+    }
+
+    #[test]
+    fn lines_repeated() {
+        assert_eq!("a\na\na\n".overlap_end_lines("a\na\nb\n"), "a\na\n");
+        assert_eq!("a\na\nb\n".overlap_start_lines("x\na\na\na\n"), "a\na\n");
+        assert_eq!("a\na\n".overlap_end_lines("a\na\na\n"), "a\na\n");
+    }
+
+    #[test]
+    fn lines_match_slice_of_lines() {
+        let left = ["x", "a", "b", "a", "b"];
+        let right = ["a", "b", "a", "b", "c"];
+
+        assert_eq!(left.overlap_end(&right), ["a", "b", "a", "b"]);
+        assert_eq!(
+            "x\na\nb\na\nb\n".overlap_end_lines("a\nb\na\nb\nc\n"),
+            "a\nb\na\nb\n"
+        );
+    }
+
+    #[test]
+    fn lines_borrow_from_self() {
+        let s = "a\nb\n";
+
+        assert_eq!(s.overlap_end_lines("b\n").as_ptr(), s[2..].as_ptr());
+        assert_eq!(s.overlap_start_lines("a").as_ptr(), s.as_ptr());
+    }
+
+    #[test]
+    fn line_bounds() {
+        let bytes = b"a\r\nb\nc\r";
+
+        assert!(is_line_start(bytes, 0));
+        assert!(!is_line_start(bytes, 1));
+        assert!(is_line_start(bytes, 3));
+        assert!(is_line_start(bytes, 5));
+        assert!(is_line_end(bytes, 1));
+        assert!(!is_line_end(bytes, 2));
+        assert!(is_line_end(bytes, 3));
+        assert!(is_line_end(bytes, 4));
+        assert!(is_line_end(bytes, 5));
+        // A lone '\r' does not terminate a line.
+        assert!(!is_line_end(bytes, 6));
+        assert!(is_line_end(bytes, 7));
+    }
+
+    #[test]
+    fn lines_aligned_lf() {
+        assert_eq!("a\nb\nc\n".overlap_end_lines_aligned("b\nc\nd\n"), "b\nc\n");
+        assert_eq!(
+            "b\nc\nd\n".overlap_start_lines_aligned("a\nb\nc\n"),
+            "b\nc\n"
+        );
+    }
+
+    #[test]
+    fn lines_aligned_crlf() {
+        assert_eq!(
+            "a\r\nb\r\nc\r\n".overlap_end_lines_aligned("b\r\nc\r\nd\r\n"),
+            "b\r\nc\r\n"
+        );
+        assert_eq!(
+            "b\r\nc\r\nd".overlap_start_lines_aligned("a\r\nb\r\nc\r\n"),
+            "b\r\nc\r\n"
+        );
+    }
+
+    #[test]
+    fn lines_aligned_no_trailing_newline() {
+        assert_eq!("a\nb".overlap_end_lines_aligned("b\nc"), "b");
+        assert_eq!("a\r\nb".overlap_end_lines_aligned("b\r\nc"), "b");
+        assert_eq!("a\r\nb".overlap_end_lines_aligned("b"), "b");
+        assert_eq!("b\r\nc".overlap_start_lines_aligned("a\r\nb"), "b");
+    }
+
+    #[test]
+    fn lines_aligned_mid_line() {
+        // The byte overlap begins in the middle of a line of `self`.
+        assert_eq!("a\nxb\n".overlap_end("b\nc"), "b\n");
+        assert_eq!("a\nxb\n".overlap_end_lines_aligned("b\nc"), "");
+        // The byte overlap ends in the middle of a line of `other`.
+        assert_eq!("a\nb".overlap_end("bc\n"), "b");
+        assert_eq!("a\nb".overlap_end_lines_aligned("bc\n"), "");
+        // The byte overlap ends between '\r' and '\n'.
+        assert_eq!("a\nb\r".overlap_end("b\r\n"), "b\r");
+        assert_eq!("a\nb\r".overlap_end_lines_aligned("b\r\n"), "");
+    }
+
+    #[test]
+    fn lines_aligned_shrink() {
+        // "b\na\nb" begins in the middle of "xb", so the overlap shrinks to "b".
+        assert_eq!("xb\na\nb".overlap_end("b\na\nb\nc"), "b\na\nb");
+        assert_eq!("xb\na\nb".overlap_end_lines_aligned("b\na\nb\nc"), "b");
+    }
+
+    #[test]
+    fn lines_aligned_empty_lines() {
+        assert_eq!("a\n\n".overlap_end_lines_aligned("\nb"), "\n");
+        assert_eq!("a\n".overlap_end_lines_aligned("\nb"), "");
+        assert_eq!("".overlap_end_lines_aligned("a"), "");
+        assert_eq!("a".overlap_end_lines_aligned(""), "");
+    }
+
+    #[test]
+    fn words_partial_overlap() {
+        assert_eq!("the cat".overlap_end_words("cat sat"), "cat");
+        assert_eq!("a b c".overlap_end_words("b c d"), "b c");
+    }
+
+    #[test]
+    fn words_mid_word_overlap() {
+        assert_eq!("the".overlap_end("herd"), "he");
+        assert_eq!("the".overlap_end_words("herd"), "");
+        assert_eq!("the cat".overlap_end("at sat"), "at");
+        assert_eq!("the cat".overlap_end_words("at sat"), "");
+    }
+
+    #[test]
+    fn words_shrink_to_word_start() {
+        // The longest overlap, "ab ab", begins in the middle of "xab".
+        assert_eq!("xab ab".overlap_end("ab ab c"), "ab ab");
+        assert_eq!("xab ab".overlap_end_words("ab ab c"), "ab");
+    }
+
+    #[test]
+    fn words_whole_string() {
+        assert_eq!("cat".overlap_end_words("cat sat"), "cat");
+        assert_eq!("cat".overlap_start_words("the cat"), "cat");
+        assert_eq!("".overlap_end_words("cat"), "");
+        assert_eq!("".overlap_start_words("cat"), "");
+    }
+
+    #[test]
+    fn words_whitespace() {
+        assert_eq!("the\tcat".overlap_end_words("cat"), "cat");
+        assert_eq!("the\ncat".overlap_end_words("cat"), "cat");
+    }
+
+    #[test]
+    fn words_multi_byte() {
+        assert_eq!("le café".overlap_end_words("café noir"), "café");
+        assert_eq!("lecafé".overlap_end_words("café noir"), "");
+    }
+
+    #[test]
+    fn max_overlap_self_then_other() {
+        assert_eq!("abc".max_overlap("bcd"), ("bc", Direction::SelfThenOther));
+    }
+
+    #[test]
+    fn max_overlap_other_then_self() {
+        assert_eq!("cde".max_overlap("abcd"), ("cd", Direction::OtherThenSelf));
+    }
+
+    #[test]
+    fn max_overlap_prefers_longer() {
+        // "ab" overlaps in one order and "bcd" in the other.
+        assert_eq!(
+            "bcdxab".max_overlap("abxbcd"),
+            ("bcd", Direction::OtherThenSelf)
+        );
+        assert_eq!(
+            "abxbcd".max_overlap("bcdxab"),
+            ("bcd", Direction::SelfThenOther)
+        );
+    }
+
+    #[test]
+    fn max_overlap_tie() {
+        // "a" overlaps in one order and "b" in the other.
+        assert_eq!("bxa".max_overlap("axb"), ("a", Direction::SelfThenOther));
+        assert_eq!("axb".max_overlap("bxa"), ("b", Direction::SelfThenOther));
+        assert_eq!("ab".max_overlap("ab"), ("ab", Direction::SelfThenOther));
+    }
+
+    #[test]
+    fn max_overlap_none() {
+        assert_eq!("abc".max_overlap("def"), ("", Direction::SelfThenOther));
+        assert_eq!("".max_overlap(""), ("", Direction::SelfThenOther));
+    }
+
+    #[test]
+    fn max_overlap_borrows_from_self() {
+        let s = "cde";
+
+        assert_eq!(s.max_overlap("abcd").0.as_ptr(), s.as_ptr());
+        assert_eq!(s.max_overlap("efg").0.as_ptr(), s[2..].as_ptr());
+    }
+
+    #[test]
+    fn slice_max_overlap() {
+        assert_eq!(
+            [3u32, 4, 5].max_overlap(&[1, 2, 3, 4]),
+            (&[3, 4][..], Direction::OtherThenSelf)
+        );
+        assert_eq!(
+            [1u32, 2, 3].max_overlap(&[2, 3, 4]),
+            (&[2, 3][..], Direction::SelfThenOther)
+        );
+    }
+
+    #[test]
+    fn trim_overlap_end() {
+        assert_eq!("abc".trim_overlap_end("bcd"), "a");
+        assert_eq!("abcbc".trim_overlap_end("bcbcd"), "a");
+        assert_eq!("a語b語".trim_overlap_end("語b語c"), "a");
+    }
+
+    #[test]
+    fn trim_overlap_start() {
+        assert_eq!("bcd".trim_overlap_start("abc"), "d");
+        assert_eq!("語b語c".trim_overlap_start("a語b語"), "c");
+    }
+
+    #[test]
+    fn trim_overlap_full() {
+        assert_eq!("abc".trim_overlap_end("abcd"), "");
+        assert_eq!("abc".trim_overlap_start("xabc"), "");
+        assert_eq!("".trim_overlap_end("abc"), "");
+    }
+
+    #[test]
+    fn trim_overlap_none() {
+        assert_eq!("abc".trim_overlap_end("def"), "abc");
+        assert_eq!("abc".trim_overlap_start("def"), "abc");
+        assert_eq!("abc".trim_overlap_end(""), "abc");
+    }
+
+    #[test]
+    fn trim_overlap_complements_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("", "a"),
+        ];
+        for &(left, right) in &pairs {
+            let (trimmed, overlap) = left.split_at(left.trim_overlap_end(right).len());
+            assert_eq!(overlap, left.overlap_end(right));
+            assert_eq!(trimmed, left.trim_overlap_end(right));
+
+            let (overlap, trimmed) = left.split_at(left.overlap_start(right).len());
+            assert_eq!(overlap, left.overlap_start(right));
+            assert_eq!(trimmed, left.trim_overlap_start(right));
+        }
+    }
+
+    #[test]
+    fn trim_overlap_borrows_from_self() {
+        let s = "abc";
+
+        assert_eq!(s.trim_overlap_end("bcd").as_ptr(), s.as_ptr());
+        assert_eq!(s.trim_overlap_start("xab").as_ptr(), s[2..].as_ptr());
+    }
+
+    #[test]
+    fn split_at_overlap_end() {
+        assert_eq!("abc".split_at_overlap_end("bcd"), ("a", "bc"));
+        assert_eq!("a語b語".split_at_overlap_end("語b語c"), ("a", "語b語"));
+    }
+
+    #[test]
+    fn split_at_overlap_start() {
+        assert_eq!("bcd".split_at_overlap_start("abc"), ("bc", "d"));
+        assert_eq!("語b語c".split_at_overlap_start("a語b語"), ("語b語", "c"));
+    }
+
+    #[test]
+    fn split_at_overlap_full() {
+        assert_eq!("abc".split_at_overlap_end("abcd"), ("", "abc"));
+        assert_eq!("abc".split_at_overlap_start("xabc"), ("abc", ""));
+        assert_eq!("".split_at_overlap_end("abc"), ("", ""));
+    }
+
+    #[test]
+    fn split_at_overlap_none() {
+        assert_eq!("abc".split_at_overlap_end("def"), ("abc", ""));
+        assert_eq!("abc".split_at_overlap_start("def"), ("", "abc"));
+    }
+
+    #[test]
+    fn split_at_overlap_matches_parts() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("", "a"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.split_at_overlap_end(right),
+                (left.trim_overlap_end(right), left.overlap_end(right))
+            );
+            assert_eq!(
+                left.split_at_overlap_start(right),
+                (left.overlap_start(right), left.trim_overlap_start(right))
+            );
+        }
+    }
+
+    #[test]
+    fn slice_split_at_overlap() {
+        assert_eq!(
+            [1u32, 2, 3].split_at_overlap_end(&[2, 3, 4]),
+            (&[1][..], &[2, 3][..])
+        );
+        assert_eq!(
+            [2u32, 3, 4].split_at_overlap_start(&[1, 2, 3]),
+            (&[2, 3][..], &[4][..])
+        );
+    }
+
+    #[test]
+    fn slice_trim_overlap() {
+        assert_eq!([1u32, 2, 3].trim_overlap_end(&[2, 3, 4]), [1]);
+        assert_eq!([2u32, 3, 4].trim_overlap_start(&[1, 2, 3]), [4]);
+        assert_eq!([1u32, 2].trim_overlap_end(&[3]), [1, 2]);
+    }
+
+    #[test]
+    fn slice_partial_overlap_start() {
+        assert_eq!([2u32, 3, 4].overlap_start(&[1, 2, 3]), [2, 3]);
+    }
+
+    #[test]
+    fn slice_partial_overlap_end() {
+        assert_eq!([1u32, 2, 3].overlap_end(&[2, 3, 4]), [2, 3]);
+    }
+
+    #[test]
+    fn slice_other_substring_of_self_start() {
+        assert_eq!([1u32, 2, 3, 4].overlap_start(&[1, 2, 3]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn slice_other_substring_of_self_end() {
+        assert_eq!([1u32, 2, 3, 4].overlap_end(&[2, 3, 4]), [2, 3, 4]);
+    }
+
+    #[test]
+    fn slice_empty_start() {
+        let empty: [u32; 0] = [];
+        assert_eq!(empty.overlap_start(&[1, 2, 3]), empty);
+        assert_eq!([1u32, 2, 3].overlap_start(&empty), empty);
+    }
+
+    #[test]
+    fn slice_empty_end() {
+        let empty: [u32; 0] = [];
+        assert_eq!(empty.overlap_end(&[1, 2, 3]), empty);
+        assert_eq!([1u32, 2, 3].overlap_end(&empty), empty);
+    }
+
+    #[test]
+    fn slice_of_strs_start() {
+        assert_eq!(["b", "c", "d"].overlap_start(&["a", "b", "c"]), ["b", "c"]);
+    }
+
+    #[test]
+    fn slice_of_strs_end() {
+        assert_eq!(["a", "b", "c"].overlap_end(&["b", "c", "d"]), ["b", "c"]);
+    }
+
+    #[test]
+    fn slice_of_tokens() {
+        #[derive(Debug, PartialEq)]
+        enum Token {
+            Ident(&'static str),
+            Number(u32),
+            Semicolon,
+        }
+
+        let left = [Token::Ident("x"), Token::Number(1), Token::Semicolon];
+        let right = [Token::Number(1), Token::Semicolon, Token::Ident("y")];
+
+        assert_eq!(
+            left.overlap_end(&right),
+            [Token::Number(1), Token::Semicolon]
+        );
+        assert_eq!(right.overlap_end(&left), []);
+        assert_eq!([Token::Number(1)].overlap_end(&[Token::Number(2)]), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_naive_overlap() {
+        // Every slice of up to 6 elements over a two-element alphabet, which covers many periodic
+        // and self-overlapping patterns.
+        let mut slices = Vec::new();
+        for len in 0..7 {
+            for bits in 0..(1u32 << len) {
+                slices.push((0..len).map(|i| (bits >> i) & 1).collect::<Vec<_>>());
+            }
+        }
+
+        for left in &slices {
+            for right in &slices {
+                let expected = (0..(left.len() + 1))
+                    .find(|&index| right.starts_with(&left[index..]))
+                    .unwrap();
+                assert_eq!(super::slice_overlap_index(left, right), expected);
+                assert_eq!(left.len() - super::kmp_overlap_len(left, right), expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn long_partial_matches() {
+        let a = (0..1000).map(|_| 'a').collect::<String>();
+        let left = a.clone() + "b";
+        let right = a.clone() + "c";
+
+        assert_eq!(left.overlap_end(&right), "");
+        assert_eq!(right.overlap_start(&left), "");
+        assert_eq!(left.overlap_start(&right), "");
+        assert_eq!(("b".to_owned() + &a).overlap_end(&right), a);
+        assert_eq!(::overlap_index_linear(&left, &right), left.len());
+        assert_eq!(::overlap_index_linear(&("b".to_owned() + &a), &right), 1);
+    }
+
+    #[test]
+    fn slice_skips_suffixes_longer_than_other() {
+        use core::cell::Cell;
+
+        struct Counted<'a>(u32, &'a Cell<usize>);
+
+        impl<'a> PartialEq for Counted<'a> {
+            fn eq(&self, other: &Self) -> bool {
+                self.1.set(self.1.get() + 1);
+                self.0 == other.0
+            }
+        }
+
+        let count = Cell::new(0);
+        let left = [
+            Counted(1, &count),
+            Counted(2, &count),
+            Counted(3, &count),
+            Counted(4, &count),
+        ];
+        let right = [Counted(4, &count)];
+
+        assert_eq!(left.overlap_end_len(&right), 1);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn overlap_matches_individual_methods() {
+        let pairs = [
+            ("bcd", "abc"),
+            ("abc", "bcd"),
+            ("abc", "abc"),
+            ("abc", "def"),
+            ("abcd", "abc"),
+            ("abcd", "bcd"),
+            ("bcd", "abcd"),
+            ("abc", "abcd"),
+            ("", "abc"),
+            ("abc", ""),
+            ("", ""),
+            ("語a日bc本", "b日本語a"),
+            ("b日本語a", "語a日bc本"),
+        ];
+
+        for &(left, right) in pairs.iter() {
+            assert_eq!(
+                left.overlap(right),
+                (left.overlap_start(right), left.overlap_end(right))
+            );
+            assert_eq!(
+                left.as_bytes().overlap(right.as_bytes()),
+                (
+                    left.as_bytes().overlap_start(right.as_bytes()),
+                    left.as_bytes().overlap_end(right.as_bytes())
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn overlap_both_sides() {
+        assert_eq!("bcdab".overlap("abc"), ("bc", "ab"));
+    }
+
+    #[test]
+    fn overlap_full() {
+        assert_eq!("abc".overlap("abc"), ("abc", "abc"));
+    }
+
+    #[test]
+    fn has_overlap_start() {
+        assert!("bcd".has_overlap_start("abc"));
+    }
+
+    #[test]
+    fn has_overlap_end() {
+        assert!("abc".has_overlap_end("bcd"));
+    }
+
+    #[test]
+    fn has_no_overlap_start() {
+        assert!(!"abc".has_overlap_start("bcd"));
+    }
+
+    #[test]
+    fn has_no_overlap_end() {
+        assert!(!"bcd".has_overlap_end("abc"));
+    }
+
+    #[test]
+    fn has_overlap_all_empty_start() {
+        assert!(!"".has_overlap_start(""));
+    }
+
+    #[test]
+    fn has_overlap_all_empty_end() {
+        assert!(!"".has_overlap_end(""));
+    }
+
+    #[test]
+    fn overlap_start_is_empty() {
+        assert!("abc".overlap_start_is_empty("bcd"));
+        assert!(!"bcd".overlap_start_is_empty("abc"));
+        assert!("".overlap_start_is_empty(""));
+    }
+
+    #[test]
+    fn overlap_end_is_empty() {
+        assert!("bcd".overlap_end_is_empty("abc"));
+        assert!(!"abc".overlap_end_is_empty("bcd"));
+        assert!("".overlap_end_is_empty(""));
+        assert!(!"a語".overlap_end_is_empty("語b"));
+    }
+
+    #[test]
+    fn overlap_is_empty_inverse_of_has_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("", "a"),
+            ("a", "a"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.overlap_end_is_empty(right),
+                !left.has_overlap_end(right)
+            );
+            assert_eq!(
+                left.overlap_start_is_empty(right),
+                !left.has_overlap_start(right)
+            );
+            assert_eq!(
+                left.overlap_end_is_empty(right),
+                left.overlap_end(right).is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn provided_methods() {
+        /// A value that only overlaps with an equal value, and then overlaps whole.
+        #[derive(Debug, PartialEq)]
+        struct Whole(&'static str);
+
+        static EMPTY: Whole = Whole("");
+
+        impl Overlap for Whole {
+            fn overlap_start(&self, other: &Self) -> &Self {
+                self.overlap_end(other)
+            }
+            fn overlap_end(&self, other: &Self) -> &Self {
+                if self == other {
+                    self
+                } else {
+                    &EMPTY
+                }
+            }
+            fn overlap_start_len(&self, other: &Self) -> usize {
+                self.overlap_end_len(other)
+            }
+            fn overlap_end_len(&self, other: &Self) -> usize {
+                self.overlap_end(other).0.len()
+            }
+            fn overlap_start_min(&self, _: &Self, _: usize) -> &Self {
+                unimplemented!()
+            }
+            fn overlap_end_min(&self, _: &Self, _: usize) -> &Self {
+                unimplemented!()
+            }
+            fn overlap_start_max(&self, _: &Self, _: usize) -> &Self {
+                unimplemented!()
+            }
+            fn overlap_end_max(&self, _: &Self, _: usize) -> &Self {
+                unimplemented!()
+            }
+            fn trim_overlap_start(&self, _: &Self) -> &Self {
+                unimplemented!()
+            }
+            fn trim_overlap_end(&self, _: &Self) -> &Self {
+                unimplemented!()
+            }
+            fn split_at_overlap_start(&self, _: &Self) -> (&Self, &Self) {
+                unimplemented!()
+            }
+            fn split_at_overlap_end(&self, _: &Self) -> (&Self, &Self) {
+                unimplemented!()
+            }
+        }
+
+        let a = Whole("a");
+        let b = Whole("b");
+
+        assert_eq!(a.overlap(&Whole("a")), (&a, &a));
+        assert_eq!(a.overlap(&b), (&EMPTY, &EMPTY));
+        assert!(a.has_overlap_start(&Whole("a")));
+        assert!(!a.has_overlap_end(&b));
+        assert!(a.overlap_start_is_empty(&b));
+        assert!(!a.overlap_end_is_empty(&Whole("a")));
+        assert_eq!(a.try_overlap_start(&Whole("a")), Some(&a));
+        assert_eq!(a.try_overlap_end(&b), None);
+        assert_eq!(a.max_overlap(&Whole("a")), (&a, Direction::SelfThenOther));
+    }
+
+    #[test]
+    fn slice_overlap_is_empty() {
+        assert!([1u32, 2].overlap_end_is_empty(&[3, 4]));
+        assert!(![1u32, 2].overlap_end_is_empty(&[2, 3]));
+        assert!(![2u32, 3].overlap_start_is_empty(&[1, 2]));
+    }
+
+    #[test]
+    fn has_overlap_multi_byte_start() {
+        assert!("語a日bc本".has_overlap_start("b日本語a"));
+    }
+
+    #[test]
+    fn has_overlap_multi_byte_end() {
+        assert!("b日本語a".has_overlap_end("語a日bc本"));
+    }
+
+    #[test]
+    fn slice_has_overlap_start() {
+        assert!([2u32, 3, 4].has_overlap_start(&[1, 2, 3]));
+        assert!(![1u32, 2, 3].has_overlap_start(&[2, 3, 4]));
+    }
+
+    #[test]
+    fn slice_has_overlap_end() {
+        assert!([1u32, 2, 3].has_overlap_end(&[2, 3, 4]));
+        assert!(![2u32, 3, 4].has_overlap_end(&[1, 2, 3]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_partial_overlap() {
+        assert_eq!("abc".merge_end("bcd"), "abcd");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_full_overlap() {
+        assert_eq!("abc".merge_end("abc"), "abc");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_no_overlap() {
+        assert_eq!("abc".merge_end("def"), "abcdef");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_other_substring_of_self() {
+        assert_eq!("abcd".merge_end("bcd"), "abcd");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_self_substring_of_other() {
+        assert_eq!("abc".merge_end("abcd"), "abcd");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_empty() {
+        assert_eq!("".merge_end("abc"), "abc");
+        assert_eq!("abc".merge_end(""), "abc");
+        assert_eq!("".merge_end(""), "");
+    }
 
-// Since the MSRV is 1.0.0, allowing usage of deprecated items is ok, as the replacements are likely
-// not available in early versions.
-#![allow(deprecated)]
-#![cfg_attr(rustc_1_6, no_std)]
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_multi_byte() {
+        assert_eq!("b日本語a".merge_end("語a日bc本"), "b日本語a日bc本");
+    }
 
-#[cfg(not(rustc_1_6))]
-extern crate std as core;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_exact_capacity() {
+        let merged = "abc".merge_end("bcd");
+        assert_eq!(merged.capacity(), merged.len());
+    }
 
-/// Shared logic for finding the index at which two strings overlap.
-///
-/// The `left` and `right` parameters are, conceptually, defined as follows:
-/// - `left` is the parameter whose suffix will be overlapping
-/// - `right` is the parameter whose prefix will be overlapping
-///
-/// If no overlap exists, the returned index will be the length of `left`. This allows the result to
-/// be used to create an empty slice.
-#[inline]
-#[must_use]
-fn string_overlap_index(left: &str, right: &str) -> usize {
-    left.char_indices()
-        .map(|(index, _)| index)
-        .find(|index| {
-            let slice_len = left.len() - index;
-            slice_len <= right.len()
-                && unsafe {
-                    // SAFETY: `index` is obtained from `left`'s `CharIndices`, so it will always be
-                    // within the bounds of `left`. Additionally, `index` will also always be on
-                    // UTF-8 character bounds of `left`.
-                    left.slice_unchecked(*index, left.len())
-                    // SAFETY: Since `slice_len - index` is less than or equal to `right.len()`,
-                    // `slice_len` will always be within the bounds of `right`. Additionally, since
-                    // the string slice is simply cast to bytes when checking equality, we don't
-                    // need to worry about whether the slice occurs on a valid UTF-8 character
-                    // bound.
-                        == right.slice_unchecked(0, slice_len)
-                }
-        })
-        .unwrap_or_else(|| left.len())
-}
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_cow_borrows_self() {
+        let s = "abcd";
 
-/// Provides methods for finding overlaps between values.
-///
-/// This trait provides methods for finding overlaps at both the start and end of `self`. This
-/// allows for returning overlapping values that are owned by `self`, regardless of which side of
-/// `self` the overlap is occurring.
-///
-/// This trait is made available by pulling it into scope:
-///
-/// ```
-/// use str_overlap::Overlap;
-/// ```
-///
-/// `Overlap` is implemented on [`str`], which means its methods are usable by `str` and any types
-/// which implement [`Deref<Target = str>`], such as [`String`].
-///
-/// [`Deref<Target = str>`]: core::ops::Deref
-/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-pub trait Overlap {
-    /// Returns the overlap found at the start of `self` and the end of `other`.
-    ///
-    /// # Example
-    /// This method can be used through its implementation on [`str`], like so:
-    ///
-    /// ```
-    /// use str_overlap::Overlap;
-    ///
-    /// assert_eq!("bcd".overlap_start("abc"), "bc");
-    /// ```
-    fn overlap_start(&self, other: &Self) -> &Self;
-    /// Returns the overlap found at the end of `self` and the start of `other`.
-    ///
-    /// # Example
-    /// This method can be used through its implementation on `str`, like so:
-    ///
-    /// ```
-    /// use str_overlap::Overlap;
-    ///
-    /// assert_eq!("abc".overlap_end("bcd"), "bc");
-    /// ```
-    fn overlap_end(&self, other: &Self) -> &Self;
-}
+        match s.merge_end_cow("bcd") {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+        match s.merge_end_cow("") {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+        match s.merge_end_cow("abcd") {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+    }
 
-/// Overlap methods for string slices.
-///
-/// This allows for the returned string slice to be a subset of either string slice from which an
-/// overlap is obtained.
-impl Overlap for str {
-    /// Returns the substring which is both the prefix to `self` and the suffix to `other`.
-    ///
-    /// The returned string slice is a reference to the substring contained in `self`.
-    ///
-    /// # Example
-    /// ```
-    /// use str_overlap::Overlap;
-    ///
-    /// assert_eq!("bcd".overlap_start("abc"), "bc");
-    /// ```
-    #[inline]
-    #[must_use]
-    fn overlap_start(&self, other: &Self) -> &Self {
-        unsafe {
-            // SAFETY: The result of `string_overlap_index()` subtracted from `other.len()` will
-            // always be on a character bound of `self`, since it is found by comparing directly the
-            // bytes of the start of `self` and the end of `other`. Therefore, the range will be
-            // within `self`'s bounds and also will uphold `str` invariants.
-            self.slice_unchecked(0, other.len() - string_overlap_index(other, self))
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_cow_borrows_other() {
+        let other = "abcd";
+
+        match "abc".merge_end_cow(other) {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), other.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+        match "".merge_end_cow(other) {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), other.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
         }
     }
 
-    /// Returns the substring which is both the suffix to `self` and the prefix to `other`.
-    ///
-    /// The returned string slice is a reference to the substring contained in `self`.
-    ///
-    /// # Example
-    /// ```
-    /// use str_overlap::Overlap;
-    ///
-    /// assert_eq!("abc".overlap_end("bcd"), "bc");
-    /// ```
-    #[inline]
-    #[must_use]
-    fn overlap_end(&self, other: &Self) -> &Self {
-        unsafe {
-            // SAFETY: The result of `string_overlap_index()` will always be on a character bound of
-            // `self`, since it is found from running over the CharIndices of `self`. Therefore, the
-            // range will be within `self`'s bounds and also will uphold `str` invariants.
-            self.slice_unchecked(string_overlap_index(self, other), self.len())
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_cow_owned() {
+        match "abc".merge_end_cow("bcd") {
+            Cow::Borrowed(_) => panic!("merge borrowed"),
+            Cow::Owned(merged) => assert_eq!(merged, "abcd"),
         }
+        // `other` is contained in `self`, but not at its end.
+        match "abcde".merge_end_cow("bc") {
+            Cow::Borrowed(_) => panic!("merge borrowed"),
+            Cow::Owned(merged) => assert_eq!(merged, "abcdebc"),
+        }
+        assert_eq!("b日本語a".merge_end_cow("語a日bc本"), "b日本語a日bc本");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use Overlap;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_end_cow_matches_merge_end() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcd", "bcd"),
+            ("abc", "abcd"),
+            ("abc", "def"),
+            ("", "abc"),
+            ("abc", ""),
+            ("", ""),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(left.merge_end_cow(right), left.merge_end(right));
+            assert_eq!(left.merge_start_cow(right), left.merge_start(right));
+        }
+    }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn partial_overlap_start() {
-        assert_eq!("bcd".overlap_start("abc"), "bc");
+    fn merge_start_cow() {
+        let s = "abcd";
+
+        match s.merge_start_cow("abc") {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+        match "bcd".merge_start_cow(s) {
+            Cow::Borrowed(merged) => assert_eq!(merged.as_ptr(), s.as_ptr()),
+            Cow::Owned(_) => panic!("merge allocated"),
+        }
+        match "bcd".merge_start_cow("abc") {
+            Cow::Borrowed(_) => panic!("merge borrowed"),
+            Cow::Owned(merged) => assert_eq!(merged, "abcd"),
+        }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn partial_overlap_end() {
-        assert_eq!("abc".overlap_end("bcd"), "bc");
+    fn merge_start_partial_overlap() {
+        assert_eq!("bcd".merge_start("abc"), "abcd");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn full_overlap_start() {
-        assert_eq!("abc".overlap_start("abc"), "abc");
+    fn merge_start_full_overlap() {
+        assert_eq!("abc".merge_start("abc"), "abc");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn full_overlap_end() {
-        assert_eq!("abc".overlap_end("abc"), "abc");
+    fn merge_start_no_overlap() {
+        assert_eq!("def".merge_start("abc"), "abcdef");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn no_overlap_start() {
-        assert_eq!("abc".overlap_start("def"), "");
+    fn merge_start_only_checks_overlap_one_way() {
+        assert_eq!("abc".merge_start("bcd"), "bcdabc");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn no_overlap_end() {
-        assert_eq!("abc".overlap_end("def"), "");
+    fn merge_start_other_substring_of_self() {
+        assert_eq!("abcd".merge_start("abc"), "abcd");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn other_substring_of_self_start() {
-        assert_eq!("abcd".overlap_start("abc"), "abc");
+    fn merge_start_empty() {
+        assert_eq!("".merge_start("abc"), "abc");
+        assert_eq!("abc".merge_start(""), "abc");
+        assert_eq!("".merge_start(""), "");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn other_substring_of_self_end() {
-        assert_eq!("abcd".overlap_end("bcd"), "bcd");
+    fn merge_start_multi_byte() {
+        assert_eq!("語a日bc本".merge_start("b日本語a"), "b日本語a日bc本");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn self_substring_of_other_start() {
-        assert_eq!("bcd".overlap_start("abcd"), "bcd");
+    fn merge_start_exact_capacity() {
+        let merged = "bcd".merge_start("abc");
+        assert_eq!(merged.capacity(), merged.len());
     }
 
     #[test]
-    fn self_substring_other_end() {
-        assert_eq!("abc".overlap_end("abcd"), "abc");
+    fn try_partial_overlap_start() {
+        assert_eq!("bcd".try_overlap_start("abc"), Some("bc"));
     }
 
     #[test]
-    fn only_checks_overlap_one_way_start() {
-        assert_eq!("abc".overlap_start("bcd"), "");
+    fn try_partial_overlap_end() {
+        assert_eq!("abc".try_overlap_end("bcd"), Some("bc"));
     }
 
     #[test]
-    fn only_checks_overlap_one_way_end() {
-        assert_eq!("bcd".overlap_end("abc"), "");
+    fn try_full_overlap_start() {
+        assert_eq!("abc".try_overlap_start("abc"), Some("abc"));
     }
 
     #[test]
-    fn self_empty_start() {
-        assert_eq!("".overlap_start("abc"), "");
+    fn try_full_overlap_end() {
+        assert_eq!("abc".try_overlap_end("abc"), Some("abc"));
     }
 
     #[test]
-    fn self_empty_end() {
-        assert_eq!("".overlap_end("abc"), "");
+    fn try_no_overlap_start() {
+        assert_eq!("abc".try_overlap_start("def"), None);
     }
 
     #[test]
-    fn other_empty_start() {
-        assert_eq!("abc".overlap_start(""), "");
+    fn try_no_overlap_end() {
+        assert_eq!("abc".try_overlap_end("def"), None);
     }
 
     #[test]
-    fn other_empty_end() {
-        assert_eq!("abc".overlap_end(""), "");
+    fn try_all_empty_start() {
+        assert_eq!("".try_overlap_start(""), None);
     }
 
     #[test]
-    fn all_empty_start() {
-        assert_eq!("".overlap_end(""), "");
+    fn try_all_empty_end() {
+        assert_eq!("".try_overlap_end(""), None);
     }
 
     #[test]
-    fn all_empty_end() {
-        assert_eq!("".overlap_start(""), "");
+    fn try_multi_byte_start() {
+        assert_eq!("語a日bc本".try_overlap_start("b日本語a"), Some("語a"));
     }
 
     #[test]
-    fn multi_byte_start() {
-        assert_eq!("語a日bc本".overlap_start("b日本語a"), "語a");
+    fn try_multi_byte_end() {
+        assert_eq!("b日本語a".try_overlap_end("語a日bc本"), Some("語a"));
     }
 
     #[test]
-    fn multi_byte_end() {
-        assert_eq!("b日本語a".overlap_end("語a日bc本"), "語a");
+    fn slice_try_overlap_start() {
+        assert_eq!(
+            [2u32, 3, 4].try_overlap_start(&[1, 2, 3]),
+            Some(&[2, 3][..])
+        );
+        assert_eq!([1u32, 2, 3].try_overlap_start(&[2, 3, 4]), None);
+    }
+
+    #[test]
+    fn slice_try_overlap_end() {
+        assert_eq!([1u32, 2, 3].try_overlap_end(&[2, 3, 4]), Some(&[2, 3][..]));
+        assert_eq!([2u32, 3, 4].try_overlap_end(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn chars_partial_overlap_start() {
+        assert_eq!(['b', 'c', 'd'].overlap_start(&['a', 'b', 'c']), ['b', 'c']);
+    }
+
+    #[test]
+    fn chars_partial_overlap_end() {
+        assert_eq!(['a', 'b', 'c'].overlap_end(&['b', 'c', 'd']), ['b', 'c']);
+    }
+
+    #[test]
+    fn chars_multi_byte_matches_str_start() {
+        let overlap =
+            ['語', 'a', '日', 'b', 'c', '本'].overlap_start(&['b', '日', '本', '語', 'a']);
+
+        assert_eq!(overlap, ['語', 'a']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("語a日bc本".overlap_start("b日本語a").chars()));
+    }
+
+    #[test]
+    fn chars_multi_byte_matches_str_end() {
+        let left = ['b', '日', '本', '語', 'a'];
+        let right = ['語', 'a', '日', 'b', 'c', '本'];
+        let overlap = left.overlap_end(&right);
+
+        assert_eq!(overlap, ['語', 'a']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("b日本語a".overlap_end("語a日bc本").chars()));
+    }
+
+    #[test]
+    fn chars_astral_plane_start() {
+        let overlap = ['😀', '👍', 'b'].overlap_start(&['a', '😀', '👍']);
+
+        assert_eq!(overlap, ['😀', '👍']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("😀👍b".overlap_start("a😀👍").chars()));
+    }
+
+    #[test]
+    fn chars_astral_plane_end() {
+        let overlap = ['a', '😀', '👍'].overlap_end(&['😀', '👍', 'b']);
+
+        assert_eq!(overlap, ['😀', '👍']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("a😀👍".overlap_end("😀👍b").chars()));
+    }
+
+    #[test]
+    fn chars_combining_marks_end() {
+        let overlap = ['c', 'a', 'f', 'e', '\u{301}'].overlap_end(&['e', '\u{301}', '!']);
+
+        assert_eq!(overlap, ['e', '\u{301}']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("cafe\u{301}".overlap_end("e\u{301}!").chars()));
+    }
+
+    #[test]
+    fn chars_combining_mark_alone_end() {
+        // Just like with string slices, an overlap may begin with a combining mark, separating it
+        // from its base character.
+        let overlap = ['e', '\u{301}'].overlap_end(&['\u{301}', 'x']);
+
+        assert_eq!(overlap, ['\u{301}']);
+        assert!(overlap
+            .iter()
+            .cloned()
+            .eq("e\u{301}".overlap_end("\u{301}x").chars()));
+    }
+
+    #[test]
+    fn min_overlap_long_enough_start() {
+        assert_eq!("bcd".overlap_start_min("abc", 2), "bc");
+    }
+
+    #[test]
+    fn min_overlap_long_enough_end() {
+        assert_eq!("abc".overlap_end_min("bcd", 2), "bc");
+    }
+
+    #[test]
+    fn min_overlap_too_short_start() {
+        assert_eq!("bcd".overlap_start_min("abc", 3), "");
+    }
+
+    #[test]
+    fn min_overlap_too_short_end() {
+        assert_eq!("abc".overlap_end_min("bcd", 3), "");
+    }
+
+    #[test]
+    fn min_overlap_zero_start() {
+        assert_eq!("bcd".overlap_start_min("abc", 0), "bc");
+        assert_eq!("abc".overlap_start_min("def", 0), "");
+    }
+
+    #[test]
+    fn min_overlap_zero_end() {
+        assert_eq!("abc".overlap_end_min("bcd", 0), "bc");
+        assert_eq!("abc".overlap_end_min("def", 0), "");
+    }
+
+    #[test]
+    fn min_overlap_exceeds_len_start() {
+        assert_eq!("abc".overlap_start_min("abc", 4), "");
+    }
+
+    #[test]
+    fn min_overlap_exceeds_len_end() {
+        assert_eq!("abc".overlap_end_min("abc", 4), "");
+    }
+
+    #[test]
+    fn min_overlap_multi_byte_start() {
+        assert_eq!("語a日bc本".overlap_start_min("b日本語a", 4), "語a");
+        assert_eq!("語a日bc本".overlap_start_min("b日本語a", 5), "");
+    }
+
+    #[test]
+    fn min_overlap_multi_byte_end() {
+        assert_eq!("b日本語a".overlap_end_min("語a日bc本", 4), "語a");
+        assert_eq!("b日本語a".overlap_end_min("語a日bc本", 5), "");
+    }
+
+    #[test]
+    fn slice_min_overlap_start() {
+        assert_eq!([2u32, 3, 4].overlap_start_min(&[1, 2, 3], 2), [2, 3]);
+        assert_eq!([2u32, 3, 4].overlap_start_min(&[1, 2, 3], 3), []);
+    }
+
+    #[test]
+    fn slice_min_overlap_end() {
+        assert_eq!([1u32, 2, 3].overlap_end_min(&[2, 3, 4], 2), [2, 3]);
+        assert_eq!([1u32, 2, 3].overlap_end_min(&[2, 3, 4], 3), []);
+    }
+
+    #[test]
+    fn max_overlap_truncated_start() {
+        assert_eq!("bcd".overlap_start_max("abc", 1), "b");
+    }
+
+    #[test]
+    fn max_overlap_truncated_end() {
+        assert_eq!("abc".overlap_end_max("bcd", 1), "c");
+    }
+
+    #[test]
+    fn max_overlap_not_truncated_start() {
+        assert_eq!("bcd".overlap_start_max("abc", 2), "bc");
+        assert_eq!("bcd".overlap_start_max("abc", 5), "bc");
+    }
+
+    #[test]
+    fn max_overlap_not_truncated_end() {
+        assert_eq!("abc".overlap_end_max("bcd", 2), "bc");
+        assert_eq!("abc".overlap_end_max("bcd", 5), "bc");
+    }
+
+    #[test]
+    fn max_overlap_zero_start() {
+        assert_eq!("bcd".overlap_start_max("abc", 0), "");
+    }
+
+    #[test]
+    fn max_overlap_zero_end() {
+        assert_eq!("abc".overlap_end_max("bcd", 0), "");
+    }
+
+    #[test]
+    fn max_overlap_multi_byte_start() {
+        // The overlap is "語a", where "語" is 3 bytes long.
+        assert_eq!("語a日bc本".overlap_start_max("b日本語a", 1), "");
+        assert_eq!("語a日bc本".overlap_start_max("b日本語a", 2), "");
+        assert_eq!("語a日bc本".overlap_start_max("b日本語a", 3), "語");
+        assert_eq!("語a日bc本".overlap_start_max("b日本語a", 4), "語a");
+    }
+
+    #[test]
+    fn max_overlap_multi_byte_end() {
+        // The overlap is "a語", where "語" is 3 bytes long.
+        assert_eq!("ba語".overlap_end_max("a語b", 1), "");
+        assert_eq!("ba語".overlap_end_max("a語b", 2), "");
+        assert_eq!("ba語".overlap_end_max("a語b", 3), "語");
+        assert_eq!("ba語".overlap_end_max("a語b", 4), "a語");
+    }
+
+    #[test]
+    fn overlap_index_slices_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("a語b語", "語b語c"),
+            ("", "abc"),
+            ("abc", ""),
+        ];
+        for &(left, right) in &pairs {
+            let index = ::overlap_index(left, right);
+            assert_eq!(&left[index..], left.overlap_end(right));
+            assert_eq!(&right[..(left.len() - index)], left.overlap_end(right));
+        }
+    }
+
+    #[test]
+    fn limit_chars_start() {
+        assert_eq!("bcd".overlap_start_limit_chars("abc", 1), "b");
+        assert_eq!("bcd".overlap_start_limit_chars("abc", 2), "bc");
+        assert_eq!("bcd".overlap_start_limit_chars("abc", 5), "bc");
+        assert_eq!("bcd".overlap_start_limit_chars("abc", 0), "");
+    }
+
+    #[test]
+    fn limit_chars_end() {
+        assert_eq!("abc".overlap_end_limit_chars("bcd", 1), "c");
+        assert_eq!("abc".overlap_end_limit_chars("bcd", 2), "bc");
+        assert_eq!("abc".overlap_end_limit_chars("bcd", 5), "bc");
+        assert_eq!("abc".overlap_end_limit_chars("bcd", 0), "");
+    }
+
+    #[test]
+    fn limit_chars_multi_byte_start() {
+        // The overlap is "語a", where "語" is 3 bytes long.
+        assert_eq!("語a日bc本".overlap_start_limit_chars("b日本語a", 0), "");
+        assert_eq!("語a日bc本".overlap_start_limit_chars("b日本語a", 1), "語");
+        assert_eq!("語a日bc本".overlap_start_limit_chars("b日本語a", 2), "語a");
+        assert_eq!("語a日bc本".overlap_start_limit_chars("b日本語a", 3), "語a");
+    }
+
+    #[test]
+    fn limit_chars_multi_byte_end() {
+        // The overlap is "日本語", which is 3 characters but 9 bytes long.
+        assert_eq!("a日本語".overlap_end_limit_chars("日本語b", 1), "語");
+        assert_eq!("a日本語".overlap_end_limit_chars("日本語b", 2), "本語");
+        assert_eq!("a日本語".overlap_end_limit_chars("日本語b", 3), "日本語");
+        assert_eq!("a日本語".overlap_end_limit_chars("日本語b", 9), "日本語");
+        // Characters of different widths count the same.
+        assert_eq!("x😀é".overlap_end_limit_chars("😀éy", 1), "é");
+        assert_eq!("x😀é".overlap_end_limit_chars("😀éy", 2), "😀é");
+    }
+
+    #[test]
+    fn multi_end_longest() {
+        assert_eq!(
+            "abc".overlap_end_multi(&["cx", "bcd", "bce"]),
+            Some((1, "bc"))
+        );
+        assert_eq!(
+            "abc".overlap_end_multi(&["def", "c", "abcd"]),
+            Some((2, "abc"))
+        );
+    }
+
+    #[test]
+    fn multi_end_tie_lowest_index() {
+        assert_eq!(
+            "abc".overlap_end_multi(&["x", "bcd", "bce"]),
+            Some((1, "bc"))
+        );
+        assert_eq!("abc".overlap_end_multi(&["cd", "ce", "cf"]), Some((0, "c")));
+    }
+
+    #[test]
+    fn multi_end_none() {
+        assert_eq!("abc".overlap_end_multi(&["x", "y"]), None);
+        assert_eq!("abc".overlap_end_multi(&[]), None);
+        assert_eq!("".overlap_end_multi(&["abc"]), None);
+    }
+
+    #[test]
+    fn multi_end_borrows_from_self() {
+        let s = "abc";
+
+        let (_, overlap) = s.overlap_end_multi(&["x", "bcd"]).unwrap();
+        assert_eq!(overlap.as_ptr(), s[1..].as_ptr());
+    }
+
+    #[test]
+    fn multi_end_multi_byte() {
+        assert_eq!(
+            "a語b語".overlap_end_multi(&["語", "語b語c", "b"]),
+            Some((1, "語b語"))
+        );
+    }
+
+    #[test]
+    fn multi_start() {
+        assert_eq!(
+            "cde".overlap_start_multi(&["xc", "abcd", "bcd"]),
+            Some((1, "cd"))
+        );
+        assert_eq!("cde".overlap_start_multi(&["x", "y"]), None);
+        assert_eq!("cde".overlap_start_multi(&[]), None);
+    }
+
+    #[test]
+    fn slice_max_overlap_start() {
+        assert_eq!([2u32, 3, 4].overlap_start_max(&[1, 2, 3], 1), [2]);
+        assert_eq!([2u32, 3, 4].overlap_start_max(&[1, 2, 3], 2), [2, 3]);
+    }
+
+    #[test]
+    fn slice_max_overlap_end() {
+        assert_eq!([1u32, 2, 3].overlap_end_max(&[2, 3, 4], 1), [3]);
+        assert_eq!([1u32, 2, 3].overlap_end_max(&[2, 3, 4], 2), [2, 3]);
     }
 }
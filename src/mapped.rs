@@ -0,0 +1,181 @@
+//! Strings read as a sequence of units, each mapped back to the bytes it was read from.
+//!
+//! Several comparison modes find overlaps between strings after transforming them, such as by
+//! decoding escapes or folding case, while still reporting the overlap as ranges within the
+//! original strings. Each mode only reads its strings into [`Mapped`] units, and the overlap is
+//! found and mapped back to the original strings here.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+use {slice_overlap_index, slice_overlap_index_at_boundary};
+
+/// A string read as a sequence of units, along with the byte range of the original string that
+/// each unit was read from.
+///
+/// Several units may be read from the same range, such as when a tab is expanded to spaces, and a
+/// single unit may be read from several characters, such as when an escape is decoded.
+pub struct Mapped<U> {
+    /// Each unit.
+    pub units: Vec<U>,
+    /// The byte range in the original string of each unit.
+    pub ranges: Vec<Range<usize>>,
+    /// The length in bytes of the original string.
+    source_len: usize,
+}
+
+impl<U> Mapped<U> {
+    /// Creates an empty sequence of units, to be read from an original string of `source_len`
+    /// bytes.
+    #[inline]
+    pub fn new(source_len: usize) -> Mapped<U> {
+        Mapped {
+            units: Vec::with_capacity(source_len),
+            ranges: Vec::with_capacity(source_len),
+            source_len,
+        }
+    }
+
+    /// Appends `unit`, read from the bytes of the original string within `range`.
+    #[inline]
+    pub fn push(&mut self, unit: U, range: Range<usize>) {
+        self.units.push(unit);
+        self.ranges.push(range);
+    }
+
+    /// Returns whether the unit index `index` is on a bound between the ranges units were read
+    /// from, rather than between several units read from the same range.
+    #[inline]
+    pub fn is_boundary(&self, index: usize) -> bool {
+        index == 0
+            || index == self.units.len()
+            || self.ranges[index - 1].start != self.ranges[index].start
+    }
+
+    /// Returns the byte ranges of the overlap beginning at the unit index `index` into `self` and
+    /// ending within `right`, within the original strings of `self` and `right` respectively.
+    ///
+    /// If the overlap is empty, the ranges are empty and located at the end of the original string
+    /// of `self` and the start of the original string of `right`.
+    #[inline]
+    fn source_ranges(&self, right: &Mapped<U>, index: usize) -> (Range<usize>, Range<usize>) {
+        let len = self.units.len() - index;
+        if len == 0 {
+            (self.source_len..self.source_len, 0..0)
+        } else {
+            (
+                self.ranges[index].start..self.source_len,
+                0..right.ranges[len - 1].end,
+            )
+        }
+    }
+}
+
+impl<U> Mapped<U>
+where
+    U: PartialEq,
+{
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of
+    /// `right`, within the original strings of `self` and `right` respectively.
+    ///
+    /// Units are always matched whole, so neither range ever begins or ends within the bytes a
+    /// unit was read from.
+    #[inline]
+    pub fn overlap_indices(&self, right: &Mapped<U>) -> (Range<usize>, Range<usize>) {
+        self.source_ranges(right, slice_overlap_index(&self.units, &right.units))
+    }
+
+    /// Returns the byte ranges of the overlap found at the end of `self` and the start of
+    /// `right`, only accepting overlaps whose bounds satisfy `is_boundary`.
+    ///
+    /// `is_boundary` is called with each candidate unit index into `self`, in the same way as in
+    /// [`slice_overlap_index_at_boundary`].
+    #[inline]
+    pub fn overlap_indices_at_boundary<F>(
+        &self,
+        right: &Mapped<U>,
+        is_boundary: F,
+    ) -> (Range<usize>, Range<usize>)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        self.source_ranges(
+            right,
+            slice_overlap_index_at_boundary(&self.units, &right.units, is_boundary),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mapped;
+
+    /// Reads each character of `s` as a unit, except that `"<>"` is read as the single unit `'='`.
+    fn read(s: &str) -> Mapped<char> {
+        let mut mapped = Mapped::new(s.len());
+        let mut index = 0;
+        while index < s.len() {
+            if s[index..].starts_with("<>") {
+                mapped.push('=', index..(index + 2));
+                index += 2;
+                continue;
+            }
+            let c = s[index..].chars().next().unwrap();
+            mapped.push(c, index..(index + c.len_utf8()));
+            index += c.len_utf8();
+        }
+        mapped
+    }
+
+    #[test]
+    fn overlap_indices() {
+        assert_eq!(read("ab<>").overlap_indices(&read("b=c")), (1..4, 0..2));
+        assert_eq!(read("ab=").overlap_indices(&read("b<>c")), (1..3, 0..3));
+        assert_eq!(read("a語").overlap_indices(&read("語b")), (1..4, 0..3));
+    }
+
+    #[test]
+    fn units_matched_whole() {
+        // The '<' at the end of `left` can't match the start of a "<>" unit in `right`.
+        assert_eq!(read("a<").overlap_indices(&read("<>b")), (2..2, 0..0));
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(read("abc").overlap_indices(&read("def")), (3..3, 0..0));
+        assert_eq!(read("").overlap_indices(&read("abc")), (0..0, 0..0));
+        assert_eq!(read("abc").overlap_indices(&read("")), (3..3, 0..0));
+    }
+
+    #[test]
+    fn is_boundary() {
+        let mut mapped = Mapped::new(2);
+        mapped.push(' ', 0..1);
+        mapped.push(' ', 0..1);
+        mapped.push('a', 1..2);
+
+        assert!(mapped.is_boundary(0));
+        assert!(!mapped.is_boundary(1));
+        assert!(mapped.is_boundary(2));
+        assert!(mapped.is_boundary(3));
+    }
+
+    #[test]
+    fn overlap_indices_at_boundary() {
+        let left = read("abab");
+        let right = read("ababc");
+
+        assert_eq!(
+            left.overlap_indices_at_boundary(&right, |_| true),
+            (0..4, 0..4)
+        );
+        // Rejecting the longest candidate falls back to the next one.
+        assert_eq!(
+            left.overlap_indices_at_boundary(&right, |index| index != 0),
+            (2..4, 0..2)
+        );
+        assert_eq!(
+            left.overlap_indices_at_boundary(&right, |_| false),
+            (4..4, 0..0)
+        );
+    }
+}
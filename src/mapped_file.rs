@@ -0,0 +1,203 @@
+//! Overlap support for large files, using the [`memmap2`] crate.
+//!
+//! Only the end of one file and the start of the other can take part in an overlap, so only those
+//! windows are memory-mapped, rather than reading either file in full.
+//!
+//! [`memmap2`]: https://docs.rs/memmap2
+
+use core::cmp;
+#[cfg(not(feature = "safe"))]
+use memmap2::MmapOptions;
+use std::fs::File;
+use std::io;
+#[cfg(feature = "safe")]
+use std::io::{Read, Seek, SeekFrom};
+#[cfg(feature = "safe")]
+use std::vec::Vec;
+use Overlap;
+
+/// Maps `len` bytes of `file` into memory, beginning at `offset`.
+///
+/// `len` must not be `0`, since empty mappings are not supported on all platforms.
+#[cfg(not(feature = "safe"))]
+#[inline]
+fn window(file: &File, offset: u64, len: usize) -> io::Result<memmap2::Mmap> {
+    unsafe {
+        // SAFETY: The mapping is only read while comparing, and is dropped before returning. The
+        // caller is responsible for the file not being modified during that time, as documented on
+        // `file_overlap_end()`.
+        MmapOptions::new().offset(offset).len(len).map(file)
+    }
+}
+
+/// Reads `len` bytes of `file` into memory, beginning at `offset`.
+///
+/// When the `safe` feature is enabled, files are read rather than memory-mapped, since mapping a
+/// file requires `unsafe`.
+#[cfg(feature = "safe")]
+#[inline]
+fn window(mut file: &File, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(len);
+    file.seek(SeekFrom::Start(offset))?;
+    file.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() == len {
+        Ok(bytes)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "file is shorter than its metadata",
+        ))
+    }
+}
+
+/// Returns the length in bytes of the overlap found at the end of `a` and the start of `b`,
+/// considering at most the last `max_window` bytes of `a` and the first `max_window` bytes of `b`.
+///
+/// Only those windows are memory-mapped, so neither file is read in full. The returned length is
+/// therefore at most `max_window`, even if the files overlap further. Files shorter than
+/// `max_window` are considered in full.
+///
+/// Bytes are compared exactly. No decoding of the files' contents is performed.
+///
+/// The files must not be modified, by this or any other process, while this function runs.
+/// Modifying a memory-mapped file can change the mapped bytes as they are being compared, which is
+/// undefined behavior. When the `safe` feature is enabled, the windows are instead read into memory
+/// using the files' cursors, which are left at unspecified positions.
+///
+/// This function is only available when the `memmap2` and `std` features are enabled.
+///
+/// # Errors
+/// Returns an error if either file's metadata can't be read, or either window can't be mapped.
+///
+/// # Example
+/// ```no_run
+/// extern crate str_overlap;
+///
+/// use std::fs::File;
+/// use str_overlap::file_overlap_end;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let a = File::open("a.log")?;
+/// let b = File::open("b.log")?;
+///
+/// let overlap_len = file_overlap_end(&a, &b, 1 << 20)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn file_overlap_end(a: &File, b: &File, max_window: usize) -> io::Result<u64> {
+    let a_len = a.metadata()?.len();
+    let b_len = b.metadata()?.len();
+    // Converting to `usize` can only lower the bound if the file is longer than `usize::MAX`, in
+    // which case `max_window` is the smaller of the two anyway.
+    let a_window_len = cmp::min(a_len, max_window as u64) as usize;
+    let b_window_len = cmp::min(b_len, max_window as u64) as usize;
+    if a_window_len == 0 || b_window_len == 0 {
+        return Ok(0);
+    }
+
+    let a_window = window(a, a_len - a_window_len as u64, a_window_len)?;
+    let b_window = window(b, 0, b_window_len)?;
+    Ok(a_window[..].overlap_end_len(&b_window[..]) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::file_overlap_end;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::process;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    /// A file in the temporary directory that is removed when dropped.
+    struct TempFile {
+        path: PathBuf,
+        file: File,
+    }
+
+    impl TempFile {
+        fn new(name: &str, contents: &[u8]) -> TempFile {
+            let mut file_name = String::from("str_overlap_");
+            file_name.push_str(name);
+            file_name.push('_');
+            file_name.push_str(&process::id().to_string());
+            let path = env::temp_dir().join(file_name);
+            File::create(&path).unwrap().write_all(contents).unwrap();
+            let file = File::open(&path).unwrap();
+            TempFile { path, file }
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+
+    #[test]
+    fn partial_overlap() {
+        let a = TempFile::new("partial_a", b"abcdef");
+        let b = TempFile::new("partial_b", b"defghi");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 16).unwrap(), 3);
+    }
+
+    #[test]
+    fn no_overlap() {
+        let a = TempFile::new("none_a", b"abc");
+        let b = TempFile::new("none_b", b"xyz");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 16).unwrap(), 0);
+    }
+
+    #[test]
+    fn files_shorter_than_window() {
+        let a = TempFile::new("short_a", b"ab");
+        let b = TempFile::new("short_b", b"bcdefghijk");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 4).unwrap(), 1);
+        assert_eq!(file_overlap_end(&a.file, &b.file, 1 << 20).unwrap(), 1);
+    }
+
+    #[test]
+    fn limited_by_window() {
+        let a = TempFile::new("limited_a", b"xaaaa");
+        let b = TempFile::new("limited_b", b"aaaay");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 16).unwrap(), 4);
+        assert_eq!(file_overlap_end(&a.file, &b.file, 2).unwrap(), 2);
+        assert_eq!(file_overlap_end(&a.file, &b.file, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn empty_file() {
+        let a = TempFile::new("empty_a", b"");
+        let b = TempFile::new("empty_b", b"abc");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 16).unwrap(), 0);
+        assert_eq!(file_overlap_end(&b.file, &a.file, 16).unwrap(), 0);
+    }
+
+    #[test]
+    fn window_beyond_page() {
+        // The window of `a` begins at an offset that isn't aligned to a page.
+        let mut contents = Vec::new();
+        contents.resize(10_000, b'x');
+        contents.extend_from_slice(b"abc");
+        let a = TempFile::new("page_a", &contents);
+        let b = TempFile::new("page_b", b"bcd");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 3).unwrap(), 2);
+        assert_eq!(file_overlap_end(&a.file, &b.file, 4097).unwrap(), 2);
+    }
+
+    #[test]
+    fn binary_contents() {
+        let a = TempFile::new("binary_a", b"\x00\xFF\xC3\x28");
+        let b = TempFile::new("binary_b", b"\xC3\x28\x00");
+
+        assert_eq!(file_overlap_end(&a.file, &b.file, 16).unwrap(), 2);
+    }
+}
@@ -0,0 +1,131 @@
+//! Overlaps of strings that treat `"\r\n"` and `'\n'` line endings as equal.
+
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Splits `s` into characters, where each `"\r\n"` line ending is read as a single `'\n'`.
+#[inline]
+fn unify(s: &str) -> Mapped<char> {
+    let mut unified = Mapped::new(s.len());
+    let bytes = s.as_bytes();
+    for (index, c) in s.char_indices() {
+        if c == '\n' && index > 0 && bytes[index - 1] == b'\r' {
+            // Extend the preceding '\r' to the whole line ending.
+            let last = unified.units.len() - 1;
+            unified.units[last] = '\n';
+            unified.ranges[last].end = index + 1;
+            continue;
+        }
+        unified.push(c, index..(index + c.len_utf8()));
+    }
+    unified
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when `"\r\n"` and `'\n'` are treated as equal, within `left` and within `right` respectively.
+///
+/// A `'\r'` at the end of `left` may match either a `'\r'` or a line ending, since `left` may have
+/// been cut between the `'\r'` and `'\n'` of a `"\r\n"`, and whichever reading gives the longer
+/// overlap is used. Otherwise, line endings are always matched whole. If nothing overlaps, the
+/// ranges are empty and located at the end of `left` and the start of `right`.
+#[inline]
+pub fn newline_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    let mut left_unified = unify(left);
+    let right_unified = unify(right);
+    let literal = left_unified.overlap_indices(&right_unified);
+    if !left.ends_with('\r') {
+        return literal;
+    }
+    // Read the trailing '\r' as the start of a cut line ending instead.
+    let last = left_unified.units.len() - 1;
+    left_unified.units[last] = '\n';
+    let cut = left_unified.overlap_indices(&right_unified);
+    if cut.0.start < literal.0.start {
+        cut
+    } else {
+        literal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::newline_overlap_indices;
+    use StrOverlap;
+
+    #[test]
+    fn crlf_then_lf() {
+        assert_eq!(
+            "a\r\nb\r\nc".overlap_end_newline_insensitive("b\nc\nd"),
+            (3..7, 0..3)
+        );
+        assert_eq!(
+            "b\nc\nd".overlap_start_newline_insensitive("a\r\nb\r\nc"),
+            (0..3, 3..7)
+        );
+    }
+
+    #[test]
+    fn lf_then_crlf() {
+        assert_eq!(
+            "a\nb\nc".overlap_end_newline_insensitive("b\r\nc\r\nd"),
+            (2..5, 0..4)
+        );
+    }
+
+    #[test]
+    fn mixed_endings_within_overlap() {
+        let left = "x\na\r\nb\nc";
+        let right = "a\nb\r\nc\r\nd";
+
+        assert_eq!(left.overlap_end_newline_insensitive(right), (2..8, 0..6));
+        assert_eq!(&left[2..8], "a\r\nb\nc");
+        assert_eq!(&right[0..6], "a\nb\r\nc");
+    }
+
+    #[test]
+    fn seam_between_cr_and_lf() {
+        // `self` was cut between the '\r' and '\n' of a line ending.
+        assert_eq!(
+            "x\na\r".overlap_end_newline_insensitive("a\r\nb"),
+            (2..4, 0..3)
+        );
+        assert_eq!(
+            "x\na\r".overlap_end_newline_insensitive("a\nb"),
+            (2..4, 0..2)
+        );
+        // The trailing '\r' still matches a literal '\r'.
+        assert_eq!(
+            "x\na\r".overlap_end_newline_insensitive("a\rb"),
+            (2..4, 0..2)
+        );
+        // Only `self` may be cut partway through a line ending, so a lone '\r' in `other` is not
+        // a line ending.
+        assert_eq!(
+            "x\na\n".overlap_end_newline_insensitive("a\rb"),
+            (4..4, 0..0)
+        );
+        assert_eq!(
+            "a\r\nb".overlap_start_newline_insensitive("x\na\r"),
+            (0..3, 2..4)
+        );
+    }
+
+    #[test]
+    fn lone_cr_is_not_a_line_ending() {
+        assert_eq!(newline_overlap_indices("a\rb", "a\nb"), (3..3, 0..0));
+        assert_eq!(newline_overlap_indices("a\rb", "a\rb"), (0..3, 0..3));
+    }
+
+    #[test]
+    fn line_ending_alone() {
+        assert_eq!(newline_overlap_indices("a\r\n", "\nb"), (1..3, 0..1));
+        assert_eq!(newline_overlap_indices("a\n", "\r\nb"), (1..2, 0..2));
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(newline_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(newline_overlap_indices("", "a"), (0..0, 0..0));
+        assert_eq!(newline_overlap_indices("a", ""), (1..1, 0..0));
+    }
+}
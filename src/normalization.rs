@@ -0,0 +1,576 @@
+//! Overlap support for canonically equivalent strings, using the [`unicode-normalization`] crate.
+//!
+//! The same text can be encoded in more than one way. For example, "é" can be a single precomposed
+//! `char`, or an "e" followed by a combining acute accent. Comparing bytes alone, these share no
+//! overlap, so both strings are normalized to Normalization Form C (NFC) before being compared.
+//!
+//! Compatibility equivalence is also supported, using Normalization Form KC (NFKC). This
+//! additionally treats characters such as the ligature "ﬁ" as equal to "fi". Text can also be
+//! compared regardless of diacritics, by decomposing it and removing its combining marks, or
+//! regardless of character width, by applying only the compatibility mappings of fullwidth and
+//! halfwidth forms. With the `unicode-security` feature, text can also be compared by its
+//! confusable skeleton, which treats visually confusable characters as equal.
+//!
+//! [`unicode-normalization`]: https://docs.rs/unicode-normalization
+
+use alloc::string::String;
+use core::ops::Range;
+use mapped::Mapped;
+use unicode_normalization::char::{
+    canonical_combining_class, compose, decompose_canonical, decompose_compatible,
+    is_combining_mark,
+};
+use unicode_normalization::UnicodeNormalization;
+use Overlap;
+
+/// Returns the overlap found at the end of `left` and the start of `right`, after normalizing
+/// both to NFC.
+///
+/// The overlap is taken from the normalized `left`, so it is in NFC regardless of how either input
+/// was encoded.
+#[inline]
+pub fn nfc_overlap_end(left: &str, right: &str) -> String {
+    let left: String = left.nfc().collect();
+    let right: String = right.nfc().collect();
+    String::from(left.overlap_end(&right))
+}
+
+/// A Unicode normalization form.
+#[derive(Clone, Copy)]
+enum Form {
+    /// Normalization Form C, for canonical equivalence.
+    Nfc,
+    /// Normalization Form KC, for compatibility equivalence.
+    Nfkc,
+    /// Normalization Form D with every combining mark removed, for comparing regardless of
+    /// diacritics.
+    Base,
+    /// Normalization Form C after mapping fullwidth and halfwidth forms to their usual width, for
+    /// comparing regardless of width.
+    Width,
+    /// The confusable skeleton defined by Unicode Technical Standard #39, for comparing visually
+    /// confusable text.
+    #[cfg(feature = "unicode-security")]
+    Skeleton,
+}
+
+/// Returns whether `c` is a fullwidth or halfwidth form, which is the ideographic space or any
+/// character in the Halfwidth and Fullwidth Forms block.
+#[inline]
+fn is_width_variant(c: char) -> bool {
+    c == '\u{3000}' || ('\u{ff00}'..='\u{ffef}').contains(&c)
+}
+
+impl Form {
+    /// Returns the first character of the decomposition of `c` in this form.
+    #[inline]
+    fn first_decomposed(self, c: char) -> char {
+        let mut first = None;
+        let mut emit = |decomposed| {
+            if first.is_none() {
+                first = Some(decomposed);
+            }
+        };
+        match self {
+            Form::Nfc | Form::Base => decompose_canonical(c, &mut emit),
+            Form::Nfkc => decompose_compatible(c, &mut emit),
+            Form::Width if is_width_variant(c) => decompose_compatible(c, &mut emit),
+            Form::Width => decompose_canonical(c, &mut emit),
+            #[cfg(feature = "unicode-security")]
+            Form::Skeleton => decompose_canonical(c, &mut emit),
+        }
+        first.unwrap_or(c)
+    }
+}
+
+/// Normalizes `s` to `form`, one segment at a time.
+///
+/// Each segment is a starter followed by every character that may combine with it, which is any
+/// character that decomposes to begin with a non-starter, or any starter that composes with the
+/// preceding character. Normalizing segments separately gives the same characters as normalizing
+/// the whole string, while ensuring that each normalized character can be traced back to a single
+/// segment of the original string.
+#[inline]
+fn normalize(s: &str, form: Form) -> Mapped<char> {
+    let mut normalized = Mapped::new(s.len());
+    let mut segment_start = 0;
+    let mut previous = None;
+    for (index, c) in s.char_indices() {
+        let first = form.first_decomposed(c);
+        previous = match previous.map(|previous| compose(previous, c).or(compose(previous, first)))
+        {
+            Some(Some(composed)) => Some(composed),
+            Some(None) if canonical_combining_class(first) != 0 => Some(c),
+            Some(None) => {
+                push_segment(&mut normalized, s, segment_start..index, form);
+                segment_start = index;
+                Some(c)
+            }
+            None => Some(c),
+        };
+    }
+    push_segment(&mut normalized, s, segment_start..s.len(), form);
+    normalized
+}
+
+/// Normalizes the segment of `s` within `range` to `form`, and appends it to `normalized`.
+#[inline]
+fn push_segment(normalized: &mut Mapped<char>, s: &str, range: Range<usize>, form: Form) {
+    let segment = &s[range.clone()];
+    let mut push = |c| normalized.push(c, range.clone());
+    match form {
+        Form::Nfc => segment.nfc().for_each(&mut push),
+        Form::Nfkc => segment.nfkc().for_each(&mut push),
+        Form::Base => segment
+            .nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .for_each(&mut push),
+        Form::Width => {
+            let mut folded = String::with_capacity(segment.len());
+            for c in segment.chars() {
+                if is_width_variant(c) {
+                    decompose_compatible(c, |decomposed| folded.push(decomposed));
+                } else {
+                    folded.push(c);
+                }
+            }
+            // A halfwidth voiced sound mark maps to a combining mark, which composes with the
+            // preceding kana.
+            folded.nfc().for_each(&mut push);
+        }
+        #[cfg(feature = "unicode-security")]
+        Form::Skeleton => unicode_security::skeleton(segment).for_each(&mut push),
+    }
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// after normalizing to `form`, within `left` and within `right` respectively.
+///
+/// The overlap is the longest one whose normalized characters are equal and whose bounds never
+/// lie within a combining character sequence of either original string.
+#[inline]
+fn normalized_overlap_indices(left: &str, right: &str, form: Form) -> (Range<usize>, Range<usize>) {
+    let left_normalized = normalize(left, form);
+    let right_normalized = normalize(right, form);
+    let len = left_normalized.units.len();
+    left_normalized.overlap_indices_at_boundary(&right_normalized, |index| {
+        left_normalized.is_boundary(index) && right_normalized.is_boundary(len - index)
+    })
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// under canonical equivalence, within `left` and within `right` respectively.
+///
+/// Both strings are normalized to NFC while comparing.
+#[inline]
+#[must_use]
+pub fn nfc_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    normalized_overlap_indices(left, right, Form::Nfc)
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// under compatibility equivalence, within `left` and within `right` respectively.
+///
+/// Both strings are normalized to NFKC while comparing.
+#[inline]
+#[must_use]
+pub fn nfkc_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    normalized_overlap_indices(left, right, Form::Nfkc)
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// regardless of diacritics, within `left` and within `right` respectively.
+///
+/// Both strings are decomposed to NFD, and every combining mark is removed, while comparing.
+#[inline]
+#[must_use]
+pub fn base_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    normalized_overlap_indices(left, right, Form::Base)
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// regardless of character width, within `left` and within `right` respectively.
+///
+/// Fullwidth and halfwidth forms are mapped to their usual width, and both strings are then
+/// normalized to NFC, while comparing.
+#[inline]
+#[must_use]
+pub fn width_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    normalized_overlap_indices(left, right, Form::Width)
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when visually confusable characters are treated as equal, within `left` and within `right`
+/// respectively.
+///
+/// Both strings are mapped to their confusable skeletons while comparing.
+#[cfg(feature = "unicode-security")]
+#[inline]
+#[must_use]
+pub fn skeleton_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    normalized_overlap_indices(left, right, Form::Skeleton)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nfc_overlap_end, normalize, Form};
+    #[cfg(feature = "unicode-security")]
+    use Overlap;
+    use StrOverlap;
+
+    #[test]
+    fn precomposed_and_decomposed() {
+        assert_eq!("caf\u{e9}".overlap_end_nfc("e\u{301}s"), "\u{e9}");
+        assert_eq!("cafe\u{301}".overlap_end_nfc("\u{e9}s"), "\u{e9}");
+        assert_eq!("\u{e9}s".overlap_start_nfc("cafe\u{301}"), "\u{e9}");
+    }
+
+    #[test]
+    fn result_is_normalized() {
+        // Both inputs are decomposed, but the overlap is returned in NFC.
+        assert_eq!("cafe\u{301}".overlap_end_nfc("e\u{301}s"), "\u{e9}");
+    }
+
+    #[test]
+    fn already_normalized() {
+        assert_eq!(nfc_overlap_end("abc", "bcd"), "bc");
+        assert_eq!(nfc_overlap_end("abc", "xyz"), "");
+        assert_eq!(nfc_overlap_end("", "abc"), "");
+    }
+
+    #[test]
+    fn combining_mark_does_not_match_alone() {
+        // Once normalized, the accent in `left` is part of "é", so it can't match the accent
+        // alone.
+        assert_eq!("cafe\u{301}".overlap_end_nfc("\u{301}s"), "");
+    }
+
+    #[test]
+    fn reordered_combining_marks() {
+        // A dot below and an acute accent are canonically equivalent in either order.
+        assert_eq!(
+            "xa\u{301}\u{323}".overlap_end_nfc("a\u{323}\u{301}y"),
+            "\u{1ea1}\u{301}"
+        );
+    }
+
+    #[test]
+    fn segments() {
+        let normalized = normalize("ae\u{301}\u{323}b", Form::Nfc);
+
+        assert_eq!(normalized.units, ['a', '\u{1eb9}', '\u{301}', 'b']);
+        assert_eq!(normalized.ranges, [0..1, 1..6, 1..6, 6..7]);
+    }
+
+    #[test]
+    fn indices_precomposed_and_decomposed() {
+        // "café" with a precomposed "é", and with a decomposed one.
+        assert_eq!(
+            "caf\u{e9}".overlap_end_nfc_indices("cafe\u{301}s"),
+            (0..5, 0..6)
+        );
+        assert_eq!(
+            "cafe\u{301}".overlap_end_nfc_indices("caf\u{e9}s"),
+            (0..6, 0..5)
+        );
+        assert_eq!(
+            "cafe\u{301}s".overlap_start_nfc_indices("xcaf\u{e9}"),
+            (0..6, 1..6)
+        );
+    }
+
+    #[test]
+    fn indices_mixed_forms_on_both_sides() {
+        assert_eq!(
+            "x\u{e9}e\u{301}".overlap_end_nfc_indices("e\u{301}\u{e9}y"),
+            (1..6, 0..5)
+        );
+    }
+
+    #[test]
+    fn indices_not_within_combining_sequence() {
+        // The "e" alone matches the start of `right`, but the overlap can't end before the accent.
+        assert_eq!("abe".overlap_end_nfc_indices("e\u{301}"), (3..3, 0..0));
+        // The accent alone matches the end of `left`, but the overlap can't begin after the "e".
+        assert_eq!("e\u{301}".overlap_end_nfc_indices("\u{301}x"), (3..3, 0..0));
+    }
+
+    #[test]
+    fn indices_reordered_combining_marks() {
+        assert_eq!(
+            "xa\u{301}\u{323}".overlap_end_nfc_indices("a\u{323}\u{301}y"),
+            (1..6, 0..5)
+        );
+    }
+
+    #[test]
+    fn indices_hangul() {
+        // "가" precomposed, and as the jamo "ᄀ" followed by "ᅡ".
+        assert_eq!(
+            "x\u{ac00}".overlap_end_nfc_indices("\u{1100}\u{1161}y"),
+            (1..4, 0..6)
+        );
+        // "각" as "ᄀ", "ᅡ", "ᆨ", which can't be split after the first two.
+        assert_eq!(
+            "x\u{1100}\u{1161}\u{11a8}".overlap_end_nfc_indices("\u{ac01}"),
+            (1..10, 0..3)
+        );
+        assert_eq!(
+            "x\u{1100}\u{1161}\u{11a8}".overlap_end_nfc_indices("\u{ac00}"),
+            (10..10, 0..0)
+        );
+    }
+
+    #[test]
+    fn indices_no_overlap() {
+        assert_eq!("abc".overlap_end_nfc_indices("xyz"), (3..3, 0..0));
+        assert_eq!("".overlap_end_nfc_indices(""), (0..0, 0..0));
+    }
+
+    #[test]
+    fn nfkc_ligatures() {
+        // "ﬁ" is a single character, compatibility equivalent to "fi".
+        assert_eq!("pro\u{fb01}".overlap_end_nfkc_indices("file"), (3..6, 0..2));
+        assert_eq!("profi".overlap_end_nfkc_indices("\u{fb01}le"), (3..5, 0..3));
+        // Half of the ligature is never part of the overlap.
+        assert_eq!("pro\u{fb01}".overlap_end_nfkc_indices("ix"), (6..6, 0..0));
+        assert_eq!("xf".overlap_end_nfkc_indices("\u{fb01}x"), (2..2, 0..0));
+    }
+
+    #[test]
+    fn nfkc_superscript_digits() {
+        assert_eq!("x\u{b2}".overlap_end_nfkc_indices("2y"), (1..3, 0..1));
+        assert_eq!("m\u{b2}\u{b3}".overlap_end_nfkc_indices("23"), (1..5, 0..2));
+        // Canonical equivalence alone does not equate these.
+        assert_eq!("x\u{b2}".overlap_end_nfc_indices("2y"), (3..3, 0..0));
+    }
+
+    #[test]
+    fn nfkc_fullwidth_latin() {
+        // "ＡＢＣ" in fullwidth forms.
+        assert_eq!(
+            "xx\u{ff21}\u{ff22}\u{ff23}".overlap_end_nfkc_indices("ABCD"),
+            (2..11, 0..3)
+        );
+        assert_eq!(
+            "ABC".overlap_start_nfkc_indices("\u{ff21}\u{ff21}"),
+            (0..1, 3..6)
+        );
+    }
+
+    #[test]
+    fn nfkc_halfwidth_voiced_mark() {
+        // The halfwidth "ｶ" and voiced sound mark "ﾞ" together are equivalent to "ガ".
+        assert_eq!(
+            "x\u{ff76}\u{ff9e}".overlap_end_nfkc_indices("\u{30ac}"),
+            (1..7, 0..3)
+        );
+        assert_eq!(
+            "x\u{ff76}\u{ff9e}".overlap_end_nfkc_indices("\u{30ab}"),
+            (7..7, 0..0)
+        );
+    }
+
+    #[test]
+    fn nfkc_includes_canonical() {
+        assert_eq!(
+            "caf\u{e9}".overlap_end_nfkc_indices("e\u{301}s"),
+            (3..5, 0..3)
+        );
+    }
+
+    #[test]
+    fn base_accents_on_one_side() {
+        let left = "my r\u{e9}sum\u{e9}";
+
+        assert_eq!(
+            left.overlap_end_ignore_diacritics_indices("resume draft"),
+            (3..11, 0..6)
+        );
+        assert_eq!(
+            "resume draft".overlap_start_ignore_diacritics_indices(left),
+            (0..6, 3..11)
+        );
+    }
+
+    #[test]
+    fn base_precomposed_and_decomposed() {
+        assert_eq!(
+            "caf\u{e9}".overlap_end_ignore_diacritics_indices("cafe\u{301}s"),
+            (0..5, 0..6)
+        );
+        assert_eq!(
+            "caf\u{e9}".overlap_end_ignore_diacritics_indices("cafe"),
+            (0..5, 0..4)
+        );
+    }
+
+    #[test]
+    fn base_stacked_diacritics() {
+        // "ệ" has both a circumflex and a dot below.
+        assert_eq!(
+            "Vi\u{1ec7}t".overlap_end_ignore_diacritics_indices("iet nam"),
+            (1..6, 0..3)
+        );
+        // The decomposed marks are stacked in different orders.
+        assert_eq!(
+            "xo\u{302}\u{301}".overlap_end_ignore_diacritics_indices("o\u{301}\u{302}y"),
+            (1..6, 0..5)
+        );
+    }
+
+    #[test]
+    fn base_does_not_split_marks() {
+        // The accent following "e" in `other` is part of the overlap, rather than left behind.
+        assert_eq!(
+            "xe".overlap_end_ignore_diacritics_indices("e\u{301}y"),
+            (1..2, 0..3)
+        );
+        assert_eq!(
+            "xe\u{301}\u{302}".overlap_end_ignore_diacritics_indices("ey"),
+            (1..6, 0..1)
+        );
+    }
+
+    #[test]
+    fn base_letters_still_differ() {
+        assert_eq!(
+            "caf\u{e9}".overlap_end_ignore_diacritics_indices("cafx"),
+            (5..5, 0..0)
+        );
+        // "ø" has no decomposition, so it is not "o" with a mark.
+        assert_eq!(
+            "\u{f8}".overlap_end_ignore_diacritics_indices("o"),
+            (2..2, 0..0)
+        );
+    }
+
+    #[test]
+    fn width_fullwidth_ascii() {
+        assert_eq!(
+            "x\u{ff21}\u{ff22}\u{ff23}".overlap_end_width_insensitive_indices("BCD"),
+            (4..10, 0..2)
+        );
+        assert_eq!(
+            "BCD".overlap_start_width_insensitive_indices("x\u{ff21}\u{ff22}\u{ff23}"),
+            (0..2, 4..10)
+        );
+    }
+
+    #[test]
+    fn width_halfwidth_katakana_with_voiced_mark() {
+        // The halfwidth "ｶ" and voiced sound mark "ﾞ" together are the fullwidth "ガ".
+        assert_eq!(
+            "x\u{ff76}\u{ff9e}".overlap_end_width_insensitive_indices("\u{30ac}y"),
+            (1..7, 0..3)
+        );
+        assert_eq!(
+            "x\u{30ac}".overlap_end_width_insensitive_indices("\u{ff76}\u{ff9e}y"),
+            (1..4, 0..6)
+        );
+        // The semi-voiced "ﾊﾟ" is "パ".
+        assert_eq!(
+            "\u{ff8a}\u{ff9f}".overlap_end_width_insensitive_indices("\u{30d1}"),
+            (0..6, 0..3)
+        );
+    }
+
+    #[test]
+    fn width_does_not_split_voiced_mark() {
+        // "ｶ" alone is "カ", but in `self` it is voiced by the mark following it.
+        assert_eq!(
+            "x\u{ff76}\u{ff9e}".overlap_end_width_insensitive_indices("\u{30ab}"),
+            (7..7, 0..0)
+        );
+        assert_eq!(
+            "x\u{ff76}".overlap_end_width_insensitive_indices("\u{30ab}\u{3099}"),
+            (4..4, 0..0)
+        );
+    }
+
+    #[test]
+    fn width_ideographic_space() {
+        assert_eq!(
+            "a\u{3000}b".overlap_end_width_insensitive_indices(" b"),
+            (1..5, 0..2)
+        );
+    }
+
+    #[test]
+    fn width_only_maps_width() {
+        // Other compatibility mappings, such as ligatures, are not applied.
+        assert_eq!(
+            "pro\u{fb01}".overlap_end_width_insensitive_indices("fi"),
+            (6..6, 0..0)
+        );
+        assert_eq!(
+            "caf\u{e9}".overlap_end_width_insensitive_indices("e\u{301}s"),
+            (3..5, 0..3)
+        );
+    }
+
+    #[cfg(feature = "unicode-security")]
+    #[test]
+    fn confusable_cyrillic_and_latin() {
+        // "раypal" with a Cyrillic "р" and "а".
+        let lookalike = "\u{440}\u{430}ypal";
+
+        assert_eq!(lookalike.overlap_end("paypal"), "");
+        assert_eq!(
+            lookalike.overlap_end_confusable_indices("paypal!"),
+            (0..8, 0..6)
+        );
+        assert_eq!(
+            "x pa".overlap_end_confusable_indices(lookalike),
+            (2..4, 0..4)
+        );
+        assert_eq!(
+            lookalike.overlap_start_confusable_indices("my pa"),
+            (0..4, 3..5)
+        );
+    }
+
+    #[cfg(feature = "unicode-security")]
+    #[test]
+    fn confusable_greek_and_latin() {
+        // "gοοgle" with Greek omicrons.
+        let lookalike = "g\u{3bf}\u{3bf}gle";
+
+        assert_eq!("a goo".overlap_end(lookalike), "");
+        assert_eq!(
+            "a goo".overlap_end_confusable_indices(lookalike),
+            (2..5, 0..5)
+        );
+    }
+
+    #[cfg(feature = "unicode-security")]
+    #[test]
+    fn confusable_mixed_scripts() {
+        // Latin "p", Cyrillic "о", Greek "ο", and Latin "l" against an all-Cyrillic "роо".
+        let left = "ap\u{43e}\u{3bf}l";
+        let right = "\u{440}\u{43e}\u{43e}lx";
+
+        assert_eq!(left.overlap_end(right), "");
+        assert_eq!(left.overlap_end_confusable_indices(right), (1..7, 0..7));
+    }
+
+    #[cfg(feature = "unicode-security")]
+    #[test]
+    fn confusable_not_within_sequence() {
+        // "m" is confusable with "rn", but is never split.
+        assert_eq!("arn".overlap_end_confusable_indices("me"), (1..3, 0..1));
+        assert_eq!("am".overlap_end_confusable_indices("ne"), (2..2, 0..0));
+        assert_eq!("ar".overlap_end_confusable_indices("me"), (2..2, 0..0));
+    }
+
+    #[cfg(feature = "unicode-security")]
+    #[test]
+    fn confusable_plain_methods_unaffected() {
+        let left = "x \u{440}\u{430}";
+        let right = "pay";
+
+        assert_eq!(left.overlap_end(right), "");
+        assert_eq!(left.overlap_end_nfkc_indices(right), (6..6, 0..0));
+        assert_eq!(left.overlap_end_confusable_indices(right), (2..6, 0..2));
+    }
+}
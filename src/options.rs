@@ -0,0 +1,254 @@
+//! Configurable overlaps, combining multiple matching options at once.
+
+use {eq_ignore_ascii_case, is_char_boundary, slice_overlap_index, slice_overlap_index_by};
+
+/// Options for finding overlaps between string slices.
+///
+/// Rather than providing a separate method for every combination of matching behavior, the
+/// options are set on this builder, which then finds overlaps using all of them at once. The
+/// default options find the same overlaps as [`Overlap`].
+///
+/// All lengths are measured in bytes. The minimum length is checked before the overlap is
+/// truncated to the maximum length.
+///
+/// # Example
+/// ```
+/// use str_overlap::OverlapOptions;
+///
+/// let options = OverlapOptions::new().ascii_case_insensitive(true).min_len(3);
+///
+/// assert_eq!(options.overlap_end("abCD", "cdEF"), "");
+/// assert_eq!(options.overlap_end("aBCD", "bcdE"), "BCD");
+/// ```
+///
+/// [`Overlap`]: ::Overlap
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OverlapOptions {
+    min_len: usize,
+    max_len: Option<usize>,
+    ascii_case_insensitive: bool,
+}
+
+impl OverlapOptions {
+    /// Creates the default options, which find the same overlaps as [`Overlap`].
+    ///
+    /// [`Overlap`]: ::Overlap
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum length of an overlap.
+    ///
+    /// If the overlap is shorter than `len`, an empty string slice is found instead, the same as
+    /// [`Overlap::overlap_end_min`].
+    ///
+    /// [`Overlap::overlap_end_min`]: ::Overlap::overlap_end_min
+    #[inline]
+    #[must_use]
+    pub fn min_len(mut self, len: usize) -> Self {
+        self.min_len = len;
+        self
+    }
+
+    /// Sets the maximum length of an overlap.
+    ///
+    /// If the overlap is longer than `len`, it is truncated on a character bound, the same as
+    /// [`Overlap::overlap_end_max`]. The truncated overlap is still part of the string slice it
+    /// was found in, but is no longer necessarily part of the other.
+    ///
+    /// [`Overlap::overlap_end_max`]: ::Overlap::overlap_end_max
+    #[inline]
+    #[must_use]
+    pub fn max_len(mut self, len: usize) -> Self {
+        self.max_len = Some(len);
+        self
+    }
+
+    /// Sets whether ASCII letters are compared without regard to case.
+    ///
+    /// Non-ASCII characters are always compared exactly.
+    #[inline]
+    #[must_use]
+    pub fn ascii_case_insensitive(mut self, insensitive: bool) -> Self {
+        self.ascii_case_insensitive = insensitive;
+        self
+    }
+
+    /// Finds the index at which `left` and `right` overlap using these options, before truncating
+    /// to the maximum length.
+    ///
+    /// The `left` and `right` parameters are defined the same as in
+    /// [`slice_overlap_index`](::slice_overlap_index).
+    fn overlap_index(&self, left: &str, right: &str) -> usize {
+        let left = left.as_bytes();
+        let right = right.as_bytes();
+        // ASCII case is only ignored for ASCII bytes, so overlaps found without regard to case are
+        // on character bounds for the same reasons described on `overlap_index()`.
+        let index = if self.ascii_case_insensitive {
+            slice_overlap_index_by(left, right, |&a, &b| eq_ignore_ascii_case(a, b))
+        } else {
+            slice_overlap_index(left, right)
+        };
+        if left.len() - index < self.min_len {
+            left.len()
+        } else {
+            index
+        }
+    }
+
+    /// Returns the overlap found at the start of `s` and the end of `other` using these options.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapOptions;
+    ///
+    /// let options = OverlapOptions::new().max_len(2);
+    ///
+    /// assert_eq!(options.overlap_start("abcd", "xabc"), "ab");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlap_start<'a>(&self, s: &'a str, other: &str) -> &'a str {
+        let overlap = &s[..(other.len() - self.overlap_index(other, s))];
+        match self.max_len {
+            Some(max_len) if overlap.len() > max_len => {
+                let mut end = max_len;
+                while !is_char_boundary(overlap, end) {
+                    end -= 1;
+                }
+                &overlap[..end]
+            }
+            _ => overlap,
+        }
+    }
+
+    /// Returns the overlap found at the end of `s` and the start of `other` using these options.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapOptions;
+    ///
+    /// let options = OverlapOptions::new().ascii_case_insensitive(true);
+    ///
+    /// assert_eq!(options.overlap_end("abc", "BCD"), "bc");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlap_end<'a>(&self, s: &'a str, other: &str) -> &'a str {
+        let overlap = &s[self.overlap_index(s, other)..];
+        match self.max_len {
+            Some(max_len) if overlap.len() > max_len => {
+                let mut start = overlap.len() - max_len;
+                while !is_char_boundary(overlap, start) {
+                    start += 1;
+                }
+                &overlap[start..]
+            }
+            _ => overlap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlapOptions;
+    use Overlap;
+
+    #[test]
+    fn default_matches_overlap() {
+        let options = OverlapOptions::new();
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("", "a"),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(options.overlap_end(left, right), left.overlap_end(right));
+            assert_eq!(
+                options.overlap_start(left, right),
+                left.overlap_start(right)
+            );
+        }
+    }
+
+    #[test]
+    fn min_len() {
+        let options = OverlapOptions::new().min_len(2);
+
+        assert_eq!(options.overlap_end("abc", "bcd"), "bc");
+        assert_eq!(options.overlap_end("abc", "cde"), "");
+        assert_eq!(options.overlap_start("cde", "abc"), "");
+    }
+
+    #[test]
+    fn max_len() {
+        let options = OverlapOptions::new().max_len(2);
+
+        assert_eq!(options.overlap_end("abcd", "bcde"), "cd");
+        assert_eq!(options.overlap_start("bcde", "abcd"), "bc");
+        assert_eq!(options.overlap_end("abc", "cde"), "c");
+    }
+
+    #[test]
+    fn max_len_matches_overlap_max() {
+        let pairs = [("b日本語a", "語a日bc本"), ("abcd", "bcde"), ("abc", "def")];
+        for &(left, right) in &pairs {
+            for max_len in 0..8 {
+                let options = OverlapOptions::new().max_len(max_len);
+
+                assert_eq!(
+                    options.overlap_end(left, right),
+                    left.overlap_end_max(right, max_len)
+                );
+                assert_eq!(
+                    options.overlap_start(right, left),
+                    right.overlap_start_max(left, max_len)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ascii_case_insensitive() {
+        let options = OverlapOptions::new().ascii_case_insensitive(true);
+
+        assert_eq!(options.overlap_end("abC", "BcD"), "bC");
+        assert_eq!(options.overlap_start("BcD", "abC"), "Bc");
+        assert_eq!(options.overlap_end("caf\u{c9}", "\u{e9}"), "");
+    }
+
+    #[test]
+    fn ascii_case_insensitive_with_min_len() {
+        let options = OverlapOptions::new()
+            .ascii_case_insensitive(true)
+            .min_len(3);
+
+        assert_eq!(options.overlap_end("xABC", "abcd"), "ABC");
+        assert_eq!(options.overlap_end("xxBC", "bcd"), "");
+        assert_eq!(options.overlap_start("abcd", "xABC"), "abc");
+    }
+
+    #[test]
+    fn min_len_with_max_len() {
+        // The minimum length is checked before truncating.
+        let options = OverlapOptions::new().min_len(3).max_len(2);
+
+        assert_eq!(options.overlap_end("abcd", "bcde"), "cd");
+        assert_eq!(options.overlap_end("abc", "bcd"), "");
+    }
+
+    #[test]
+    fn all_options() {
+        let options = OverlapOptions::new()
+            .ascii_case_insensitive(true)
+            .min_len(3)
+            .max_len(2);
+
+        assert_eq!(options.overlap_end("xABC", "abcd"), "BC");
+        assert_eq!(options.overlap_start("abcd", "xABC"), "ab");
+        assert_eq!(options.overlap_end("xxBC", "bcd"), "");
+    }
+}
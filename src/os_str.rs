@@ -0,0 +1,740 @@
+//! Overlap support for platform-native strings.
+//!
+//! On Unix platforms, an [`OsStr`] is an arbitrary sequence of bytes, so overlaps are found by
+//! comparing those bytes directly. On other platforms, the overlap is found by comparing the
+//! [encoded bytes] of each [`OsStr`], only ever splitting them at points where the resulting slices
+//! are still valid [`OsStr`]s.
+//!
+//! [encoded bytes]: OsStr::as_encoded_bytes
+
+use std::ffi::OsStr;
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+use {Direction, Overlap};
+
+/// Returns the bytes backing `os_str`.
+#[cfg(unix)]
+#[inline]
+fn as_bytes(os_str: &OsStr) -> &[u8] {
+    os_str.as_bytes()
+}
+
+/// Returns the bytes backing `os_str`.
+#[cfg(not(unix))]
+#[inline]
+fn as_bytes(os_str: &OsStr) -> &[u8] {
+    os_str.as_encoded_bytes()
+}
+
+/// Converts a subslice of the bytes backing an [`OsStr`] back into an [`OsStr`].
+///
+/// The subslice must have been obtained using [`overlap_index`], or be the full or empty slice.
+#[cfg(unix)]
+#[inline]
+fn from_bytes(bytes: &[u8]) -> &OsStr {
+    OsStr::from_bytes(bytes)
+}
+
+/// Converts a subslice of the bytes backing an [`OsStr`] back into an [`OsStr`].
+///
+/// The subslice must have been obtained using [`overlap_index`], or be the full or empty slice.
+#[cfg(not(unix))]
+#[inline]
+fn from_bytes(bytes: &[u8]) -> &OsStr {
+    unsafe {
+        // SAFETY: `overlap_index()` only ever returns indices at which encoded bytes may be split,
+        // so the subslice was split from a valid `OsStr` at valid points.
+        OsStr::from_encoded_bytes_unchecked(bytes)
+    }
+}
+
+/// Finds the index at which the bytes backing two `OsStr`s overlap.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+#[cfg(unix)]
+#[inline]
+fn overlap_index(left: &[u8], right: &[u8]) -> usize {
+    left.len() - left.overlap_end_len(right)
+}
+
+/// Finds the index at which the encoded bytes backing two `OsStr`s overlap.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+///
+/// Encoded bytes may only be split immediately before or after a valid, non-empty UTF-8 sequence.
+/// The encoding never splits a surrogate pair into separate sequences, but it may contain
+/// unpaired surrogates, and splitting between two of those is not allowed. Overlaps that would
+/// require such a split are therefore skipped in favor of shorter ones.
+#[cfg(not(unix))]
+fn overlap_index(left: &[u8], right: &[u8]) -> usize {
+    ::slice_overlap_index_at_boundary(left, right, |index| {
+        is_split_point(left, index) && is_split_point(right, left.len() - index)
+    })
+}
+
+/// Returns whether `bytes` can be split at `index` while keeping both halves valid.
+///
+/// On Unix platforms, any sequence of bytes is valid, so this is always true.
+#[cfg(unix)]
+#[inline]
+fn is_split_point(_bytes: &[u8], _index: usize) -> bool {
+    true
+}
+
+/// Returns the length of the UTF-8 sequence started by `byte`, or `0` if `byte` can't start one.
+#[cfg(not(unix))]
+#[inline]
+fn sequence_len(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
+}
+
+/// Returns whether `bytes` can be split at `index` while keeping both halves valid encoded bytes.
+#[cfg(not(unix))]
+fn is_split_point(bytes: &[u8], index: usize) -> bool {
+    use core::str;
+
+    if index == 0 || index == bytes.len() {
+        return true;
+    }
+
+    // Check whether a valid UTF-8 sequence begins at `index`.
+    let len = sequence_len(bytes[index]);
+    if len != 0
+        && index + len <= bytes.len()
+        && str::from_utf8(&bytes[index..(index + len)]).is_ok()
+    {
+        return true;
+    }
+
+    // Check whether a valid UTF-8 sequence ends at `index`.
+    let mut start = index - 1;
+    while start > 0 && index - start < 4 && bytes[start] & 0xC0 == 0x80 {
+        start -= 1;
+    }
+    str::from_utf8(&bytes[start..index]).is_ok()
+}
+
+/// Overlap methods for platform-native string slices.
+///
+/// Overlaps are found without converting to UTF-8, so no information is lost. The platform
+/// determines how `OsStr`s are compared:
+/// - On Unix, the bytes of each `OsStr` are compared directly, and overlaps may end at any byte.
+/// - On other platforms, the unspecified encoded bytes of each `OsStr` are compared, and overlaps
+///   only end where those bytes may be split, which is never inside an encoded character. This
+///   requires Rust 1.74 or later, and is not available when the `safe` feature is enabled, since
+///   splitting the encoded bytes requires `unsafe`.
+///
+/// This implementation is only available when the `std` feature is enabled, on the platforms
+/// described above. [`OsString`] can use these methods through its implementation of
+/// [`Deref<Target = OsStr>`].
+///
+/// [`Deref<Target = OsStr>`]: core::ops::Deref
+/// [`OsString`]: std::ffi::OsString
+impl Overlap for OsStr {
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`.
+    ///
+    /// The returned `OsStr` is a reference to the substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").overlap_start(OsStr::new("abc")), "bc");
+    /// ```
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        from_bytes(&as_bytes(self)[..self.overlap_start_len(other)])
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`.
+    ///
+    /// The returned `OsStr` is a reference to the substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").overlap_end(OsStr::new("bcd")), "bc");
+    /// ```
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        from_bytes(&as_bytes(self)[overlap_index(as_bytes(self), as_bytes(other))..])
+    }
+
+    /// Returns the length of the substring which is both the prefix to `self` and the suffix to
+    /// `other`.
+    ///
+    /// The length is measured in the bytes backing the `OsStr`s.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").overlap_start_len(OsStr::new("abc")), 2);
+    /// ```
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        other.len() - overlap_index(as_bytes(other), as_bytes(self))
+    }
+
+    /// Returns the length of the substring which is both the suffix to `self` and the prefix to
+    /// `other`.
+    ///
+    /// The length is measured in the bytes backing the `OsStr`s.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").overlap_end_len(OsStr::new("bcd")), 2);
+    /// ```
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.len() - overlap_index(as_bytes(self), as_bytes(other))
+    }
+
+    /// Returns both the substring which is the prefix to `self` and the suffix to `other`, and the
+    /// substring which is the suffix to `self` and the prefix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// let (start, end) = OsStr::new("bcdab").overlap(OsStr::new("abc"));
+    ///
+    /// assert_eq!(start, "bc");
+    /// assert_eq!(end, "ab");
+    /// ```
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    /// Returns whether any overlap exists at the start of `self` and the end of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(OsStr::new("bcd").has_overlap_start(OsStr::new("abc")));
+    /// ```
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        overlap_index(as_bytes(other), as_bytes(self)) != other.len()
+    }
+
+    /// Returns whether any overlap exists at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(OsStr::new("abc").has_overlap_end(OsStr::new("bcd")));
+    /// ```
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        overlap_index(as_bytes(self), as_bytes(other)) != self.len()
+    }
+
+    /// Returns whether the overlap at the start of `self` and the end of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(OsStr::new("abc").overlap_start_is_empty(OsStr::new("def")));
+    /// ```
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        overlap_index(as_bytes(other), as_bytes(self)) == other.len()
+    }
+
+    /// Returns whether the overlap at the end of `self` and the start of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(OsStr::new("abc").overlap_end_is_empty(OsStr::new("def")));
+    /// ```
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        overlap_index(as_bytes(self), as_bytes(other)) == self.len()
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`, or
+    /// `None` if no such non-empty substring exists.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").try_overlap_start(OsStr::new("abc")).unwrap(), "bc");
+    /// ```
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        let index = overlap_index(as_bytes(other), as_bytes(self));
+        if index == other.len() {
+            None
+        } else {
+            Some(from_bytes(&as_bytes(self)[..(other.len() - index)]))
+        }
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`, or
+    /// `None` if no such non-empty substring exists.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").try_overlap_end(OsStr::new("bcd")).unwrap(), "bc");
+    /// ```
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        let index = overlap_index(as_bytes(self), as_bytes(other));
+        if index == self.len() {
+            None
+        } else {
+            Some(from_bytes(&as_bytes(self)[index..]))
+        }
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").overlap_start_min(OsStr::new("abc"), 2), "bc");
+    /// ```
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() < min_len {
+            from_bytes(&as_bytes(overlap)[..0])
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").overlap_end_min(OsStr::new("bcd"), 2), "bc");
+    /// ```
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() < min_len {
+            from_bytes(&as_bytes(overlap)[overlap.len()..])
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`,
+    /// truncated to at most `max_len` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").overlap_start_max(OsStr::new("abc"), 1), "b");
+    /// ```
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = as_bytes(self.overlap_start(other));
+        if overlap.len() <= max_len {
+            return from_bytes(overlap);
+        }
+        let mut end = max_len;
+        while !is_split_point(overlap, end) {
+            end -= 1;
+        }
+        from_bytes(&overlap[..end])
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`,
+    /// truncated to at most `max_len` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").overlap_end_max(OsStr::new("bcd"), 1), "c");
+    /// ```
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = as_bytes(self.overlap_end(other));
+        if overlap.len() <= max_len {
+            return from_bytes(overlap);
+        }
+        let mut start = overlap.len() - max_len;
+        while !is_split_point(overlap, start) {
+            start += 1;
+        }
+        from_bytes(&overlap[start..])
+    }
+
+    /// Returns the longer of the overlaps found in either order of `self` and `other`, along with
+    /// the order in which they should be joined.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::{Direction, Overlap};
+    ///
+    /// assert_eq!(
+    ///     OsStr::new("cde").max_overlap(OsStr::new("abcd")),
+    ///     (OsStr::new("cd"), Direction::OtherThenSelf)
+    /// );
+    /// ```
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if as_bytes(start).len() > as_bytes(end).len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    /// Returns the substring of `self` which follows its prefix that is also the suffix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("bcd").trim_overlap_start(OsStr::new("abc")), "d");
+    /// ```
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        from_bytes(&as_bytes(self)[self.overlap_start_len(other)..])
+    }
+
+    /// Returns the substring of `self` which precedes its suffix that is also the prefix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(OsStr::new("abc").trim_overlap_end(OsStr::new("bcd")), "a");
+    /// ```
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        from_bytes(&as_bytes(self)[..overlap_index(as_bytes(self), as_bytes(other))])
+    }
+
+    /// Splits `self` into its prefix that is also the suffix to `other`, and the substring that
+    /// follows it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(
+    ///     OsStr::new("bcd").split_at_overlap_start(OsStr::new("abc")),
+    ///     (OsStr::new("bc"), OsStr::new("d"))
+    /// );
+    /// ```
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let (overlap, rest) = as_bytes(self).split_at(self.overlap_start_len(other));
+        (from_bytes(overlap), from_bytes(rest))
+    }
+
+    /// Splits `self` into the substring that precedes its suffix that is also the prefix to
+    /// `other`, and that suffix.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(
+    ///     OsStr::new("abc").split_at_overlap_end(OsStr::new("bcd")),
+    ///     (OsStr::new("a"), OsStr::new("bc"))
+    /// );
+    /// ```
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let (rest, overlap) =
+            as_bytes(self).split_at(overlap_index(as_bytes(self), as_bytes(other)));
+        (from_bytes(rest), from_bytes(overlap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{OsStr, OsString};
+    use {Direction, Overlap};
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(OsStr::new("bcd").overlap_start(OsStr::new("abc")), "bc");
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(OsStr::new("abc").overlap_end(OsStr::new("bcd")), "bc");
+    }
+
+    #[test]
+    fn no_overlap_start() {
+        assert_eq!(OsStr::new("abc").overlap_start(OsStr::new("def")), "");
+    }
+
+    #[test]
+    fn no_overlap_end() {
+        assert_eq!(OsStr::new("abc").overlap_end(OsStr::new("def")), "");
+    }
+
+    #[test]
+    fn all_empty_start() {
+        assert_eq!(OsStr::new("").overlap_start(OsStr::new("")), "");
+    }
+
+    #[test]
+    fn all_empty_end() {
+        assert_eq!(OsStr::new("").overlap_end(OsStr::new("")), "");
+    }
+
+    #[test]
+    fn os_string_start() {
+        let os_string = OsString::from("bcd");
+        assert_eq!(os_string.overlap_start(&OsString::from("abc")), "bc");
+    }
+
+    #[test]
+    fn os_string_end() {
+        let os_string = OsString::from("abc");
+        assert_eq!(os_string.overlap_end(&OsString::from("bcd")), "bc");
+    }
+
+    #[test]
+    fn multi_byte_start() {
+        assert_eq!(
+            OsStr::new("語a日bc本").overlap_start(OsStr::new("b日本語a")),
+            "語a"
+        );
+    }
+
+    #[test]
+    fn multi_byte_end() {
+        assert_eq!(
+            OsStr::new("b日本語a").overlap_end(OsStr::new("語a日bc本")),
+            "語a"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_start() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let os_string = OsString::from_vec(b"\xFFb\xFEcd".to_vec());
+        let other = OsString::from_vec(b"a\xFFb\xFE".to_vec());
+
+        assert_eq!(
+            os_string.overlap_start(&other),
+            &*OsString::from_vec(b"\xFFb\xFE".to_vec())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_end() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let os_string = OsString::from_vec(b"a\xFFb\xFE".to_vec());
+        let other = OsString::from_vec(b"\xFFb\xFEcd".to_vec());
+
+        assert_eq!(
+            os_string.overlap_end(&other),
+            &*OsString::from_vec(b"\xFFb\xFE".to_vec())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_len() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let os_string = OsString::from_vec(b"a\xFFb\xFE".to_vec());
+        let other = OsString::from_vec(b"\xFFb\xFEcd".to_vec());
+
+        assert_eq!(os_string.overlap_end_len(&other), 3);
+        assert_eq!(other.overlap_start_len(&os_string), 3);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn unpaired_surrogates_end() {
+        use std::os::windows::ffi::OsStringExt;
+
+        // Two unpaired high surrogates can't be split apart, so no overlap is found.
+        let os_string = OsString::from_wide(&[0x61, 0xD800, 0xD800, 0x61]);
+        let other = OsString::from_wide(&[0xD800, 0x61, 0x62]);
+
+        assert_eq!(os_string.overlap_end(&other), "");
+        assert_eq!(
+            OsString::from_wide(&[0xD800, 0x61]).overlap_end(&other),
+            &*OsString::from_wide(&[0xD800, 0x61])
+        );
+    }
+
+    #[test]
+    fn overlap_both_sides() {
+        let (start, end) = OsStr::new("bcdab").overlap(OsStr::new("abc"));
+
+        assert_eq!(start, "bc");
+        assert_eq!(end, "ab");
+    }
+
+    #[test]
+    fn has_overlap_start() {
+        assert!(OsStr::new("bcd").has_overlap_start(OsStr::new("abc")));
+        assert!(!OsStr::new("abc").has_overlap_start(OsStr::new("bcd")));
+    }
+
+    #[test]
+    fn has_overlap_end() {
+        assert!(OsStr::new("abc").has_overlap_end(OsStr::new("bcd")));
+        assert!(!OsStr::new("bcd").has_overlap_end(OsStr::new("abc")));
+    }
+
+    #[test]
+    fn overlap_is_empty() {
+        assert!(OsStr::new("abc").overlap_end_is_empty(OsStr::new("def")));
+        assert!(!OsStr::new("abc").overlap_end_is_empty(OsStr::new("bcd")));
+        assert!(OsStr::new("abc").overlap_start_is_empty(OsStr::new("bcd")));
+        assert!(!OsStr::new("bcd").overlap_start_is_empty(OsStr::new("abc")));
+    }
+
+    #[test]
+    fn try_overlap_start() {
+        assert_eq!(
+            OsStr::new("bcd").try_overlap_start(OsStr::new("abc")),
+            Some(OsStr::new("bc"))
+        );
+        assert_eq!(OsStr::new("abc").try_overlap_start(OsStr::new("bcd")), None);
+    }
+
+    #[test]
+    fn try_overlap_end() {
+        assert_eq!(
+            OsStr::new("abc").try_overlap_end(OsStr::new("bcd")),
+            Some(OsStr::new("bc"))
+        );
+        assert_eq!(OsStr::new("bcd").try_overlap_end(OsStr::new("abc")), None);
+    }
+
+    #[test]
+    fn min_overlap_start() {
+        assert_eq!(
+            OsStr::new("bcd").overlap_start_min(OsStr::new("abc"), 2),
+            "bc"
+        );
+        assert_eq!(
+            OsStr::new("bcd").overlap_start_min(OsStr::new("abc"), 3),
+            ""
+        );
+    }
+
+    #[test]
+    fn min_overlap_end() {
+        assert_eq!(
+            OsStr::new("abc").overlap_end_min(OsStr::new("bcd"), 2),
+            "bc"
+        );
+        assert_eq!(OsStr::new("abc").overlap_end_min(OsStr::new("bcd"), 3), "");
+    }
+
+    #[test]
+    fn max_overlap_start() {
+        assert_eq!(
+            OsStr::new("bcd").overlap_start_max(OsStr::new("abc"), 1),
+            "b"
+        );
+        assert_eq!(
+            OsStr::new("bcd").overlap_start_max(OsStr::new("abc"), 2),
+            "bc"
+        );
+    }
+
+    #[test]
+    fn max_overlap_end() {
+        assert_eq!(OsStr::new("abc").overlap_end_max(OsStr::new("bcd"), 1), "c");
+        assert_eq!(
+            OsStr::new("abc").overlap_end_max(OsStr::new("bcd"), 2),
+            "bc"
+        );
+    }
+
+    #[test]
+    fn max_overlap() {
+        assert_eq!(
+            OsStr::new("cde").max_overlap(OsStr::new("abcd")),
+            (OsStr::new("cd"), Direction::OtherThenSelf)
+        );
+        assert_eq!(
+            OsStr::new("abc").max_overlap(OsStr::new("bcd")),
+            (OsStr::new("bc"), Direction::SelfThenOther)
+        );
+    }
+
+    #[test]
+    fn trim_overlap() {
+        assert_eq!(OsStr::new("abc").trim_overlap_end(OsStr::new("bcd")), "a");
+        assert_eq!(OsStr::new("bcd").trim_overlap_start(OsStr::new("abc")), "d");
+        assert_eq!(OsStr::new("abc").trim_overlap_end(OsStr::new("abc")), "");
+        assert_eq!(OsStr::new("abc").trim_overlap_end(OsStr::new("def")), "abc");
+    }
+
+    #[test]
+    fn split_at_overlap() {
+        assert_eq!(
+            OsStr::new("abc").split_at_overlap_end(OsStr::new("bcd")),
+            (OsStr::new("a"), OsStr::new("bc"))
+        );
+        assert_eq!(
+            OsStr::new("bcd").split_at_overlap_start(OsStr::new("abc")),
+            (OsStr::new("bc"), OsStr::new("d"))
+        );
+    }
+}
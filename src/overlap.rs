@@ -0,0 +1,1193 @@
+//! The borrowing overlap methods, which are available with neither `std` nor `alloc`.
+//!
+//! Everything in this module finds overlaps without allocating, whichever features are enabled,
+//! and must remain usable when the crate is built with `--no-default-features`. Methods which
+//! require an allocator are provided elsewhere, behind the `alloc` feature.
+
+#[cfg(not(feature = "safe"))]
+use core::ops::Range;
+use {is_char_boundary, overlap_index, slice_overlap_index};
+
+/// Returns the substring of `s` within `range`, without bounds checks.
+///
+/// `str::get_unchecked()` is used where it is available. Older compilers only provide the
+/// deprecated `str::slice_unchecked()`, which takes the bounds of the range as separate arguments.
+///
+/// # Safety
+/// `range` must be within the bounds of `s`, and both of its ends must be on character bounds of
+/// `s`.
+#[cfg(all(rustc_1_20, not(feature = "safe")))]
+#[inline]
+#[must_use]
+unsafe fn str_get_unchecked(s: &str, range: Range<usize>) -> &str {
+    s.get_unchecked(range)
+}
+
+/// Returns the substring of `s` within `range`, without bounds checks.
+///
+/// `str::get_unchecked()` is used where it is available. Older compilers only provide the
+/// deprecated `str::slice_unchecked()`, which takes the bounds of the range as separate arguments.
+///
+/// # Safety
+/// `range` must be within the bounds of `s`, and both of its ends must be on character bounds of
+/// `s`.
+#[cfg(all(not(rustc_1_20), not(feature = "safe")))]
+#[inline]
+#[must_use]
+unsafe fn str_get_unchecked(s: &str, range: Range<usize>) -> &str {
+    s.slice_unchecked(range.start, range.end)
+}
+
+/// Returns the substring of `$s` within `$range`.
+///
+/// `$range` must be within the bounds of `$s`, and both of its ends must be on character bounds of
+/// `$s`. Bounds are only checked when the `safe` feature is enabled, and callers must otherwise
+/// uphold the requirements of [`str_get_unchecked`].
+#[cfg(feature = "safe")]
+macro_rules! slice_str {
+    ($s:expr, $range:expr) => {
+        &$s[$range]
+    };
+}
+
+/// Returns the substring of `$s` within `$range`.
+///
+/// `$range` must be within the bounds of `$s`, and both of its ends must be on character bounds of
+/// `$s`. Bounds are only checked when the `safe` feature is enabled, and callers must otherwise
+/// uphold the requirements of [`str_get_unchecked`].
+#[cfg(not(feature = "safe"))]
+macro_rules! slice_str {
+    ($s:expr, $range:expr) => {
+        unsafe { str_get_unchecked($s, $range) }
+    };
+}
+
+/// The order in which two values should be joined to make use of their overlap.
+///
+/// This is returned by [`Overlap::max_overlap`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// `self` comes first, and its end overlaps with the start of `other`.
+    SelfThenOther,
+    /// `other` comes first, and its end overlaps with the start of `self`.
+    OtherThenSelf,
+}
+
+/// Provides methods for finding overlaps between values.
+///
+/// This trait provides methods for finding overlaps at both the start and end of `self`. This
+/// allows for returning overlapping values that are owned by `self`, regardless of which side of
+/// `self` the overlap is occurring.
+///
+/// This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::Overlap;
+/// ```
+///
+/// `Overlap` is implemented on [`str`], which means its methods are usable by `str` and any types
+/// which implement [`Deref<Target = str>`], such as [`String`]. It is also implemented on slices
+/// of any type implementing [`PartialEq`], which allows finding overlaps of raw bytes that are not
+/// valid UTF-8, token streams, `char` slices, or any other sequence of comparable values. When the
+/// `std` feature is enabled, it is additionally implemented on [`OsStr`] on Unix, and on other
+/// platforms when using Rust 1.74 or later without the `safe` feature.
+///
+/// Every method is marked `#[must_use]`, since none of them have any effect other than returning
+/// a value. Ignoring the result is therefore likely a mistake, and is warned about:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use str_overlap::Overlap;
+///
+/// "abc".overlap_end("bcd");
+/// ```
+///
+/// [`Deref<Target = str>`]: core::ops::Deref
+/// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+pub trait Overlap {
+    /// Returns the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// For right-to-left text, this is also the overlap at the end of `self` and the start of
+    /// `other` in reading order, since reversing both values swaps their starts and ends.
+    ///
+    /// # Example
+    /// This method can be used through its implementation on [`str`], like so:
+    ///
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start("abc"), "bc");
+    /// ```
+    #[must_use]
+    fn overlap_start(&self, other: &Self) -> &Self;
+    /// Returns the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// This method can be used through its implementation on `str`, like so:
+    ///
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end("bcd"), "bc");
+    /// ```
+    #[must_use]
+    fn overlap_end(&self, other: &Self) -> &Self;
+
+    /// Returns the length of the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// The returned value is a count of bytes, not of characters. It is equal to the length of the
+    /// value returned by [`overlap_start`], but is computed without constructing the overlapping
+    /// slice.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_len("abc"), 2);
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn overlap_start_len(&self, other: &Self) -> usize;
+
+    /// Returns the length of the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// The returned value is a count of bytes, not of characters. It is equal to the length of the
+    /// value returned by [`overlap_end`], but is computed without constructing the overlapping
+    /// slice.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end_len("bcd"), 2);
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn overlap_end_len(&self, other: &Self) -> usize;
+
+    /// Returns both the overlap found at the start of `self` and the overlap found at the end of
+    /// `self`.
+    ///
+    /// The first value of the returned tuple is the same as the value returned by
+    /// [`overlap_start`], and the second is the same as the value returned by [`overlap_end`]. The
+    /// two overlaps are independent of one another, so this is equivalent to calling both methods.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcdab".overlap("abc"), ("bc", "ab"));
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    /// Returns whether any overlap exists at the start of `self` and the end of `other`.
+    ///
+    /// This is equivalent to checking whether [`overlap_start`] is non-empty, but does not
+    /// construct the overlapping slice.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("bcd".has_overlap_start("abc"));
+    /// assert!(!"abc".has_overlap_start("def"));
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        self.overlap_start_len(other) != 0
+    }
+
+    /// Returns whether any overlap exists at the end of `self` and the start of `other`.
+    ///
+    /// This is equivalent to checking whether [`overlap_end`] is non-empty, but does not construct
+    /// the overlapping slice.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".has_overlap_end("bcd"));
+    /// assert!(!"abc".has_overlap_end("def"));
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        self.overlap_end_len(other) != 0
+    }
+
+    /// Returns whether the overlap at the start of `self` and the end of `other` is empty.
+    ///
+    /// This is the inverse of [`has_overlap_start`], and likewise does not construct the
+    /// overlapping slice. It reads naturally in guard clauses that return early when there is
+    /// nothing to merge.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".overlap_start_is_empty("def"));
+    /// assert!(!"bcd".overlap_start_is_empty("abc"));
+    /// ```
+    ///
+    /// [`has_overlap_start`]: Overlap::has_overlap_start
+    #[must_use]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        !self.has_overlap_start(other)
+    }
+
+    /// Returns whether the overlap at the end of `self` and the start of `other` is empty.
+    ///
+    /// This is the inverse of [`has_overlap_end`], and likewise does not construct the
+    /// overlapping slice. It reads naturally in guard clauses that return early when there is
+    /// nothing to merge.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".overlap_end_is_empty("def"));
+    /// assert!(!"abc".overlap_end_is_empty("bcd"));
+    /// ```
+    ///
+    /// [`has_overlap_end`]: Overlap::has_overlap_end
+    #[must_use]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        !self.has_overlap_end(other)
+    }
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, or `None` if there
+    /// is no overlap.
+    ///
+    /// This is the same as [`overlap_start`], except that an empty overlap is represented as
+    /// `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".try_overlap_start("abc"), Some("bc"));
+    /// assert_eq!("abc".try_overlap_start("def"), None);
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        if self.has_overlap_start(other) {
+            Some(self.overlap_start(other))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, or `None` if there
+    /// is no overlap.
+    ///
+    /// This is the same as [`overlap_end`], except that an empty overlap is represented as `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// if let Some(overlap) = "abc".try_overlap_end("bcd") {
+    ///     assert_eq!(overlap, "bc");
+    /// }
+    /// assert_eq!("abc".try_overlap_end("def"), None);
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        if self.has_overlap_end(other) {
+            Some(self.overlap_end(other))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, if its length is at
+    /// least `min_len`.
+    ///
+    /// If the overlap found by [`overlap_start`] is shorter than `min_len`, an empty value is
+    /// returned instead. Lengths are measured the same as in [`overlap_start_len`], which for
+    /// string slices means bytes. If `min_len` exceeds the length of either `self` or `other`, no
+    /// overlap can be long enough, so an empty value is always returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_min("abc", 2), "bc");
+    /// assert_eq!("bcd".overlap_start_min("abc", 3), "");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [`overlap_start_len`]: Overlap::overlap_start_len
+    #[must_use]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, if its length is at
+    /// least `min_len`.
+    ///
+    /// If the overlap found by [`overlap_end`] is shorter than `min_len`, an empty value is
+    /// returned instead. Lengths are measured the same as in [`overlap_end_len`], which for string
+    /// slices means bytes. If `min_len` exceeds the length of either `self` or `other`, no overlap
+    /// can be long enough, so an empty value is always returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end_min("bcd", 2), "bc");
+    /// assert_eq!("abc".overlap_end_min("bcd", 3), "");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`overlap_end_len`]: Overlap::overlap_end_len
+    #[must_use]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`, truncated to at
+    /// most `max_len`.
+    ///
+    /// If the overlap found by [`overlap_start`] is longer than `max_len`, only its leading part is
+    /// returned. Lengths are measured the same as in [`overlap_start_len`], which for string
+    /// slices means bytes. For string slices, the truncation always occurs on a character bound,
+    /// rounding down if `max_len` falls within a multi-byte character.
+    ///
+    /// Note that a truncated overlap is still a prefix of `self`, but is no longer necessarily a
+    /// suffix of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_max("abc", 1), "b");
+    /// assert_eq!("bcd".overlap_start_max("abc", 3), "bc");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [`overlap_start_len`]: Overlap::overlap_start_len
+    #[must_use]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, truncated to at
+    /// most `max_len`.
+    ///
+    /// If the overlap found by [`overlap_end`] is longer than `max_len`, only its trailing part is
+    /// returned. Lengths are measured the same as in [`overlap_end_len`], which for string slices
+    /// means bytes. For string slices, the truncation always occurs on a character bound, rounding
+    /// down if `max_len` falls within a multi-byte character.
+    ///
+    /// Note that a truncated overlap is still a suffix of `self`, but is no longer necessarily a
+    /// prefix of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end_max("bcd", 1), "c");
+    /// assert_eq!("abc".overlap_end_max("bcd", 3), "bc");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`overlap_end_len`]: Overlap::overlap_end_len
+    #[must_use]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self;
+
+    /// Returns the longer of the overlaps found in either order of `self` and `other`, along with
+    /// the order in which they should be joined.
+    ///
+    /// This is useful when it isn't known which of two fragments comes first. The overlap at the
+    /// end of `self` and the start of `other` is compared with the overlap at the end of `other`
+    /// and the start of `self`. If the first is at least as long as the second, it is returned
+    /// with [`Direction::SelfThenOther`]. Otherwise, the second is returned with
+    /// [`Direction::OtherThenSelf`]. Ties are therefore always resolved as
+    /// [`Direction::SelfThenOther`].
+    ///
+    /// In either case, the returned value is a reference to the overlap contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{Direction, Overlap};
+    ///
+    /// assert_eq!("cde".max_overlap("abcd"), ("cd", Direction::OtherThenSelf));
+    /// assert_eq!("abc".max_overlap("bca"), ("bc", Direction::SelfThenOther));
+    /// // Ties are resolved as `SelfThenOther`.
+    /// assert_eq!("aba".max_overlap("aba"), ("aba", Direction::SelfThenOther));
+    /// ```
+    #[must_use]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        if self.overlap_start_len(other) > self.overlap_end_len(other) {
+            (self.overlap_start(other), Direction::OtherThenSelf)
+        } else {
+            (self.overlap_end(other), Direction::SelfThenOther)
+        }
+    }
+
+    /// Returns `self` with the overlap found at its start and the end of `other` removed.
+    ///
+    /// This is the complement of [`overlap_start`], and is the part of `self` that remains after
+    /// `other` is merged before it. If all of `self` overlaps, an empty value is returned, and if
+    /// nothing overlaps, all of `self` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".trim_overlap_start("abc"), "d");
+    /// assert_eq!("bcd".trim_overlap_start("xyz"), "bcd");
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    #[must_use]
+    fn trim_overlap_start(&self, other: &Self) -> &Self;
+
+    /// Returns `self` with the overlap found at its end and the start of `other` removed.
+    ///
+    /// This is the complement of [`overlap_end`], and is the part of `self` that remains after
+    /// `other` is merged after it. If all of `self` overlaps, an empty value is returned, and if
+    /// nothing overlaps, all of `self` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".trim_overlap_end("bcd"), "a");
+    /// assert_eq!("abc".trim_overlap_end("xyz"), "abc");
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    #[must_use]
+    fn trim_overlap_end(&self, other: &Self) -> &Self;
+
+    /// Splits `self` into the overlap found at its start and the end of `other`, and the
+    /// remainder that follows it.
+    ///
+    /// The first value is the same as [`overlap_start`] and the second is the same as
+    /// [`trim_overlap_start`], but the overlap is only found once. Joining both values always
+    /// gives `self`, and for string slices, the split is always on a character bound.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".split_at_overlap_start("abc"), ("bc", "d"));
+    /// ```
+    ///
+    /// [`overlap_start`]: Overlap::overlap_start
+    /// [`trim_overlap_start`]: Overlap::trim_overlap_start
+    #[must_use]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self);
+
+    /// Splits `self` into the part that precedes the overlap found at its end and the start of
+    /// `other`, and the overlap itself.
+    ///
+    /// The first value is the same as [`trim_overlap_end`] and the second is the same as
+    /// [`overlap_end`], but the overlap is only found once. Joining both values always gives
+    /// `self`, and for string slices, the split is always on a character bound.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".split_at_overlap_end("bcd"), ("a", "bc"));
+    /// ```
+    ///
+    /// [`overlap_end`]: Overlap::overlap_end
+    /// [`trim_overlap_end`]: Overlap::trim_overlap_end
+    #[must_use]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self);
+}
+
+/// Overlap methods for string slices.
+///
+/// This allows for the returned string slice to be a subset of either string slice from which an
+/// overlap is obtained.
+impl Overlap for str {
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`.
+    ///
+    /// The returned string slice is a reference to the substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start("abc"), "bc");
+    /// ```
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        // SAFETY: The result of `overlap_index()` subtracted from `other.len()` will always be on
+        // a character bound of `self`, since it is found by comparing directly the bytes of the
+        // start of `self` and the end of `other`. Therefore, the range will be within `self`'s
+        // bounds and also will uphold `str` invariants.
+        slice_str!(self, 0..(other.len() - overlap_index(other, self)))
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`.
+    ///
+    /// The returned string slice is a reference to the substring contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end("bcd"), "bc");
+    /// ```
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        // SAFETY: The result of `overlap_index()` will always be on a character bound of
+        // `self`, as is documented on that function. Therefore, the range will be within `self`'s
+        // bounds and also will uphold `str` invariants.
+        slice_str!(self, overlap_index(self, other)..self.len())
+    }
+
+    /// Returns the length in bytes of the substring which is both the prefix to `self` and the
+    /// suffix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_len("abc"), 2);
+    /// ```
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        other.len() - overlap_index(other, self)
+    }
+
+    /// Returns the length in bytes of the substring which is both the suffix to `self` and the
+    /// prefix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end_len("bcd"), 2);
+    /// ```
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.len() - overlap_index(self, other)
+    }
+
+    /// Returns both the substring which is the prefix to `self` and the suffix to `other`, and the
+    /// substring which is the suffix to `self` and the prefix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcdab".overlap("abc"), ("bc", "ab"));
+    /// ```
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    /// Returns whether any overlap exists at the start of `self` and the end of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("bcd".has_overlap_start("abc"));
+    /// ```
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        overlap_index(other, self) != other.len()
+    }
+
+    /// Returns whether any overlap exists at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".has_overlap_end("bcd"));
+    /// ```
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        overlap_index(self, other) != self.len()
+    }
+
+    /// Returns whether the overlap at the start of `self` and the end of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".overlap_start_is_empty("def"));
+    /// ```
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        overlap_index(other, self) == other.len()
+    }
+
+    /// Returns whether the overlap at the end of `self` and the start of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!("abc".overlap_end_is_empty("def"));
+    /// ```
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        overlap_index(self, other) == self.len()
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`, or
+    /// `None` if no such non-empty substring exists.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".try_overlap_start("abc"), Some("bc"));
+    /// ```
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        let index = overlap_index(other, self);
+        if index == other.len() {
+            None
+        } else {
+            // SAFETY: `other.len() - index` is on a character bound of `self`, for the same reasons
+            // as in `overlap_start()`.
+            Some(slice_str!(self, 0..(other.len() - index)))
+        }
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`, or
+    /// `None` if no such non-empty substring exists.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".try_overlap_end("bcd"), Some("bc"));
+    /// ```
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        let index = overlap_index(self, other);
+        if index == self.len() {
+            None
+        } else {
+            // SAFETY: `index` is on a character bound of `self`, for the same reasons as in
+            // `overlap_end()`.
+            Some(slice_str!(self, index..self.len()))
+        }
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".overlap_start_min("abc", 2), "bc");
+    /// ```
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() < min_len {
+            &overlap[..0]
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".overlap_end_min("bcd", 2), "bc");
+    /// ```
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() < min_len {
+            &overlap[overlap.len()..]
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the substring which is both the prefix to `self` and the suffix to `other`,
+    /// truncated to at most `max_len` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("語a日bc本".overlap_start_max("b日本語a", 2), "");
+    /// assert_eq!("語a日bc本".overlap_start_max("b日本語a", 3), "語");
+    /// ```
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() <= max_len {
+            return overlap;
+        }
+        let mut end = max_len;
+        while !is_char_boundary(overlap, end) {
+            end -= 1;
+        }
+        &overlap[..end]
+    }
+
+    /// Returns the substring which is both the suffix to `self` and the prefix to `other`,
+    /// truncated to at most `max_len` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("b日本語a".overlap_end_max("語a日bc本", 3), "a");
+    /// assert_eq!("b日本語a".overlap_end_max("語a日bc本", 4), "語a");
+    /// ```
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() <= max_len {
+            return overlap;
+        }
+        let mut start = overlap.len() - max_len;
+        while !is_char_boundary(overlap, start) {
+            start += 1;
+        }
+        &overlap[start..]
+    }
+
+    /// Returns the longer of the overlaps found in either order of `self` and `other`, along with
+    /// the order in which they should be joined.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{Direction, Overlap};
+    ///
+    /// assert_eq!("cde".max_overlap("abcd"), ("cd", Direction::OtherThenSelf));
+    /// ```
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    /// Returns the substring of `self` which follows its prefix that is also the suffix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".trim_overlap_start("abc"), "d");
+    /// ```
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        // SAFETY: The overlap ends on a character bound of `self`, for the same reasons as
+        // described in `overlap_start()`.
+        slice_str!(self, (other.len() - overlap_index(other, self))..self.len())
+    }
+
+    /// Returns the substring of `self` which precedes its suffix that is also the prefix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".trim_overlap_end("bcd"), "a");
+    /// ```
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        // SAFETY: The result of `overlap_index()` will always be on a character bound of
+        // `self`, as is documented on that function.
+        slice_str!(self, 0..overlap_index(self, other))
+    }
+
+    /// Splits `self` into its prefix that is also the suffix to `other`, and the substring that
+    /// follows it.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("bcd".split_at_overlap_start("abc"), ("bc", "d"));
+    /// ```
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let index = other.len() - overlap_index(other, self);
+        // SAFETY: `index` is on a character bound of `self`, for the same reasons as described
+        // in `overlap_start()`.
+        (
+            slice_str!(self, 0..index),
+            slice_str!(self, index..self.len()),
+        )
+    }
+
+    /// Splits `self` into the substring that precedes its suffix that is also the prefix to
+    /// `other`, and that suffix.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!("abc".split_at_overlap_end("bcd"), ("a", "bc"));
+    /// ```
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let index = overlap_index(self, other);
+        // SAFETY: The result of `overlap_index()` will always be on a character bound of
+        // `self`, as is documented on that function.
+        (
+            slice_str!(self, 0..index),
+            slice_str!(self, index..self.len()),
+        )
+    }
+}
+
+/// Overlap methods for slices.
+///
+/// The overlap is found by comparing the elements of the slices using their [`PartialEq`]
+/// implementation. For byte slices, this means no UTF-8 validation is performed, so any byte
+/// values, including invalid UTF-8 and NUL bytes, are compared as-is. For `char` slices, the
+/// overlap found is the same as the overlap of the equivalent string slices, differing only in
+/// representation.
+impl<T> Overlap for [T]
+where
+    T: PartialEq,
+{
+    /// Returns the subslice which is both the prefix to `self` and the suffix to `other`.
+    ///
+    /// The returned slice is a reference to the subslice contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".overlap_start(b"abc"), b"bc");
+    /// ```
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        &self[..self.overlap_start_len(other)]
+    }
+
+    /// Returns the subslice which is both the suffix to `self` and the prefix to `other`.
+    ///
+    /// The returned slice is a reference to the subslice contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".overlap_end(b"bcd"), b"bc");
+    /// ```
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        &self[slice_overlap_index(self, other)..]
+    }
+
+    /// Returns the length of the subslice which is both the prefix to `self` and the suffix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".overlap_start_len(b"abc"), 2);
+    /// ```
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        other.len() - slice_overlap_index(other, self)
+    }
+
+    /// Returns the length of the subslice which is both the suffix to `self` and the prefix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".overlap_end_len(b"bcd"), 2);
+    /// ```
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.len() - slice_overlap_index(self, other)
+    }
+
+    /// Returns both the subslice which is the prefix to `self` and the suffix to `other`, and the
+    /// subslice which is the suffix to `self` and the prefix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcdab".overlap(b"abc"), (&b"bc"[..], &b"ab"[..]));
+    /// ```
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    /// Returns whether any overlap exists at the start of `self` and the end of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(b"bcd".has_overlap_start(b"abc"));
+    /// ```
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        slice_overlap_index(other, self) != other.len()
+    }
+
+    /// Returns whether any overlap exists at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(b"abc".has_overlap_end(b"bcd"));
+    /// ```
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        slice_overlap_index(self, other) != self.len()
+    }
+
+    /// Returns whether the overlap at the start of `self` and the end of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(b"abc".overlap_start_is_empty(b"def"));
+    /// ```
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        slice_overlap_index(other, self) == other.len()
+    }
+
+    /// Returns whether the overlap at the end of `self` and the start of `other` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert!(b"abc".overlap_end_is_empty(b"def"));
+    /// ```
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        slice_overlap_index(self, other) == self.len()
+    }
+
+    /// Returns the subslice which is both the prefix to `self` and the suffix to `other`, or
+    /// `None` if no such non-empty subslice exists.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".try_overlap_start(b"abc"), Some(&b"bc"[..]));
+    /// ```
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        let index = slice_overlap_index(other, self);
+        if index == other.len() {
+            None
+        } else {
+            Some(&self[..(other.len() - index)])
+        }
+    }
+
+    /// Returns the subslice which is both the suffix to `self` and the prefix to `other`, or
+    /// `None` if no such non-empty subslice exists.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".try_overlap_end(b"bcd"), Some(&b"bc"[..]));
+    /// ```
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        let index = slice_overlap_index(self, other);
+        if index == self.len() {
+            None
+        } else {
+            Some(&self[index..])
+        }
+    }
+
+    /// Returns the subslice which is both the prefix to `self` and the suffix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".overlap_start_min(b"abc", 2), b"bc");
+    /// ```
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() < min_len {
+            &overlap[..0]
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the subslice which is both the suffix to `self` and the prefix to `other`, if it
+    /// is at least `min_len` long.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".overlap_end_min(b"bcd", 2), b"bc");
+    /// ```
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() < min_len {
+            &overlap[overlap.len()..]
+        } else {
+            overlap
+        }
+    }
+
+    /// Returns the subslice which is both the prefix to `self` and the suffix to `other`,
+    /// truncated to at most `max_len` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".overlap_start_max(b"abc", 1), b"b");
+    /// ```
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_start(other);
+        if overlap.len() <= max_len {
+            overlap
+        } else {
+            &overlap[..max_len]
+        }
+    }
+
+    /// Returns the subslice which is both the suffix to `self` and the prefix to `other`,
+    /// truncated to at most `max_len` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".overlap_end_max(b"bcd", 1), b"c");
+    /// ```
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        let overlap = self.overlap_end(other);
+        if overlap.len() <= max_len {
+            overlap
+        } else {
+            &overlap[(overlap.len() - max_len)..]
+        }
+    }
+
+    /// Returns the longer of the overlaps found in either order of `self` and `other`, along with
+    /// the order in which they should be joined.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::{Direction, Overlap};
+    ///
+    /// assert_eq!(b"cde".max_overlap(b"abcd"), (&b"cd"[..], Direction::OtherThenSelf));
+    /// ```
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    /// Returns the subslice of `self` which follows its prefix that is also the suffix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".trim_overlap_start(b"abc"), b"d");
+    /// ```
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        &self[self.overlap_start_len(other)..]
+    }
+
+    /// Returns the subslice of `self` which precedes its suffix that is also the prefix to
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".trim_overlap_end(b"bcd"), b"a");
+    /// ```
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        &self[..slice_overlap_index(self, other)]
+    }
+
+    /// Splits `self` into its prefix that is also the suffix to `other`, and the subslice that
+    /// follows it.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"bcd".split_at_overlap_start(b"abc"), (&b"bc"[..], &b"d"[..]));
+    /// ```
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        self.split_at(self.overlap_start_len(other))
+    }
+
+    /// Splits `self` into the subslice that precedes its suffix that is also the prefix to
+    /// `other`, and that suffix.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::Overlap;
+    ///
+    /// assert_eq!(b"abc".split_at_overlap_end(b"bcd"), (&b"a"[..], &b"bc"[..]));
+    /// ```
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        self.split_at(slice_overlap_index(self, other))
+    }
+}
@@ -0,0 +1,217 @@
+//! Overlaps that are borrowed mutably from `self`.
+//!
+//! Only `self` is borrowed mutably, while `other` is borrowed immutably, so the returned overlap
+//! can be modified in place without aliasing `other`.
+
+#[cfg(rustc_1_20)]
+use overlap_index;
+use Overlap;
+
+/// Returns the mutable substring of `$s` within `$range`.
+///
+/// `$range` must be within the bounds of `$s`, and both of its ends must be on character bounds of
+/// `$s`. Bounds are only checked when the `safe` feature is enabled.
+#[cfg(all(rustc_1_20, feature = "safe"))]
+macro_rules! slice_str_mut {
+    ($s:expr, $range:expr) => {
+        &mut $s[$range]
+    };
+}
+
+/// Returns the mutable substring of `$s` within `$range`.
+///
+/// `$range` must be within the bounds of `$s`, and both of its ends must be on character bounds of
+/// `$s`. Bounds are only checked when the `safe` feature is enabled, and callers must otherwise
+/// uphold the requirements of `str::get_unchecked_mut()`.
+#[cfg(all(rustc_1_20, not(feature = "safe")))]
+macro_rules! slice_str_mut {
+    ($s:expr, $range:expr) => {
+        unsafe { $s.get_unchecked_mut($range) }
+    };
+}
+
+/// Provides methods for finding overlaps that are borrowed mutably from `self`.
+///
+/// This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::OverlapMut;
+/// ```
+///
+/// `OverlapMut` is implemented on slices of any type implementing [`PartialEq`], and on [`str`]
+/// on Rust 1.20.0 or newer. The overlaps are found the same way as in [`Overlap`].
+pub trait OverlapMut {
+    /// Returns a mutable reference to the overlap found at the start of `self` and the end of
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapMut;
+    ///
+    /// let mut values = [2, 3, 4];
+    /// for value in values.overlap_start_mut(&[1, 2, 3]) {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(values, [20, 30, 4]);
+    /// ```
+    #[must_use]
+    fn overlap_start_mut(&mut self, other: &Self) -> &mut Self;
+
+    /// Returns a mutable reference to the overlap found at the end of `self` and the start of
+    /// `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapMut;
+    ///
+    /// let mut values = [1, 2, 3];
+    /// for value in values.overlap_end_mut(&[2, 3, 4]) {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(values, [1, 20, 30]);
+    /// ```
+    #[must_use]
+    fn overlap_end_mut(&mut self, other: &Self) -> &mut Self;
+}
+
+/// Mutable overlap methods for string slices.
+///
+/// This implementation is only available on Rust 1.20.0 or newer, since string slices can't be
+/// sliced mutably on older compilers.
+#[cfg(rustc_1_20)]
+impl OverlapMut for str {
+    /// Returns a mutable reference to the substring which is both the prefix to `self` and the
+    /// suffix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapMut;
+    ///
+    /// let mut s = String::from("bcd");
+    /// s.overlap_start_mut("abc").make_ascii_uppercase();
+    ///
+    /// assert_eq!(s, "BCd");
+    /// ```
+    #[inline]
+    fn overlap_start_mut(&mut self, other: &Self) -> &mut Self {
+        let len = other.len() - overlap_index(other, self);
+        // SAFETY: The overlap is a suffix of `other` that is no longer than `self`, so `len` is
+        // within the bounds of `self`. Since the overlap is also a prefix of `self`, and
+        // `overlap_index()` only returns character bounds of `other`, `len` is on a character
+        // bound of `self`.
+        slice_str_mut!(self, 0..len)
+    }
+
+    /// Returns a mutable reference to the substring which is both the suffix to `self` and the
+    /// prefix to `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapMut;
+    ///
+    /// let mut s = String::from("abc");
+    /// s.overlap_end_mut("bcd").make_ascii_uppercase();
+    ///
+    /// assert_eq!(s, "aBC");
+    /// ```
+    #[inline]
+    fn overlap_end_mut(&mut self, other: &Self) -> &mut Self {
+        let index = overlap_index(self, other);
+        let len = self.len();
+        // SAFETY: `overlap_index()` only returns character bounds of `self` within its
+        // bounds.
+        slice_str_mut!(self, index..len)
+    }
+}
+
+/// Mutable overlap methods for slices.
+impl<T> OverlapMut for [T]
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn overlap_start_mut(&mut self, other: &Self) -> &mut Self {
+        let len = self.overlap_start_len(other);
+        &mut self[..len]
+    }
+
+    #[inline]
+    fn overlap_end_mut(&mut self, other: &Self) -> &mut Self {
+        let len = self.overlap_end_len(other);
+        let index = self.len() - len;
+        &mut self[index..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use OverlapMut;
+
+    #[cfg(rustc_1_23)]
+    #[test]
+    fn str_uppercase_end_in_place() {
+        let mut buffer = *b"the quick brown";
+        let s = ::core::str::from_utf8_mut(&mut buffer).unwrap();
+
+        s.overlap_end_mut("brown fox").make_ascii_uppercase();
+        assert_eq!(s, "the quick BROWN");
+    }
+
+    #[cfg(rustc_1_23)]
+    #[test]
+    fn str_uppercase_start_in_place() {
+        let mut buffer = *b"fox jumps";
+        let s = ::core::str::from_utf8_mut(&mut buffer).unwrap();
+
+        s.overlap_start_mut("brown fox").make_ascii_uppercase();
+        assert_eq!(s, "FOX jumps");
+    }
+
+    #[cfg(rustc_1_23)]
+    #[test]
+    fn str_multi_byte() {
+        let mut buffer = [0; 7];
+        buffer.copy_from_slice("ab語cd".as_bytes());
+        let s = ::core::str::from_utf8_mut(&mut buffer).unwrap();
+
+        s.overlap_end_mut("語cde").make_ascii_uppercase();
+        assert_eq!(s, "ab語CD");
+    }
+
+    #[cfg(rustc_1_20)]
+    #[test]
+    fn str_no_overlap() {
+        let mut buffer = *b"abc";
+        let s = ::core::str::from_utf8_mut(&mut buffer).unwrap();
+
+        assert_eq!(s.overlap_end_mut("def"), "");
+        assert_eq!(s.overlap_start_mut("def"), "");
+    }
+
+    #[test]
+    fn slice_end() {
+        let mut values = [1, 2, 3];
+        for value in values.overlap_end_mut(&[2, 3, 4]) {
+            *value = 0;
+        }
+        assert_eq!(values, [1, 0, 0]);
+    }
+
+    #[test]
+    fn slice_start() {
+        let mut values = [2, 3, 4];
+        for value in values.overlap_start_mut(&[1, 2, 3]) {
+            *value = 0;
+        }
+        assert_eq!(values, [0, 0, 4]);
+    }
+
+    #[test]
+    fn slice_no_overlap() {
+        let mut values = [1, 2, 3];
+        assert!(values.overlap_end_mut(&[4, 5]).is_empty());
+        assert!(values.overlap_start_mut(&[4, 5]).is_empty());
+    }
+}
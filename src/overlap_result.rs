@@ -0,0 +1,234 @@
+//! Overlaps that carry the byte ranges they were found at.
+
+use core::fmt;
+use core::ops::{Deref, Range};
+use overlap_index;
+
+/// An overlap of two string slices, along with its byte range within each of them.
+///
+/// This struct is created by [`StrOverlap::overlap_start_result`] and
+/// [`StrOverlap::overlap_end_result`]. It dereferences to the overlapping text, so it can be used
+/// wherever a `&str` is expected, while also recording where that text was found.
+///
+/// An `OverlapResult` can be compared directly with a `str` or `&str`, in either order, in which
+/// case only its text is compared. Comparing two `OverlapResult`s also compares their ranges.
+///
+/// # Example
+/// ```
+/// use str_overlap::StrOverlap;
+///
+/// let overlap = "abc".overlap_end_result("bcd");
+///
+/// assert_eq!(overlap, "bc");
+/// assert_eq!(overlap.self_range, 1..3);
+/// assert_eq!(overlap.other_range, 0..2);
+/// assert!(overlap.starts_with('b'));
+/// ```
+///
+/// [`StrOverlap::overlap_start_result`]: ::StrOverlap::overlap_start_result
+/// [`StrOverlap::overlap_end_result`]: ::StrOverlap::overlap_end_result
+#[derive(Clone, Eq, PartialEq)]
+pub struct OverlapResult<'a> {
+    /// The overlapping text, borrowed from `self`.
+    pub text: &'a str,
+    /// The byte range of the overlap within `self`.
+    pub self_range: Range<usize>,
+    /// The byte range of the overlap within `other`.
+    pub other_range: Range<usize>,
+}
+
+impl<'a> Deref for OverlapResult<'a> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+/// Compares the overlapping text with a string slice, ignoring the ranges.
+impl<'a> PartialEq<str> for OverlapResult<'a> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+/// Compares the overlapping text with a string slice, ignoring the ranges.
+impl<'a> PartialEq<&'a str> for OverlapResult<'a> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.text == *other
+    }
+}
+
+/// Compares a string slice with the overlapping text, ignoring the ranges.
+impl<'a> PartialEq<OverlapResult<'a>> for str {
+    #[inline]
+    fn eq(&self, other: &OverlapResult<'a>) -> bool {
+        self == other.text
+    }
+}
+
+/// Compares a string slice with the overlapping text, ignoring the ranges.
+impl<'a> PartialEq<OverlapResult<'a>> for &'a str {
+    #[inline]
+    fn eq(&self, other: &OverlapResult<'a>) -> bool {
+        *self == other.text
+    }
+}
+
+/// Formats the overlap as its text followed by its ranges, such as
+/// `"bc" (self: 1..3, other: 0..2)`.
+impl<'a> fmt::Debug for OverlapResult<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} (self: {:?}, other: {:?})",
+            self.text, self.self_range, self.other_range
+        )
+    }
+}
+
+/// Returns the overlap found at the start of `s` and the end of `other`, along with its ranges.
+#[inline]
+pub fn overlap_start_result<'a>(s: &'a str, other: &str) -> OverlapResult<'a> {
+    let index = overlap_index(other, s);
+    let len = other.len() - index;
+    OverlapResult {
+        text: &s[..len],
+        self_range: 0..len,
+        other_range: index..other.len(),
+    }
+}
+
+/// Returns the overlap found at the end of `s` and the start of `other`, along with its ranges.
+#[inline]
+pub fn overlap_end_result<'a>(s: &'a str, other: &str) -> OverlapResult<'a> {
+    let index = overlap_index(s, other);
+    OverlapResult {
+        text: &s[index..],
+        self_range: index..s.len(),
+        other_range: 0..(s.len() - index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlapResult;
+    use StrOverlap;
+
+    #[test]
+    fn end_ranges() {
+        let overlap = "abc".overlap_end_result("bcd");
+
+        assert_eq!(overlap.text, "bc");
+        assert_eq!(overlap.self_range, 1..3);
+        assert_eq!(overlap.other_range, 0..2);
+    }
+
+    #[test]
+    fn start_ranges() {
+        let overlap = "bcd".overlap_start_result("abc");
+
+        assert_eq!(overlap.text, "bc");
+        assert_eq!(overlap.self_range, 0..2);
+        assert_eq!(overlap.other_range, 1..3);
+    }
+
+    #[test]
+    fn multi_byte() {
+        let overlap = "a語b".overlap_end_result("語bc");
+
+        assert_eq!(overlap.text, "語b");
+        assert_eq!(overlap.self_range, 1..5);
+        assert_eq!(overlap.other_range, 0..4);
+    }
+
+    #[test]
+    fn no_overlap() {
+        let end = "abc".overlap_end_result("def");
+        let start = "abc".overlap_start_result("def");
+
+        assert_eq!(end.text, "");
+        assert_eq!(end.self_range, 3..3);
+        assert_eq!(end.other_range, 0..0);
+        assert_eq!(start.self_range, 0..0);
+        assert_eq!(start.other_range, 3..3);
+    }
+
+    #[test]
+    fn derefs_to_str() {
+        let overlap = "abc".overlap_end_result("bcd");
+
+        assert_eq!(overlap.len(), 2);
+        assert!(overlap.ends_with('c'));
+        let text: &str = &overlap;
+        assert_eq!(text, "bc");
+    }
+
+    #[test]
+    fn borrows_from_self() {
+        let s = "abc";
+
+        assert_eq!(s.overlap_end_result("bcd").as_ptr(), s[1..].as_ptr());
+        assert_eq!(s.overlap_start_result("xab").as_ptr(), s.as_ptr());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn debug() {
+        use alloc::string::String;
+        use core::fmt::Write;
+
+        let mut found = String::new();
+        write!(found, "{:?}", "abc".overlap_end_result("bcd")).unwrap();
+        assert_eq!(found, "\"bc\" (self: 1..3, other: 0..2)");
+
+        let mut empty = String::new();
+        write!(empty, "{:?}", "abc".overlap_end_result("def")).unwrap();
+        assert_eq!(empty, "\"\" (self: 3..3, other: 0..0)");
+    }
+
+    #[test]
+    fn compares_with_str() {
+        let overlap = "abc".overlap_end_result("bcd");
+
+        assert!(overlap == "bc");
+        assert!("bc" == overlap);
+        assert!(overlap == *"bc");
+        assert!(*"bc" == overlap);
+        assert!(overlap != "abc");
+        assert!("abc" != overlap);
+        assert_eq!(overlap, "bc");
+        assert_eq!("bc", overlap);
+    }
+
+    #[test]
+    fn compares_with_str_in_generic_code() {
+        fn same<T, U>(left: T, right: U) -> bool
+        where
+            T: PartialEq<U>,
+        {
+            left == right
+        }
+
+        assert!(same("abc".overlap_end_result("bcd"), "bc"));
+        assert!(same("bc", "abc".overlap_end_result("bcd")));
+        assert!(!same("abc".overlap_end_result("def"), "bc"));
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            "abc".overlap_end_result("bcd"),
+            OverlapResult {
+                text: "bc",
+                self_range: 1..3,
+                other_range: 0..2,
+            }
+        );
+        // Equal text found at different ranges is not equal.
+        assert!("abc".overlap_end_result("bcd") != "xabc".overlap_end_result("bcd"));
+    }
+}
@@ -0,0 +1,154 @@
+//! Overlaps between values of different types.
+//!
+//! [`Overlap`] only finds overlaps between two values of the same type. When one side is a string
+//! slice and the other is a buffer of raw bytes, converting either one first is either fallible
+//! (validating the bytes as UTF-8) or loses the type of the other side. [`OverlapWith`] instead
+//! compares the bytes of both sides directly.
+
+use Overlap;
+
+/// Provides methods for finding overlaps between `self` and a value of another type.
+///
+/// This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::OverlapWith;
+/// ```
+///
+/// `OverlapWith<[u8]>` is implemented on [`str`], and `OverlapWith<str>` is implemented on
+/// `[u8]`. Both compare bytes for equality and return the overlap as a `&[u8]`, since an overlap
+/// with arbitrary bytes need not begin or end on a character bound of the string slice. Neither
+/// side is ever validated as UTF-8, so the bytes outside of the overlap may contain anything.
+///
+/// The methods of this trait are named differently from those of [`Overlap`], so that both traits
+/// can be in scope at once.
+pub trait OverlapWith<Rhs: ?Sized> {
+    /// The type of the returned overlap, which is borrowed from `self`.
+    type Output: ?Sized;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapWith;
+    ///
+    /// assert_eq!("bcd".overlap_start_with(b"\xFFabc".as_ref()), b"bc");
+    /// ```
+    #[must_use]
+    fn overlap_start_with(&self, other: &Rhs) -> &Self::Output;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use str_overlap::OverlapWith;
+    ///
+    /// assert_eq!("abc".overlap_end_with(b"bcd\xFF".as_ref()), b"bc");
+    /// ```
+    #[must_use]
+    fn overlap_end_with(&self, other: &Rhs) -> &Self::Output;
+}
+
+impl OverlapWith<[u8]> for str {
+    type Output = [u8];
+
+    #[inline]
+    fn overlap_start_with(&self, other: &[u8]) -> &[u8] {
+        self.as_bytes().overlap_start(other)
+    }
+
+    #[inline]
+    fn overlap_end_with(&self, other: &[u8]) -> &[u8] {
+        self.as_bytes().overlap_end(other)
+    }
+}
+
+impl OverlapWith<str> for [u8] {
+    type Output = [u8];
+
+    #[inline]
+    fn overlap_start_with(&self, other: &str) -> &[u8] {
+        self.overlap_start(other.as_bytes())
+    }
+
+    #[inline]
+    fn overlap_end_with(&self, other: &str) -> &[u8] {
+        self.overlap_end(other.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlapWith;
+    use Overlap;
+
+    #[test]
+    fn str_with_bytes_start() {
+        assert_eq!("bcd".overlap_start_with(b"abc".as_ref()), b"bc");
+    }
+
+    #[test]
+    fn str_with_bytes_end() {
+        assert_eq!("abc".overlap_end_with(b"bcd".as_ref()), b"bc");
+    }
+
+    #[test]
+    fn bytes_with_str_start() {
+        assert_eq!(b"bcd".as_ref().overlap_start_with("abc"), b"bc");
+    }
+
+    #[test]
+    fn bytes_with_str_end() {
+        assert_eq!(b"abc".as_ref().overlap_end_with("bcd"), b"bc");
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!("abc".overlap_end_with(b"def".as_ref()), b"");
+        assert_eq!(b"abc".as_ref().overlap_start_with("def"), b"");
+        assert_eq!("".overlap_end_with(b"".as_ref()), b"");
+    }
+
+    #[test]
+    fn invalid_utf8_outside_overlap() {
+        let bytes = b"cd\xC3\x28\xFF".as_ref();
+
+        assert_eq!("abcd".overlap_end_with(bytes), b"cd");
+        assert_eq!(bytes.overlap_start_with("abcd"), b"cd");
+
+        let bytes = b"\xFF\xFEab".as_ref();
+
+        assert_eq!("abc".overlap_start_with(bytes), b"ab");
+        assert_eq!(bytes.overlap_end_with("abc"), b"ab");
+    }
+
+    #[test]
+    fn partial_character() {
+        // The overlap may split a multi-byte character of the string slice.
+        let s = "a語";
+
+        assert_eq!(s.overlap_end_with(b"\x9e".as_ref()), b"\x9e");
+        assert_eq!(s.overlap_end_with(b"\xaa\x9ex".as_ref()), b"\xaa\x9e");
+    }
+
+    #[test]
+    fn matches_byte_overlap() {
+        let pairs = [("abc", "bcd"), ("aba", "aba"), ("a語", "語b"), ("", "abc")];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                left.overlap_end_with(right.as_bytes()),
+                left.as_bytes().overlap_end(right.as_bytes())
+            );
+            assert_eq!(
+                left.as_bytes().overlap_start_with(right),
+                left.as_bytes().overlap_start(right.as_bytes())
+            );
+        }
+    }
+
+    #[test]
+    fn both_traits_in_scope() {
+        assert_eq!("abc".overlap_end("bcd"), "bc");
+        assert_eq!("abc".overlap_end_with(b"bcd".as_ref()), b"bc");
+    }
+}
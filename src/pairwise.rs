@@ -0,0 +1,220 @@
+//! Overlaps between every pair of strings in a collection.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use Overlap;
+#[cfg(feature = "alloc")]
+use StrOverlap;
+
+/// Returns the longest overlap between any two of `strings`.
+///
+/// Every ordered pair of distinct indices `(i, j)` is considered, with the overlap found at the end
+/// of `strings[i]` and the start of `strings[j]`. The returned tuple contains `i`, `j`, and the
+/// overlap, which is a reference to the substring contained in `strings[i]`. Overlaps are
+/// compared by their length in bytes, and if several pairs share the longest overlap, the one with
+/// the lowest `i`, then the lowest `j`, is returned.
+///
+/// Returns `None` if there are fewer than two strings, or if no pair of strings overlaps.
+///
+/// This compares every pair of strings, so its running time grows quadratically with the number of
+/// strings.
+///
+/// # Example
+/// ```
+/// use str_overlap::longest_common_overlap;
+///
+/// assert_eq!(
+///     longest_common_overlap(&["cdef", "xyz", "abcd"]),
+///     Some((2, 0, "cd"))
+/// );
+/// assert_eq!(longest_common_overlap(&["abc", "xyz"]), None);
+/// ```
+#[must_use]
+pub fn longest_common_overlap<'a>(strings: &[&'a str]) -> Option<(usize, usize, &'a str)> {
+    let mut longest = None;
+    // Only non-empty overlaps are ever returned.
+    let mut longest_len = 0;
+    for (i, left) in strings.iter().enumerate() {
+        for (j, right) in strings.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let overlap = left.overlap_end(right);
+            if overlap.len() > longest_len {
+                longest = Some((i, j, overlap));
+                longest_len = overlap.len();
+            }
+        }
+    }
+    longest
+}
+
+/// Assembles `fragments` into a single string by repeatedly merging the pair with the longest
+/// overlap.
+///
+/// Fragments that are equal to, or contained within, another fragment add nothing to the result,
+/// so they are removed first. The remaining fragments are then merged greedily: the pair chosen by
+/// [`longest_common_overlap`] is merged using [`merge_end`], and this repeats until a single string
+/// remains. If no remaining pair overlaps, the first two fragments are concatenated instead.
+///
+/// This is a greedy heuristic for the shortest common superstring problem. It is not guaranteed to
+/// find the shortest string containing every fragment, although every fragment is always
+/// contained in the result.
+///
+/// Returns an empty string if there are no non-empty fragments.
+///
+/// This function is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// use str_overlap::assemble;
+///
+/// assert_eq!(assemble(&["cde", "abc", "bcd"]), "abcde");
+/// ```
+///
+/// [`merge_end`]: StrOverlap::merge_end
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn assemble(fragments: &[&str]) -> String {
+    // Of several equal fragments, only the first is kept.
+    let mut remaining: Vec<String> = fragments
+        .iter()
+        .enumerate()
+        .filter(|&(i, fragment)| {
+            !fragment.is_empty()
+                && !fragments.iter().enumerate().any(|(j, other)| {
+                    j != i && other.contains(fragment) && (other.len() > fragment.len() || j < i)
+                })
+        })
+        .map(|(_, fragment)| String::from(*fragment))
+        .collect();
+
+    while remaining.len() > 1 {
+        let (i, j) = {
+            let strings: Vec<&str> = remaining.iter().map(|string| &string[..]).collect();
+            longest_common_overlap(&strings).map_or((0, 1), |(i, j, _)| (i, j))
+        };
+        let merged = remaining[i].merge_end(&remaining[j]);
+        remaining[i] = merged;
+        remaining.remove(j);
+    }
+    remaining.pop().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::assemble;
+    use super::longest_common_overlap;
+
+    #[test]
+    fn fewer_than_two_strings() {
+        assert_eq!(longest_common_overlap(&[]), None);
+        assert_eq!(longest_common_overlap(&["abcabc"]), None);
+    }
+
+    #[test]
+    fn two_strings() {
+        assert_eq!(longest_common_overlap(&["abc", "bcd"]), Some((0, 1, "bc")));
+        assert_eq!(longest_common_overlap(&["bcd", "abc"]), Some((1, 0, "bc")));
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(longest_common_overlap(&["abc", "def", "ghi"]), None);
+        assert_eq!(longest_common_overlap(&["", ""]), None);
+    }
+
+    #[test]
+    fn non_adjacent_pair() {
+        assert_eq!(
+            longest_common_overlap(&["xxabcd", "cdy", "abcdz"]),
+            Some((0, 2, "abcd"))
+        );
+        assert_eq!(
+            longest_common_overlap(&["bcdefg", "zzz", "abcdef"]),
+            Some((2, 0, "bcdef"))
+        );
+    }
+
+    #[test]
+    fn string_does_not_overlap_itself() {
+        assert_eq!(
+            longest_common_overlap(&["abab", "bx", "xyz"]),
+            Some((0, 1, "b"))
+        );
+    }
+
+    #[test]
+    fn ties_prefer_lowest_indices() {
+        assert_eq!(
+            longest_common_overlap(&["ab", "ba", "bc"]),
+            Some((0, 1, "b"))
+        );
+    }
+
+    #[test]
+    fn returns_substring_of_left() {
+        let strings = ["za", "xyz"];
+        let (i, _, overlap) = longest_common_overlap(&strings).unwrap();
+
+        assert_eq!(i, 1);
+        assert_eq!(overlap.as_ptr(), strings[1][2..].as_ptr());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_chain() {
+        assert_eq!(assemble(&["abc", "bcd", "cde"]), "abcde");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_shuffled() {
+        assert_eq!(assemble(&["cde", "abc", "bcd"]), "abcde");
+        assert_eq!(assemble(&["lo wo", "hello", "world"]), "hello world");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_duplicates() {
+        assert_eq!(assemble(&["abc", "bcd", "abc", "bcd"]), "abcd");
+        assert_eq!(assemble(&["aaa", "aaa"]), "aaa");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_contained_fragments() {
+        assert_eq!(assemble(&["abcde", "bcd", "c", "def"]), "abcdef");
+        assert_eq!(assemble(&["b", "abc"]), "abc");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_no_overlap() {
+        assert_eq!(assemble(&["abc", "xyz"]), "abcxyz");
+        assert_eq!(assemble(&["abc", "xyz", "yzq"]), "abcxyzq");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_empty() {
+        assert_eq!(assemble(&[]), "");
+        assert_eq!(assemble(&["", ""]), "");
+        assert_eq!(assemble(&["", "abc"]), "abc");
+        assert_eq!(assemble(&["abc"]), "abc");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assemble_contains_every_fragment() {
+        let fragments = ["xbcd", "abcx", "cdab", "dabc", "bcdx"];
+        let assembled = assemble(&fragments);
+
+        for fragment in fragments.iter() {
+            assert!(assembled.contains(fragment));
+        }
+    }
+}
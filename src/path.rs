@@ -0,0 +1,385 @@
+//! Overlap support for filesystem paths.
+//!
+//! Paths are compared by their [`Component`]s rather than by their bytes. This means an overlap
+//! always consists of whole components: `ab/cd` does not overlap with `b/cd/ef`, even though the
+//! bytes `b/cd` are shared.
+//!
+//! [`Component`]: std::path::Component
+
+use std::path::{Path, PathBuf};
+
+/// Returns the overlap found at the end of `left` and the start of `right`, compared by component.
+///
+/// The returned path is a sub-path of `left`, made up of the trailing components of `left` that
+/// are equal to the leading components of `right`. If no components overlap, an empty path is
+/// returned.
+///
+/// Components are compared as they are yielded by [`Path::components()`], so redundant separators,
+/// trailing separators, and non-leading `.` components are ignored. A root component only
+/// overlaps with another root component, meaning an absolute `right` can only overlap with all of
+/// `left`.
+///
+/// # Example
+/// ```
+/// use std::path::Path;
+/// use str_overlap::path_overlap_end;
+///
+/// assert_eq!(
+///     path_overlap_end(Path::new("/var/log/app"), Path::new("log/app/today.txt")),
+///     Path::new("log/app")
+/// );
+/// assert_eq!(
+///     path_overlap_end(Path::new("ab/cd"), Path::new("b/cd/ef")),
+///     Path::new("")
+/// );
+/// ```
+#[must_use]
+pub fn path_overlap_end<'a>(left: &'a Path, right: &Path) -> &'a Path {
+    let mut components = left.components();
+    loop {
+        let suffix = components.as_path();
+        if suffix.components().count() <= right.components().count()
+            && suffix
+                .components()
+                .zip(right.components())
+                .all(|(left_component, right_component)| left_component == right_component)
+        {
+            return suffix;
+        }
+        // The empty suffix always matches, so this will never exhaust the components before
+        // returning.
+        components.next();
+    }
+}
+
+/// Provides methods for finding overlaps between paths, compared by component.
+///
+/// Overlaps are found the same way as in [`path_overlap_end`], and are always sub-paths of `self`
+/// made up of whole components. This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::PathOverlap;
+/// ```
+///
+/// This trait is only available when the `std` feature is enabled.
+pub trait PathOverlap {
+    /// Returns the overlap found at the start of `self` and the end of `other`, compared by
+    /// component.
+    ///
+    /// The returned path is made up of the leading components of `self` that are equal to the
+    /// trailing components of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use str_overlap::PathOverlap;
+    ///
+    /// assert_eq!(
+    ///     Path::new("b/c/d").overlap_start(Path::new("a/b/c")),
+    ///     Path::new("b/c")
+    /// );
+    /// ```
+    #[must_use]
+    fn overlap_start(&self, other: &Self) -> &Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`, compared by
+    /// component.
+    ///
+    /// The returned path is made up of the trailing components of `self` that are equal to the
+    /// leading components of `other`. This is the same as [`path_overlap_end`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use str_overlap::PathOverlap;
+    ///
+    /// assert_eq!(
+    ///     Path::new("a/b/c").overlap_end(Path::new("b/c/d")),
+    ///     Path::new("b/c")
+    /// );
+    /// ```
+    #[must_use]
+    fn overlap_end(&self, other: &Self) -> &Self;
+}
+
+impl PathOverlap for Path {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        let other_len = other.components().count();
+        let mut components = self.components();
+        loop {
+            let prefix = components.as_path();
+            let prefix_len = prefix.components().count();
+            if prefix_len <= other_len
+                && prefix
+                    .components()
+                    .eq(other.components().skip(other_len - prefix_len))
+            {
+                return prefix;
+            }
+            // The empty prefix always matches, so this will never exhaust the components before
+            // returning.
+            components.next_back();
+        }
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        path_overlap_end(self, other)
+    }
+}
+
+/// Joins `right` onto `left`, removing the components that overlap.
+///
+/// The overlap is found using [`path_overlap_end`], and only the components of `right` following
+/// the overlap are joined onto `left`. If the entirety of `right` overlaps, the result is equal to
+/// `left`.
+///
+/// If no components overlap, this is the same as [`Path::join()`]. Note that this means an
+/// absolute `right` that doesn't overlap with `left` will replace it entirely.
+///
+/// # Example
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use str_overlap::join_dedup;
+///
+/// assert_eq!(
+///     join_dedup(Path::new("/var/log/app"), Path::new("log/app/today.txt")),
+///     PathBuf::from("/var/log/app/today.txt")
+/// );
+/// ```
+#[must_use]
+pub fn join_dedup(left: &Path, right: &Path) -> PathBuf {
+    let mut remaining = right.components();
+    for _ in path_overlap_end(left, right).components() {
+        remaining.next();
+    }
+
+    let remaining = remaining.as_path();
+    if remaining.as_os_str().is_empty() {
+        left.to_path_buf()
+    } else {
+        left.join(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join_dedup, path_overlap_end, PathOverlap};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!(
+            path_overlap_end(Path::new("a/b/c"), Path::new("b/c/d")),
+            Path::new("b/c")
+        );
+    }
+
+    #[test]
+    fn full_overlap() {
+        assert_eq!(
+            path_overlap_end(Path::new("a/b/c"), Path::new("a/b/c")),
+            Path::new("a/b/c")
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(
+            path_overlap_end(Path::new("a/b/c"), Path::new("d/e/f")),
+            Path::new("")
+        );
+    }
+
+    #[test]
+    fn no_partial_component_overlap() {
+        assert_eq!(
+            path_overlap_end(Path::new("ab/cd"), Path::new("b/cd/ef")),
+            Path::new("")
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            path_overlap_end(Path::new(""), Path::new("a")),
+            Path::new("")
+        );
+        assert_eq!(
+            path_overlap_end(Path::new("a"), Path::new("")),
+            Path::new("")
+        );
+        assert_eq!(
+            path_overlap_end(Path::new(""), Path::new("")),
+            Path::new("")
+        );
+    }
+
+    #[test]
+    fn trailing_separator() {
+        assert_eq!(
+            path_overlap_end(Path::new("a/b/c/"), Path::new("b/c/d")),
+            Path::new("b/c")
+        );
+        assert_eq!(
+            path_overlap_end(Path::new("a/b/c"), Path::new("b/c/")),
+            Path::new("b/c")
+        );
+    }
+
+    #[test]
+    fn current_dir_components() {
+        assert_eq!(
+            path_overlap_end(Path::new("a/./b/c"), Path::new("b/./c/d")),
+            Path::new("b/c")
+        );
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(
+            path_overlap_end(Path::new("/a/b"), Path::new("/a/b/c")),
+            Path::new("/a/b")
+        );
+        assert_eq!(
+            path_overlap_end(Path::new("/a/b"), Path::new("a/b/c")),
+            Path::new("a/b")
+        );
+        assert_eq!(
+            path_overlap_end(Path::new("a/b"), Path::new("/b/c")),
+            Path::new("")
+        );
+    }
+
+    #[test]
+    fn join_dedup_partial_overlap() {
+        assert_eq!(
+            join_dedup(Path::new("/var/log/app"), Path::new("log/app/today.txt")),
+            PathBuf::from("/var/log/app/today.txt")
+        );
+    }
+
+    #[test]
+    fn join_dedup_full_overlap() {
+        assert_eq!(
+            join_dedup(Path::new("a/b/c"), Path::new("b/c")),
+            PathBuf::from("a/b/c")
+        );
+    }
+
+    #[test]
+    fn join_dedup_no_overlap() {
+        assert_eq!(
+            join_dedup(Path::new("a/b"), Path::new("c/d")),
+            PathBuf::from("a/b/c/d")
+        );
+    }
+
+    #[test]
+    fn join_dedup_no_partial_component_overlap() {
+        assert_eq!(
+            join_dedup(Path::new("ab/cd"), Path::new("b/cd/ef")),
+            PathBuf::from("ab/cd/b/cd/ef")
+        );
+    }
+
+    #[test]
+    fn trait_overlap_end() {
+        let path = Path::new("a/b/c");
+        let overlap = path.overlap_end(Path::new("b/c/d"));
+
+        assert_eq!(overlap, Path::new("b/c"));
+        // The overlap is a sub-path of `self`.
+        assert_eq!(
+            overlap.as_os_str().to_str().unwrap().as_ptr(),
+            path.to_str().unwrap()[2..].as_ptr()
+        );
+    }
+
+    #[test]
+    fn trait_overlap_start() {
+        let path = Path::new("b/c/d");
+        let overlap = path.overlap_start(Path::new("a/b/c"));
+
+        assert_eq!(overlap, Path::new("b/c"));
+        assert_eq!(
+            overlap.as_os_str().to_str().unwrap().as_ptr(),
+            path.to_str().unwrap().as_ptr()
+        );
+    }
+
+    #[test]
+    fn trait_no_overlap() {
+        assert_eq!(
+            Path::new("a/b").overlap_end(Path::new("c/d")),
+            Path::new("")
+        );
+        assert_eq!(
+            Path::new("a/b").overlap_start(Path::new("c/d")),
+            Path::new("")
+        );
+        assert_eq!(
+            Path::new("ab/cd").overlap_start(Path::new("x/a")),
+            Path::new("")
+        );
+    }
+
+    #[test]
+    fn trait_trailing_separator() {
+        assert_eq!(
+            Path::new("a/b/c/").overlap_end(Path::new("b/c//d")),
+            Path::new("b/c")
+        );
+        assert_eq!(
+            Path::new("b/c/d/").overlap_start(Path::new("a/b/c/")),
+            Path::new("b/c")
+        );
+        assert_eq!(
+            Path::new("b//c/").overlap_start(Path::new("a/b/c")),
+            Path::new("b//c/")
+        );
+    }
+
+    #[test]
+    fn trait_root() {
+        assert_eq!(
+            Path::new("/a/b").overlap_start(Path::new("x/y")),
+            Path::new("")
+        );
+        assert_eq!(
+            Path::new("/a/b").overlap_start(Path::new("/a")),
+            Path::new("/a")
+        );
+        assert_eq!(
+            Path::new("/a/b").overlap_start(Path::new("x/a")),
+            Path::new("")
+        );
+        assert_eq!(
+            Path::new("/a/b").overlap_end(Path::new("/a/b/c")),
+            Path::new("/a/b")
+        );
+        assert_eq!(Path::new("a/b").overlap_end(Path::new("/b")), Path::new(""));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn trait_prefix() {
+        assert_eq!(
+            Path::new(r"C:\a\b").overlap_end(Path::new(r"b\c")),
+            Path::new("b")
+        );
+        assert_eq!(
+            Path::new(r"C:\a\b").overlap_end(Path::new(r"C:\a\b\c")),
+            Path::new(r"C:\a\b")
+        );
+        assert_eq!(
+            Path::new(r"a\b").overlap_end(Path::new(r"C:\b")),
+            Path::new("")
+        );
+        assert_eq!(
+            Path::new(r"C:\a\b").overlap_start(Path::new(r"D:\x\C:\a")),
+            Path::new("")
+        );
+    }
+}
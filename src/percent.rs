@@ -0,0 +1,192 @@
+//! Overlaps of percent-encoded text, which treat each `%XY` escape as equal to the byte it
+//! encodes.
+//!
+//! Text such as a URL may have any byte escaped or left raw, so `"a%2Fb"` and `"a/b"` are the
+//! same text. An escape is only decoded when both of its hex digits are present and valid, in
+//! either case. Otherwise, including when an escape is cut off at the edge of a string, its `'%'`
+//! is compared literally.
+
+use core::ops::Range;
+use is_char_boundary;
+use mapped::Mapped;
+
+/// Returns the value of the hex digit `byte`, in either case.
+#[inline]
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|value| value as u8)
+}
+
+/// Splits `s` into bytes, where each valid `%XY` escape is read as the single byte it encodes.
+#[inline]
+fn decode_all(s: &[u8]) -> Mapped<u8> {
+    let mut decoded = Mapped::new(s.len());
+    let mut index = 0;
+    while index < s.len() {
+        if s[index] == b'%' && index + 2 < s.len() {
+            if let (Some(high), Some(low)) = (hex_value(s[index + 1]), hex_value(s[index + 2])) {
+                decoded.push(high << 4 | low, index..(index + 3));
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(s[index], index..(index + 1));
+        index += 1;
+    }
+    decoded
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when each `%XY` escape is treated as equal to the byte it encodes, within `left` and within
+/// `right` respectively.
+///
+/// Escapes are always matched whole, so neither range ever begins or ends within one. If nothing
+/// overlaps, the ranges are empty and located at the end of `left` and the start of `right`.
+#[inline]
+pub fn percent_overlap_indices(left: &[u8], right: &[u8]) -> (Range<usize>, Range<usize>) {
+    decode_all(left).overlap_indices(&decode_all(right))
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when each `%XY` escape is treated as equal to the byte it encodes, within `left` and within
+/// `right` respectively.
+///
+/// This is the same as [`percent_overlap_indices`], except that a raw multi-byte character is
+/// never split, so both ranges are always on character bounds.
+#[inline]
+pub fn str_percent_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    let left_decoded = decode_all(left.as_bytes());
+    let right_decoded = decode_all(right.as_bytes());
+    let len = left_decoded.units.len();
+    left_decoded.overlap_indices_at_boundary(&right_decoded, |index| {
+        is_char_boundary(left, left_decoded.ranges[index].start)
+            && is_char_boundary(right, right_decoded.ranges[len - index - 1].end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{percent_overlap_indices, str_percent_overlap_indices};
+    use {ByteSliceOverlap, Overlap, StrOverlap};
+
+    #[test]
+    fn escaped_and_raw() {
+        assert_eq!(
+            "a/b%2Fc".overlap_end_percent_decoded_indices("b/c/d"),
+            (2..7, 0..3)
+        );
+        assert_eq!(
+            "a%2Fb/c".overlap_end_percent_decoded_indices("/b%2Fc/d"),
+            (1..7, 0..6)
+        );
+        assert_eq!(
+            "/b%2Fc/d".overlap_start_percent_decoded_indices("a%2Fb/c"),
+            (0..6, 1..7)
+        );
+    }
+
+    #[test]
+    fn hex_case() {
+        assert_eq!(
+            "a%2f".overlap_end_percent_decoded_indices("%2Fb"),
+            (1..4, 0..3)
+        );
+        assert_eq!(
+            "x%e2%82%ac".overlap_end_percent_decoded_indices("\u{20ac}y"),
+            (1..10, 0..3)
+        );
+    }
+
+    #[test]
+    fn invalid_escapes_compared_literally() {
+        assert_eq!(
+            "a%zz".overlap_end_percent_decoded_indices("%zzb"),
+            (1..4, 0..3)
+        );
+        assert_eq!(
+            "a%g1".overlap_end_percent_decoded_indices("g1"),
+            (2..4, 0..2)
+        );
+        assert_eq!(
+            "a%25".overlap_end_percent_decoded_indices("%b"),
+            (1..4, 0..1)
+        );
+    }
+
+    #[test]
+    fn seam_within_escape() {
+        // Compared byte by byte, the overlap ends between the '%' and hex digits of `right`.
+        assert_eq!("a%2".overlap_end("%2Fb"), "%2");
+        assert_eq!(
+            "a%2".overlap_end_percent_decoded_indices("%2Fb"),
+            (3..3, 0..0)
+        );
+        // An escape cut off at the end of `self` is compared literally.
+        assert_eq!(
+            "a%2".overlap_end_percent_decoded_indices("%2xb"),
+            (1..3, 0..2)
+        );
+        assert_eq!(
+            "a%".overlap_end_percent_decoded_indices("%2Fb"),
+            (2..2, 0..0)
+        );
+        // Likewise for an escape cut off at the start of `other`.
+        assert_eq!(
+            "a%2F".overlap_end_percent_decoded_indices("2Fb"),
+            (4..4, 0..0)
+        );
+    }
+
+    #[test]
+    fn multi_byte_never_split() {
+        // The escaped bytes of "é" match the second byte of a raw "é", which can't be split.
+        assert_eq!(
+            "x\u{e9}".overlap_end_percent_decoded_indices("%A9y"),
+            (3..3, 0..0)
+        );
+        assert_eq!(
+            "x\u{e9}".overlap_end_percent_decoded_indices("%C3%A9y"),
+            (1..3, 0..6)
+        );
+        // Byte slices have no characters, so they may be split anywhere.
+        assert_eq!(
+            "x\u{e9}"
+                .as_bytes()
+                .overlap_end_percent_decoded_indices(b"%A9y"),
+            (2..3, 0..3)
+        );
+    }
+
+    #[test]
+    fn byte_slices() {
+        assert_eq!(
+            b"a\xFF/".overlap_end_percent_decoded_indices(b"%ff%2Fb"),
+            (1..3, 0..6)
+        );
+        assert_eq!(
+            b"%ff%2Fb".overlap_start_percent_decoded_indices(b"a\xFF/"),
+            (0..6, 1..3)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(str_percent_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(str_percent_overlap_indices("", "%2F"), (0..0, 0..0));
+        assert_eq!(percent_overlap_indices(b"%2F", b""), (3..3, 0..0));
+    }
+
+    #[test]
+    fn unescaped_matches_overlap() {
+        let pairs = [
+            ("abc", "bcd"),
+            ("abcab", "abcabd"),
+            ("abc", "def"),
+            ("a語b語", "語b語c"),
+        ];
+        for &(left, right) in &pairs {
+            let (left_range, right_range) = left.overlap_end_percent_decoded_indices(right);
+            assert_eq!(&left[left_range], left.overlap_end(right));
+            assert_eq!(&right[right_range], left.overlap_end(right));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Overlap support for ropes from the [`ropey`] crate.
+//!
+//! A rope stores its text in many separate chunks, so an overlap may span several of them. The
+//! functions in this module walk the chunks of a [`RopeSlice`] directly, without first copying the
+//! rope into a contiguous buffer. Since a contiguous slice of the overlap may not exist, they
+//! return the length of the overlap in bytes instead.
+//!
+//! [`RopeSlice`]: ropey::RopeSlice
+//! [`ropey`]: https://docs.rs/ropey
+
+use alloc::vec::Vec;
+use core::cmp;
+use ropey::RopeSlice;
+use {failure_table, kmp_matched_len};
+
+/// Returns the length in bytes of the overlap found at the start of `left` and the end of
+/// `right`.
+///
+/// The overlap may span any number of the rope's chunks. The returned length is always on a
+/// character bound of both `left` and `right`.
+///
+/// This function is only available when the `ropey` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate ropey;
+/// extern crate str_overlap;
+///
+/// use ropey::Rope;
+/// use str_overlap::rope_overlap_start_len;
+///
+/// # fn main() {
+/// let rope = Rope::from_str("bcd");
+///
+/// assert_eq!(rope_overlap_start_len(rope.slice(..), "abc"), 2);
+/// # }
+/// ```
+#[must_use]
+pub fn rope_overlap_start_len(left: RopeSlice, right: &str) -> usize {
+    let mut eq = |a: &u8, b: &u8| a == b;
+
+    // Prefixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    let len = cmp::min(left.len_bytes(), right.len());
+    if len == 0 {
+        return 0;
+    }
+
+    // Searching both sides in reverse turns this into finding an overlap at the end of `left`,
+    // which allows the rope's chunks to be streamed as the searched text. Only the bytes of
+    // `right` are copied, since the searched pattern must be indexable.
+    let pattern = right.as_bytes()[(right.len() - len)..]
+        .iter()
+        .rev()
+        .cloned()
+        .collect::<Vec<_>>();
+    let failure = failure_table(&pattern, &mut eq);
+
+    let mut chunks = Vec::new();
+    let mut chunks_len = 0;
+    for chunk in left.chunks() {
+        if chunks_len >= len {
+            break;
+        }
+        chunks.push(chunk.as_bytes());
+        chunks_len += chunk.len();
+    }
+
+    kmp_matched_len(
+        chunks
+            .iter()
+            .rev()
+            .flat_map(|chunk| chunk.iter().rev())
+            .skip(chunks_len - len),
+        &pattern,
+        &failure,
+        &mut eq,
+    )
+}
+
+/// Returns the length in bytes of the overlap found at the end of `left` and the start of
+/// `right`.
+///
+/// The overlap may span any number of the rope's chunks. The returned length is always on a
+/// character bound of both `left` and `right`.
+///
+/// This function is only available when the `ropey` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate ropey;
+/// extern crate str_overlap;
+///
+/// use ropey::Rope;
+/// use str_overlap::rope_overlap_end_len;
+///
+/// # fn main() {
+/// let rope = Rope::from_str("abc");
+///
+/// assert_eq!(rope_overlap_end_len(rope.slice(..), "bcd"), 2);
+/// # }
+/// ```
+#[must_use]
+pub fn rope_overlap_end_len(left: RopeSlice, right: &str) -> usize {
+    let mut eq = |a: &u8, b: &u8| a == b;
+
+    // Suffixes of `left` that are longer than `right` can never overlap, so they are skipped.
+    let len = cmp::min(left.len_bytes(), right.len());
+    if len == 0 {
+        return 0;
+    }
+
+    let pattern = &right.as_bytes()[..len];
+    let failure = failure_table(pattern, &mut eq);
+
+    let start = left.len_bytes() - len;
+    let (chunks, chunk_start, _, _) = left.chunks_at_byte(start);
+    kmp_matched_len(
+        chunks
+            .flat_map(|chunk| chunk.as_bytes().iter())
+            .skip(start - chunk_start),
+        pattern,
+        &failure,
+        &mut eq,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rope_overlap_end_len, rope_overlap_start_len};
+    use alloc::borrow::ToOwned;
+    use alloc::string::String;
+    use ropey::Rope;
+    use Overlap;
+
+    /// Text long enough to be stored across many chunks, with many repeated substrings.
+    fn long_text() -> String {
+        let mut text = String::new();
+        for i in 0..2000 {
+            text.push_str(if i % 7 == 0 { "語" } else { "ab" });
+        }
+        text
+    }
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(
+            rope_overlap_start_len(Rope::from_str("bcd").slice(..), "abc"),
+            2
+        );
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(
+            rope_overlap_end_len(Rope::from_str("abc").slice(..), "bcd"),
+            2
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        let rope = Rope::from_str("abc");
+
+        assert_eq!(rope_overlap_start_len(rope.slice(..), "def"), 0);
+        assert_eq!(rope_overlap_end_len(rope.slice(..), "def"), 0);
+        assert_eq!(rope_overlap_end_len(rope.slice(..), ""), 0);
+        assert_eq!(rope_overlap_end_len(Rope::new().slice(..), "abc"), 0);
+    }
+
+    #[test]
+    fn rope_slice() {
+        let rope = Rope::from_str("xabcx");
+
+        assert_eq!(rope_overlap_end_len(rope.slice(1..4), "bcd"), 2);
+        assert_eq!(rope_overlap_start_len(rope.slice(1..4), "zab"), 2);
+    }
+
+    #[test]
+    fn spans_multiple_chunks_end() {
+        let text = long_text();
+        let rope = Rope::from_str(&text);
+        assert!(rope.chunks().count() > 2);
+
+        let right = text[1000..].to_owned() + "c";
+        let len = rope_overlap_end_len(rope.slice(..), &right);
+
+        assert_eq!(len, text.len() - 1000);
+        assert_eq!(len, text.overlap_end_len(&right));
+    }
+
+    #[test]
+    fn spans_multiple_chunks_start() {
+        let text = long_text();
+        let rope = Rope::from_str(&text);
+        assert!(rope.chunks().count() > 2);
+
+        let index = text.len() - 1001;
+        assert!(text.is_char_boundary(index));
+        let right = "c".to_owned() + &text[..index];
+        let len = rope_overlap_start_len(rope.slice(..), &right);
+
+        assert_eq!(len, index);
+        assert_eq!(len, text.overlap_start_len(&right));
+    }
+
+    #[test]
+    fn matches_str_overlap() {
+        let text = long_text();
+        let rope = Rope::from_str(&text);
+        for &index in &[0, 1, 2, 999, 1000, 1001, 2500, text.len() - 1] {
+            if !text.is_char_boundary(index) {
+                continue;
+            }
+            let suffix = &text[index..];
+            let prefix = &text[..index];
+
+            assert_eq!(
+                rope_overlap_end_len(rope.slice(..), suffix),
+                text.overlap_end_len(suffix)
+            );
+            assert_eq!(
+                rope_overlap_start_len(rope.slice(..), prefix),
+                text.overlap_start_len(prefix)
+            );
+        }
+    }
+}
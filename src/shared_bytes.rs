@@ -0,0 +1,144 @@
+//! Overlap support for shared byte buffers from the [`bytes`] crate.
+//!
+//! Slicing a [`Bytes`] only increments a reference count, so overlaps are returned as new
+//! `Bytes` handles into the original allocation rather than as borrowed slices. This allows an
+//! overlap to outlive the `Bytes` it was found in without copying its contents.
+//!
+//! [`Bytes`]: bytes::Bytes
+//! [`bytes`]: https://docs.rs/bytes
+
+use bytes::Bytes;
+use Overlap;
+
+/// Provides methods for finding overlaps between shared byte buffers.
+///
+/// The returned overlaps are obtained using [`Bytes::slice()`], and therefore share the
+/// allocation of `self`. This trait is made available by pulling it into scope:
+///
+/// ```
+/// use str_overlap::BytesOverlap;
+/// ```
+///
+/// This trait is only available when the `bytes` feature is enabled.
+///
+/// [`Bytes::slice()`]: bytes::Bytes::slice
+pub trait BytesOverlap {
+    /// Returns the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate bytes;
+    /// extern crate str_overlap;
+    ///
+    /// use bytes::Bytes;
+    /// use str_overlap::BytesOverlap;
+    ///
+    /// # fn main() {
+    /// let bytes = Bytes::from_static(b"bcd");
+    ///
+    /// assert_eq!(bytes.overlap_start(&Bytes::from_static(b"abc")), "bc");
+    /// # }
+    /// ```
+    #[must_use]
+    fn overlap_start(&self, other: &Self) -> Self;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate bytes;
+    /// extern crate str_overlap;
+    ///
+    /// use bytes::Bytes;
+    /// use str_overlap::BytesOverlap;
+    ///
+    /// # fn main() {
+    /// let bytes = Bytes::from_static(b"abc");
+    ///
+    /// assert_eq!(bytes.overlap_end(&Bytes::from_static(b"bcd")), "bc");
+    /// # }
+    /// ```
+    #[must_use]
+    fn overlap_end(&self, other: &Self) -> Self;
+}
+
+impl BytesOverlap for Bytes {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> Self {
+        self.slice(..self[..].overlap_start_len(&other[..]))
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> Self {
+        self.slice((self.len() - self[..].overlap_end_len(&other[..]))..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BytesOverlap;
+    use bytes::Bytes;
+
+    /// Returns whether `inner` lies within the memory of `outer`.
+    fn is_within(inner: &Bytes, outer: &Bytes) -> bool {
+        let outer_range = outer.as_ptr() as usize..(outer.as_ptr() as usize + outer.len());
+        let start = inner.as_ptr() as usize;
+        outer_range.start <= start && start + inner.len() <= outer_range.end
+    }
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(
+            Bytes::from_static(b"bcd").overlap_start(&Bytes::from_static(b"abc")),
+            Bytes::from_static(b"bc")
+        );
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(
+            Bytes::from_static(b"abc").overlap_end(&Bytes::from_static(b"bcd")),
+            Bytes::from_static(b"bc")
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        let bytes = Bytes::from_static(b"abc");
+        let other = Bytes::from_static(b"def");
+
+        assert!(bytes.overlap_start(&other).is_empty());
+        assert!(bytes.overlap_end(&other).is_empty());
+        assert!(Bytes::new().overlap_end(&bytes).is_empty());
+    }
+
+    #[test]
+    fn overlap_start_shares_allocation() {
+        let bytes = Bytes::copy_from_slice(b"bcdef");
+        let overlap = bytes.overlap_start(&Bytes::copy_from_slice(b"abc"));
+
+        assert_eq!(overlap, "bc");
+        assert_eq!(overlap.as_ptr(), bytes.as_ptr());
+        assert!(is_within(&overlap, &bytes));
+    }
+
+    #[test]
+    fn overlap_end_shares_allocation() {
+        let bytes = Bytes::copy_from_slice(b"abcde");
+        let overlap = bytes.overlap_end(&Bytes::copy_from_slice(b"defg"));
+
+        assert_eq!(overlap, "de");
+        assert_eq!(overlap.as_ptr(), bytes[3..].as_ptr());
+        assert!(is_within(&overlap, &bytes));
+    }
+
+    #[test]
+    fn overlap_outlives_original() {
+        let overlap = {
+            let bytes = Bytes::copy_from_slice(b"abcde");
+            bytes.overlap_end(&Bytes::from_static(b"cdef"))
+        };
+
+        assert_eq!(overlap, "cde");
+    }
+}
@@ -0,0 +1,181 @@
+//! Overlap support for reference-counted string slices.
+//!
+//! An overlap borrowed from an [`Arc<str>`] can't outlive the borrow of that `Arc`, which makes it
+//! awkward to send to other threads. Rather than copying the overlap into a new `String`, the
+//! functions in this module return a [`SharedOverlap`], which holds a clone of the `Arc` along with
+//! the range of the overlap within it.
+//!
+//! [`Arc<str>`]: alloc::sync::Arc
+
+use alloc::sync::Arc;
+use core::ops::Range;
+use {overlap_index, Overlap};
+
+/// An overlap within a reference-counted string slice.
+///
+/// This struct is created by the [`shared_overlap_start`] and [`shared_overlap_end`] functions.
+/// It shares the allocation of the string it was found in, so cloning it only increments a
+/// reference count, and it can outlive the original `Arc`.
+///
+/// This struct is only available when the `alloc` feature is enabled, on Rust 1.21.0 or newer.
+#[derive(Clone, Debug)]
+pub struct SharedOverlap {
+    source: Arc<str>,
+    range: Range<usize>,
+}
+
+impl SharedOverlap {
+    /// Returns the overlap as a string slice.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use str_overlap::shared_overlap_end;
+    ///
+    /// let s: Arc<str> = Arc::from("abc");
+    ///
+    /// assert_eq!(shared_overlap_end(&s, "bcd").as_str(), "bc");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source[self.range.clone()]
+    }
+
+    /// Returns the string that the overlap was found in.
+    #[inline]
+    #[must_use]
+    pub fn source(&self) -> &Arc<str> {
+        &self.source
+    }
+
+    /// Returns the byte range of the overlap within [`source()`](SharedOverlap::source).
+    #[inline]
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// Returns the overlap found at the start of `s` and the end of `other`, sharing the allocation of
+/// `s`.
+///
+/// The overlap is found the same way as in [`Overlap::overlap_start`], but no string bytes are
+/// copied.
+///
+/// This function is only available when the `alloc` feature is enabled, on Rust 1.21.0 or newer.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use str_overlap::shared_overlap_start;
+///
+/// let s: Arc<str> = Arc::from("bcd");
+///
+/// assert_eq!(shared_overlap_start(&s, "abc").as_str(), "bc");
+/// ```
+#[must_use]
+pub fn shared_overlap_start(s: &Arc<str>, other: &str) -> SharedOverlap {
+    SharedOverlap {
+        source: s.clone(),
+        range: 0..s.overlap_start_len(other),
+    }
+}
+
+/// Returns the overlap found at the end of `s` and the start of `other`, sharing the allocation of
+/// `s`.
+///
+/// The overlap is found the same way as in [`Overlap::overlap_end`], but no string bytes are
+/// copied.
+///
+/// This function is only available when the `alloc` feature is enabled, on Rust 1.21.0 or newer.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use str_overlap::shared_overlap_end;
+///
+/// let s: Arc<str> = Arc::from("abc");
+/// let overlap = shared_overlap_end(&s, "bcd");
+///
+/// thread::spawn(move || assert_eq!(overlap.as_str(), "bc"))
+///     .join()
+///     .unwrap();
+/// ```
+#[must_use]
+pub fn shared_overlap_end(s: &Arc<str>, other: &str) -> SharedOverlap {
+    SharedOverlap {
+        source: s.clone(),
+        range: overlap_index(s, other)..s.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shared_overlap_end, shared_overlap_start};
+    use alloc::sync::Arc;
+    use Overlap;
+
+    #[test]
+    fn partial_overlap_end() {
+        let s: Arc<str> = Arc::from("abc");
+        let overlap = shared_overlap_end(&s, "bcd");
+
+        assert_eq!(overlap.as_str(), s.overlap_end("bcd"));
+        assert_eq!(overlap.range(), 1..3);
+    }
+
+    #[test]
+    fn partial_overlap_start() {
+        let s: Arc<str> = Arc::from("bcd");
+        let overlap = shared_overlap_start(&s, "abc");
+
+        assert_eq!(overlap.as_str(), s.overlap_start("abc"));
+        assert_eq!(overlap.range(), 0..2);
+    }
+
+    #[test]
+    fn no_overlap() {
+        let s: Arc<str> = Arc::from("abc");
+
+        assert_eq!(shared_overlap_end(&s, "xyz").as_str(), "");
+        assert_eq!(shared_overlap_end(&s, "xyz").range(), 3..3);
+        assert_eq!(shared_overlap_start(&s, "xyz").as_str(), "");
+        assert_eq!(shared_overlap_start(&s, "xyz").range(), 0..0);
+    }
+
+    #[test]
+    fn multi_byte() {
+        let s: Arc<str> = Arc::from("hello, 世界");
+
+        assert_eq!(shared_overlap_end(&s, "世界!").as_str(), "世界");
+        assert_eq!(shared_overlap_end(&s, "\u{754c}").as_str(), "界");
+    }
+
+    #[test]
+    fn shares_allocation() {
+        let s: Arc<str> = Arc::from("abcd");
+        let overlap = shared_overlap_end(&s, "cde");
+
+        assert!(Arc::ptr_eq(overlap.source(), &s));
+        assert_eq!(Arc::strong_count(&s), 2);
+        // The overlap points into the original string rather than a copy of it.
+        assert_eq!(overlap.as_str().as_ptr(), s[2..].as_ptr());
+
+        let cloned = overlap.clone();
+        assert_eq!(Arc::strong_count(&s), 3);
+        assert_eq!(cloned.as_str().as_ptr(), s[2..].as_ptr());
+    }
+
+    #[test]
+    fn outlives_source() {
+        let overlap = {
+            let s: Arc<str> = Arc::from("abc");
+            shared_overlap_end(&s, "bcd")
+        };
+
+        assert_eq!(Arc::strong_count(overlap.source()), 1);
+        assert_eq!(overlap.as_str(), "bc");
+    }
+}
@@ -0,0 +1,164 @@
+//! Overlaps of strings that expand tabs to spaces before comparing them.
+//!
+//! Indentation written with tabs in one editor is often written with spaces in another. Expanding
+//! each tab to the spaces it stands for, based on its column, lets these overlap, while still
+//! reporting the overlap as ranges within the original strings.
+
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Splits `s` into columns, where each tab is expanded to the spaces that reach the next tab stop,
+/// with tab stops every `tab_width` columns.
+///
+/// Columns are counted in characters from the start of `s` and from the start of each line.
+#[inline]
+fn expand(s: &str, tab_width: usize) -> Mapped<char> {
+    let mut expanded = Mapped::new(s.len());
+    let mut column = 0;
+    for (index, c) in s.char_indices() {
+        let range = index..(index + c.len_utf8());
+        if c == '\t' {
+            let spaces = tab_width - column % tab_width;
+            for _ in 0..spaces {
+                expanded.push(' ', range.clone());
+            }
+            column += spaces;
+        } else {
+            expanded.push(c, range);
+            column = if c == '\n' { 0 } else { column + 1 };
+        }
+    }
+    expanded
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// after expanding tabs to spaces, within `left` and within `right` respectively.
+///
+/// Tab stops are every `tab_width` columns. A tab is always matched whole, so an overlap that
+/// would begin or end partway through the spaces a tab expands to is shrunk until it doesn't. If
+/// nothing overlaps, the ranges are empty and located at the end of `left` and the start of
+/// `right`.
+///
+/// # Panics
+/// Panics if `tab_width` is zero.
+#[inline]
+pub fn tab_overlap_indices(
+    left: &str,
+    right: &str,
+    tab_width: usize,
+) -> (Range<usize>, Range<usize>) {
+    assert!(tab_width != 0, "tab width must be non-zero");
+    let left_expanded = expand(left, tab_width);
+    let right_expanded = expand(right, tab_width);
+    let len = left_expanded.units.len();
+    left_expanded.overlap_indices_at_boundary(&right_expanded, |index| {
+        left_expanded.is_boundary(index) && right_expanded.is_boundary(len - index)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tab_overlap_indices;
+    use StrOverlap;
+
+    #[test]
+    fn tab_and_spaces() {
+        let left = "x\n\tfoo";
+        let right = "        foo bar";
+
+        assert_eq!(
+            left.overlap_end_tab_expanded_indices(right, 8),
+            (2..6, 0..11)
+        );
+        assert_eq!(
+            right.overlap_start_tab_expanded_indices(left, 8),
+            (0..11, 2..6)
+        );
+        assert_eq!(
+            left.overlap_end_tab_expanded_indices(right, 4),
+            (6..6, 0..0)
+        );
+    }
+
+    #[test]
+    fn tab_stop_depends_on_column() {
+        // After "a", a tab only reaches the next tab stop, three columns later.
+        assert_eq!(
+            "x\na\tb".overlap_end_tab_expanded_indices("a   b", 4),
+            (2..5, 0..5)
+        );
+        assert_eq!(
+            "x\na\tb".overlap_end_tab_expanded_indices("a    b", 4),
+            (5..5, 0..0)
+        );
+    }
+
+    #[test]
+    fn seam_within_tab() {
+        // The tab expands to three spaces, but `other` begins with only two.
+        assert_eq!(
+            "a\t".overlap_end_tab_expanded_indices("  b", 4),
+            (2..2, 0..0)
+        );
+        assert_eq!(
+            "a\t".overlap_end_tab_expanded_indices("   b", 4),
+            (1..2, 0..3)
+        );
+        // The tab in `other` expands to four spaces, but `self` ends with only three.
+        assert_eq!(
+            "x   ".overlap_end_tab_expanded_indices("\tb", 4),
+            (4..4, 0..0)
+        );
+        assert_eq!(
+            "x    ".overlap_end_tab_expanded_indices("\tb", 4),
+            (1..5, 0..1)
+        );
+    }
+
+    #[test]
+    fn mixed_tabs_then_spaces() {
+        let left = "}\n\t  return";
+        let right = "      return x";
+
+        assert_eq!(
+            left.overlap_end_tab_expanded_indices(right, 4),
+            (2..11, 0..12)
+        );
+        assert_eq!(&left[2..11], "\t  return");
+        assert_eq!(&right[0..12], "      return");
+    }
+
+    #[test]
+    fn mixed_spaces_then_tab() {
+        // Two spaces followed by a tab still only reach the first tab stop.
+        assert_eq!(
+            "  \treturn".overlap_end_tab_expanded_indices("\treturn;", 4),
+            (0..9, 0..7)
+        );
+        assert_eq!(
+            "\t  \treturn".overlap_end_tab_expanded_indices("\t\treturn;", 4),
+            (0..10, 0..8)
+        );
+    }
+
+    #[test]
+    fn tabs_on_both_sides() {
+        assert_eq!(
+            "a\n\t\tb".overlap_end_tab_expanded_indices("\t\tb", 2),
+            (2..5, 0..3)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(tab_overlap_indices("abc", "def", 4), (3..3, 0..0));
+        assert_eq!(tab_overlap_indices("", "\t", 4), (0..0, 0..0));
+        assert_eq!(tab_overlap_indices("\t", "", 4), (1..1, 0..0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_tab_width() {
+        let _ = tab_overlap_indices("a\t", "\tb", 0);
+    }
+}
@@ -0,0 +1,175 @@
+//! Overlaps of strings split into tokens by a user-provided tokenizer.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+use slice_overlap_index;
+
+/// Returns the range of `token` within `s`.
+///
+/// # Panics
+/// Panics if `token` is not a substring of `s`.
+#[inline]
+fn token_range(s: &str, token: &str) -> Range<usize> {
+    let start = (token.as_ptr() as usize).wrapping_sub(s.as_ptr() as usize);
+    assert!(
+        start <= s.len() && token.len() <= s.len() - start,
+        "tokenizer returned a token that is not a substring of its input"
+    );
+    start..(start + token.len())
+}
+
+/// Returns the range of `left` spanned by the tokens at its end that are equal to the tokens at
+/// the start of `right`.
+///
+/// Both strings are split using `tokenizer`, which must return substrings of the string it is
+/// given, in order. The range begins at the start of the first overlapping token and ends at the
+/// end of the last one, so any text between the tokens is included, but text before the first or
+/// after the last is not. If no tokens overlap, the range is empty and located at the end of
+/// `left`.
+///
+/// # Panics
+/// Panics if `tokenizer` returns a token of `left` that is not a substring of `left`.
+#[inline]
+pub fn token_overlap_range<'a, F, I>(left: &'a str, right: &'a str, tokenizer: F) -> Range<usize>
+where
+    F: Fn(&'a str) -> I,
+    I: Iterator<Item = &'a str>,
+{
+    let left_ranges: Vec<Range<usize>> = tokenizer(left)
+        .map(|token| token_range(left, token))
+        .collect();
+    let left_tokens: Vec<&str> = left_ranges
+        .iter()
+        .map(|range| &left[range.clone()])
+        .collect();
+    let right_tokens: Vec<&str> = tokenizer(right).collect();
+    let index = slice_overlap_index(&left_tokens, &right_tokens);
+    if index == left_ranges.len() {
+        left.len()..left.len()
+    } else {
+        left_ranges[index].start..left_ranges[left_ranges.len() - 1].end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::{IntoIter, Vec};
+    use {Overlap, StrOverlap};
+
+    /// Splits `s` into runs of alphanumeric characters and individual punctuation characters,
+    /// skipping whitespace.
+    fn words_and_punctuation(s: &str) -> IntoIter<&str> {
+        let mut tokens = Vec::new();
+        let mut word_start = None;
+        for (index, c) in s.char_indices() {
+            if c.is_alphanumeric() {
+                if word_start.is_none() {
+                    word_start = Some(index);
+                }
+                continue;
+            }
+            if let Some(start) = word_start.take() {
+                tokens.push(&s[start..index]);
+            }
+            if !c.is_whitespace() {
+                tokens.push(&s[index..(index + c.len_utf8())]);
+            }
+        }
+        if let Some(start) = word_start {
+            tokens.push(&s[start..]);
+        }
+        tokens.into_iter()
+    }
+
+    #[test]
+    fn whitespace_tokens() {
+        assert_eq!(
+            "foo bar baz".overlap_end_tokens("bar  baz qux", |s| s.split_whitespace()),
+            "bar baz"
+        );
+    }
+
+    #[test]
+    fn multiple_spaces_in_self() {
+        // The whitespace between overlapping tokens is kept, but not the whitespace after them.
+        assert_eq!(
+            "  foo  bar   baz  ".overlap_end_tokens("bar baz qux", |s| s.split_whitespace()),
+            "bar   baz"
+        );
+    }
+
+    #[test]
+    fn partial_tokens_do_not_overlap() {
+        assert_eq!(
+            "foo bar".overlap_end_tokens("ar baz", |s| s.split_whitespace()),
+            ""
+        );
+        assert_eq!("foo bar".overlap_end("ar baz"), "ar");
+    }
+
+    #[test]
+    fn no_tokens() {
+        assert_eq!("".overlap_end_tokens("foo", |s| s.split_whitespace()), "");
+        assert_eq!(
+            "foo ".overlap_end_tokens("   ", |s| s.split_whitespace()),
+            ""
+        );
+    }
+
+    #[test]
+    fn empty_overlap_at_end() {
+        let s = "foo bar";
+        let overlap = s.overlap_end_tokens("baz", |s| s.split_whitespace());
+
+        assert_eq!(overlap, "");
+        assert_eq!(overlap.as_ptr() as usize, s.as_ptr() as usize + s.len());
+    }
+
+    #[test]
+    fn punctuation_tokens() {
+        assert_eq!(
+            "Hello, world!".overlap_end_tokens("world ! Goodbye", words_and_punctuation),
+            "world!"
+        );
+        assert_eq!(
+            "x a,b".overlap_end_tokens("a , b, c", words_and_punctuation),
+            "a,b"
+        );
+    }
+
+    #[test]
+    fn punctuation_does_not_match_words() {
+        assert_eq!(
+            "say hi.".overlap_end_tokens("hi there", words_and_punctuation),
+            ""
+        );
+        assert_eq!(
+            "say hi.".overlap_end_tokens("hi. there", words_and_punctuation),
+            "hi."
+        );
+    }
+
+    #[test]
+    fn repeated_tokens() {
+        assert_eq!(
+            "a a a".overlap_end_tokens("a  a b", |s| s.split_whitespace()),
+            "a a"
+        );
+    }
+
+    #[test]
+    fn multi_byte_tokens() {
+        assert_eq!(
+            "café, naïve".overlap_end_tokens("naïve résumé", words_and_punctuation),
+            "naïve"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not a substring")]
+    fn token_not_substring() {
+        let s = String::from("foo bar");
+        let _ = s.overlap_end_tokens("bar", |_| "bar".split(' '));
+    }
+}
@@ -0,0 +1,159 @@
+//! Overlaps of bytes compared through a translation table.
+//!
+//! Each byte is looked up in a 256-entry table, in the manner of `tr`, and two bytes are equal if
+//! they translate to the same byte. A single table can express ASCII case-insensitivity, mapping
+//! of one character set onto another, or treating whole classes of bytes as equal, and looking
+//! bytes up in it is cheaper and more predictable than calling an arbitrary closure.
+
+use slice_overlap_index_by;
+
+/// Returns the length in bytes of the overlap found at the start of `left` and the end of
+/// `right`, comparing bytes after translating them through `table`.
+///
+/// Two bytes `a` and `b` are compared as equal if `table[a] == table[b]`. The overlap is a prefix
+/// of `left`, but its bytes may differ from those of the suffix of `right` it was compared with.
+///
+/// # Example
+/// ```
+/// use str_overlap::overlap_start_translated;
+///
+/// // Translate ASCII uppercase letters to lowercase, and every other byte to itself.
+/// let mut table = [0; 256];
+/// for (byte, entry) in table.iter_mut().enumerate() {
+///     *entry = byte as u8;
+/// }
+/// for byte in b'A'..(b'Z' + 1) {
+///     table[byte as usize] = byte | 0x20;
+/// }
+///
+/// assert_eq!(overlap_start_translated(b"BCD", b"abc", &table), 2);
+/// ```
+#[inline]
+#[must_use]
+pub fn overlap_start_translated(left: &[u8], right: &[u8], table: &[u8; 256]) -> usize {
+    right.len()
+        - slice_overlap_index_by(right, left, |&a, &b| table[a as usize] == table[b as usize])
+}
+
+/// Returns the length in bytes of the overlap found at the end of `left` and the start of
+/// `right`, comparing bytes after translating them through `table`.
+///
+/// Two bytes `a` and `b` are compared as equal if `table[a] == table[b]`. The overlap is a suffix
+/// of `left`, but its bytes may differ from those of the prefix of `right` it was compared with.
+///
+/// # Example
+/// ```
+/// use str_overlap::overlap_end_translated;
+///
+/// // Translate ASCII uppercase letters to lowercase, and every other byte to itself.
+/// let mut table = [0; 256];
+/// for (byte, entry) in table.iter_mut().enumerate() {
+///     *entry = byte as u8;
+/// }
+/// for byte in b'A'..(b'Z' + 1) {
+///     table[byte as usize] = byte | 0x20;
+/// }
+///
+/// assert_eq!(overlap_end_translated(b"ABC", b"bcd", &table), 2);
+/// ```
+#[inline]
+#[must_use]
+pub fn overlap_end_translated(left: &[u8], right: &[u8], table: &[u8; 256]) -> usize {
+    left.len()
+        - slice_overlap_index_by(left, right, |&a, &b| table[a as usize] == table[b as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overlap_end_translated, overlap_start_translated};
+    use Overlap;
+
+    /// Returns the table mapping every byte to itself.
+    fn identity() -> [u8; 256] {
+        let mut table = [0; 256];
+        for (byte, entry) in table.iter_mut().enumerate() {
+            *entry = byte as u8;
+        }
+        table
+    }
+
+    /// Returns the table mapping ASCII uppercase letters to lowercase.
+    fn ascii_lowercase() -> [u8; 256] {
+        let mut table = identity();
+        for byte in b'A'..(b'Z' + 1) {
+            table[byte as usize] = byte | 0x20;
+        }
+        table
+    }
+
+    #[test]
+    fn ascii_case_insensitive() {
+        let table = ascii_lowercase();
+
+        assert_eq!(overlap_end_translated(b"xABC", b"abcd", &table), 3);
+        assert_eq!(overlap_start_translated(b"abcd", b"xABC", &table), 3);
+        // Non-ASCII bytes are translated to themselves.
+        assert_eq!(overlap_end_translated(b"x\xC4", b"\xE4y", &table), 0);
+    }
+
+    #[test]
+    fn charset_normalized() {
+        // Latin-1 "é" (0xE9) is transliterated to "e", and all ASCII control characters are
+        // translated to spaces.
+        let mut table = identity();
+        table[0xE9] = b'e';
+        for entry in &mut table[..0x20] {
+            *entry = b' ';
+        }
+        table[0x7F] = b' ';
+
+        assert_eq!(
+            overlap_end_translated(b"a caf\xE9\t", b"cafe\rbar", &table),
+            5
+        );
+        assert_eq!(
+            overlap_start_translated(b"cafe\rbar", b"a caf\xE9\t", &table),
+            5
+        );
+    }
+
+    #[test]
+    fn identity_matches_overlap() {
+        let table = identity();
+        let pairs: [(&[u8], &[u8]); 4] = [
+            (&b"abc"[..], &b"bcd"[..]),
+            (&b"abcab"[..], &b"abcabd"[..]),
+            (&b"abc"[..], &b"def"[..]),
+            (&b"\xFFa\xFF"[..], &b"a\xFFb"[..]),
+        ];
+        for &(left, right) in &pairs {
+            assert_eq!(
+                overlap_end_translated(left, right, &table),
+                left.overlap_end_len(right)
+            );
+            assert_eq!(
+                overlap_start_translated(left, right, &table),
+                left.overlap_start_len(right)
+            );
+        }
+    }
+
+    #[test]
+    fn constant_table() {
+        // Every byte is equal to every other, so the overlap is as long as the shorter slice.
+        let table = [0; 256];
+
+        assert_eq!(overlap_end_translated(b"abc", b"xy", &table), 2);
+        assert_eq!(overlap_end_translated(b"ab", b"xyz", &table), 2);
+        assert_eq!(overlap_start_translated(b"abc", b"xy", &table), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let table = identity();
+
+        assert_eq!(overlap_end_translated(b"", b"abc", &table), 0);
+        assert_eq!(overlap_end_translated(b"abc", b"", &table), 0);
+        assert_eq!(overlap_start_translated(b"", b"", &table), 0);
+    }
+}
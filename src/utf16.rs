@@ -0,0 +1,195 @@
+//! Overlap support for UTF-16 encoded text.
+//!
+//! UTF-16 text is commonly stored as a slice of `u16` code units. While [`Overlap`] is implemented
+//! on `[u16]`, it compares code units without regard for their meaning, and can therefore return
+//! an overlap that splits a surrogate pair. The functions in this module avoid doing so.
+//!
+//! Unpaired surrogates are allowed, and are compared like any other code unit. This means
+//! potentially ill-formed UTF-16 (sometimes called WTF-16) can be used as well.
+//!
+//! [`Overlap`]: ::Overlap
+
+use slice_overlap_index_at_boundary;
+
+/// Returns whether `code_unit` is a leading (high) surrogate.
+#[inline]
+fn is_leading_surrogate(code_unit: u16) -> bool {
+    code_unit & 0xFC00 == 0xD800
+}
+
+/// Returns whether `code_unit` is a trailing (low) surrogate.
+#[inline]
+fn is_trailing_surrogate(code_unit: u16) -> bool {
+    code_unit & 0xFC00 == 0xDC00
+}
+
+/// Returns whether splitting `code_units` at `index` would not split a surrogate pair.
+#[inline]
+pub fn is_boundary(code_units: &[u16], index: usize) -> bool {
+    index == 0
+        || index == code_units.len()
+        || !(is_leading_surrogate(code_units[index - 1])
+            && is_trailing_surrogate(code_units[index]))
+}
+
+/// Finds the index at which two UTF-16 slices overlap, without splitting surrogate pairs in either
+/// slice.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+#[inline]
+pub fn utf16_overlap_index(left: &[u16], right: &[u16]) -> usize {
+    slice_overlap_index_at_boundary(left, right, |index| {
+        is_boundary(left, index) && is_boundary(right, left.len() - index)
+    })
+}
+
+/// Returns the overlap found at the start of `left` and the end of `right`, without splitting any
+/// surrogate pairs.
+///
+/// This is the UTF-16 equivalent of [`Overlap::overlap_start`]. If the largest overlap of code
+/// units would split a surrogate pair in either slice, the overlap shrinks to the next boundary
+/// that doesn't.
+///
+/// # Example
+/// ```
+/// use str_overlap::utf16_overlap_start;
+///
+/// let left: Vec<u16> = "bcd".encode_utf16().collect();
+/// let right: Vec<u16> = "abc".encode_utf16().collect();
+///
+/// assert_eq!(
+///     utf16_overlap_start(&left, &right),
+///     &*"bc".encode_utf16().collect::<Vec<_>>()
+/// );
+/// ```
+///
+/// [`Overlap::overlap_start`]: ::Overlap::overlap_start
+#[must_use]
+pub fn utf16_overlap_start<'a>(left: &'a [u16], right: &[u16]) -> &'a [u16] {
+    &left[..(right.len() - utf16_overlap_index(right, left))]
+}
+
+/// Returns the overlap found at the end of `left` and the start of `right`, without splitting any
+/// surrogate pairs.
+///
+/// This is the UTF-16 equivalent of [`Overlap::overlap_end`]. If the largest overlap of code units
+/// would split a surrogate pair in either slice, the overlap shrinks to the next boundary that
+/// doesn't.
+///
+/// # Example
+/// ```
+/// use str_overlap::utf16_overlap_end;
+///
+/// // "a😀" and "\u{DE00}b", where the second string begins with an unpaired trailing surrogate
+/// // matching the second half of the emoji.
+/// let left = [0x61, 0xD83D, 0xDE00];
+/// let right = [0xDE00, 0x62];
+///
+/// assert_eq!(utf16_overlap_end(&left, &right), []);
+/// ```
+///
+/// [`Overlap::overlap_end`]: ::Overlap::overlap_end
+#[must_use]
+pub fn utf16_overlap_end<'a>(left: &'a [u16], right: &[u16]) -> &'a [u16] {
+    &left[utf16_overlap_index(left, right)..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{utf16_overlap_end, utf16_overlap_start};
+    use Overlap;
+
+    #[test]
+    fn partial_overlap_start() {
+        assert_eq!(utf16_overlap_start(&[0x62, 0x63], &[0x61, 0x62]), [0x62]);
+    }
+
+    #[test]
+    fn partial_overlap_end() {
+        assert_eq!(utf16_overlap_end(&[0x61, 0x62], &[0x62, 0x63]), [0x62]);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(utf16_overlap_start(&[0x61, 0x62], &[0x63, 0x64]), []);
+        assert_eq!(utf16_overlap_end(&[0x61, 0x62], &[0x63, 0x64]), []);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(utf16_overlap_start(&[], &[0x61]), []);
+        assert_eq!(utf16_overlap_end(&[0x61], &[]), []);
+        assert_eq!(utf16_overlap_end(&[], &[]), []);
+    }
+
+    #[test]
+    fn surrogate_pair_overlap_end() {
+        // "a😀" and "😀b".
+        assert_eq!(
+            utf16_overlap_end(&[0x61, 0xD83D, 0xDE00], &[0xD83D, 0xDE00, 0x62]),
+            [0xD83D, 0xDE00]
+        );
+    }
+
+    #[test]
+    fn surrogate_pair_overlap_start() {
+        // "😀b" and "a😀".
+        assert_eq!(
+            utf16_overlap_start(&[0xD83D, 0xDE00, 0x62], &[0x61, 0xD83D, 0xDE00]),
+            [0xD83D, 0xDE00]
+        );
+    }
+
+    #[test]
+    fn does_not_split_surrogate_pair_in_left_end() {
+        let left = [0x61, 0xD83D, 0xDE00];
+        let right = [0xDE00, 0x62];
+
+        // Comparing code units alone would split the pair.
+        assert_eq!(left.overlap_end(&right), [0xDE00]);
+        assert_eq!(utf16_overlap_end(&left, &right), []);
+    }
+
+    #[test]
+    fn does_not_split_surrogate_pair_in_right_end() {
+        let left = [0x61, 0xD83D];
+        let right = [0xD83D, 0xDE00, 0x62];
+
+        // Comparing code units alone would split the pair.
+        assert_eq!(left.overlap_end(&right), [0xD83D]);
+        assert_eq!(utf16_overlap_end(&left, &right), []);
+    }
+
+    #[test]
+    fn does_not_split_surrogate_pair_in_left_start() {
+        let left = [0xD83D, 0x62];
+        let right = [0x61, 0xD83D, 0xDE00];
+
+        assert_eq!(utf16_overlap_start(&right, &left), []);
+        assert_eq!(utf16_overlap_start(&left, &[0x61, 0xD83D]), [0xD83D]);
+    }
+
+    #[test]
+    fn shrinks_to_shorter_overlap() {
+        // The longest overlap would split the pair, but a shorter one is still valid.
+        let left = [0xD83D, 0xDE00, 0xDE00];
+        let right = [0xDE00, 0xDE00, 0x62];
+
+        assert_eq!(left.overlap_end(&right), [0xDE00, 0xDE00]);
+        assert_eq!(utf16_overlap_end(&left, &right), [0xDE00]);
+    }
+
+    #[test]
+    fn unpaired_surrogates() {
+        // Unpaired surrogates can be split at freely.
+        assert_eq!(
+            utf16_overlap_end(&[0x61, 0xDE00, 0xDE00], &[0xDE00, 0xDE00, 0x62]),
+            [0xDE00, 0xDE00]
+        );
+        assert_eq!(
+            utf16_overlap_end(&[0x61, 0xD83D], &[0xD83D, 0x62]),
+            [0xD83D]
+        );
+    }
+}
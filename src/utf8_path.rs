@@ -0,0 +1,198 @@
+//! Overlap support for UTF-8 filesystem paths from the [`camino`] crate.
+//!
+//! These functions behave the same as [`path_overlap_end`] and [`join_dedup`], comparing paths by
+//! their components rather than by their bytes.
+//!
+//! [`join_dedup`]: ::join_dedup
+//! [`path_overlap_end`]: ::path_overlap_end
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+/// Returns the overlap found at the end of `left` and the start of `right`, compared by component.
+///
+/// The returned path is a sub-path of `left`, made up of the trailing components of `left` that
+/// are equal to the leading components of `right`. If no components overlap, an empty path is
+/// returned. Components are compared the same way as in [`path_overlap_end`].
+///
+/// This function is only available when the `camino` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate camino;
+/// extern crate str_overlap;
+///
+/// use camino::Utf8Path;
+/// use str_overlap::utf8_path_overlap_end;
+///
+/// # fn main() {
+/// assert_eq!(
+///     utf8_path_overlap_end(Utf8Path::new("/var/log/app"), Utf8Path::new("log/app/today.txt")),
+///     Utf8Path::new("log/app")
+/// );
+/// # }
+/// ```
+///
+/// [`path_overlap_end`]: ::path_overlap_end
+#[must_use]
+pub fn utf8_path_overlap_end<'a>(left: &'a Utf8Path, right: &Utf8Path) -> &'a Utf8Path {
+    let mut components = left.components();
+    loop {
+        let suffix = components.as_path();
+        if suffix.components().count() <= right.components().count()
+            && suffix
+                .components()
+                .zip(right.components())
+                .all(|(left_component, right_component)| left_component == right_component)
+        {
+            return suffix;
+        }
+        // The empty suffix always matches, so this will never exhaust the components before
+        // returning.
+        components.next();
+    }
+}
+
+/// Joins `right` onto `left`, removing the components that overlap.
+///
+/// The overlap is found using [`utf8_path_overlap_end`], and only the components of `right`
+/// following the overlap are joined onto `left`. If no components overlap, this is the same as
+/// [`Utf8Path::join()`].
+///
+/// This function is only available when the `camino` feature is enabled.
+///
+/// # Example
+/// ```
+/// extern crate camino;
+/// extern crate str_overlap;
+///
+/// use camino::{Utf8Path, Utf8PathBuf};
+/// use str_overlap::utf8_join_dedup;
+///
+/// # fn main() {
+/// assert_eq!(
+///     utf8_join_dedup(Utf8Path::new("/var/log/app"), Utf8Path::new("log/app/today.txt")),
+///     Utf8PathBuf::from("/var/log/app/today.txt")
+/// );
+/// # }
+/// ```
+#[must_use]
+pub fn utf8_join_dedup(left: &Utf8Path, right: &Utf8Path) -> Utf8PathBuf {
+    let mut remaining = right.components();
+    for _ in utf8_path_overlap_end(left, right).components() {
+        remaining.next();
+    }
+
+    let remaining = remaining.as_path();
+    if remaining.as_str().is_empty() {
+        left.to_path_buf()
+    } else {
+        left.join(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{utf8_join_dedup, utf8_path_overlap_end};
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    #[test]
+    fn partial_overlap() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("a/b/c"), Utf8Path::new("b/c/d")),
+            Utf8Path::new("b/c")
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("a/b/c"), Utf8Path::new("d/e/f")),
+            Utf8Path::new("")
+        );
+    }
+
+    #[test]
+    fn no_partial_component_overlap() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("ab/cd"), Utf8Path::new("b/cd/ef")),
+            Utf8Path::new("")
+        );
+    }
+
+    #[test]
+    fn separators_normalized() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("a//b/./c/"), Utf8Path::new("b/c//d")),
+            Utf8Path::new("b/c")
+        );
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("/a/b"), Utf8Path::new("/a/b/c")),
+            Utf8Path::new("/a/b")
+        );
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new("a/b"), Utf8Path::new("/b/c")),
+            Utf8Path::new("")
+        );
+    }
+
+    #[test]
+    fn join_dedup_partial_overlap() {
+        assert_eq!(
+            utf8_join_dedup(
+                Utf8Path::new("/var/log/app"),
+                Utf8Path::new("log/app/today.txt")
+            ),
+            Utf8PathBuf::from("/var/log/app/today.txt")
+        );
+    }
+
+    #[test]
+    fn join_dedup_full_overlap() {
+        assert_eq!(
+            utf8_join_dedup(Utf8Path::new("a/b/c"), Utf8Path::new("b/c")),
+            Utf8PathBuf::from("a/b/c")
+        );
+    }
+
+    #[test]
+    fn join_dedup_no_overlap() {
+        assert_eq!(
+            utf8_join_dedup(Utf8Path::new("a/b"), Utf8Path::new("c/d")),
+            Utf8PathBuf::from("a/b/c/d")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn windows_prefix() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new(r"C:\a\b"), Utf8Path::new(r"a\b\c")),
+            Utf8Path::new(r"a\b")
+        );
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new(r"C:\a\b"), Utf8Path::new(r"D:\a\b")),
+            Utf8Path::new("")
+        );
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new(r"C:\a\b"), Utf8Path::new(r"C:\a\b\c")),
+            Utf8Path::new(r"C:\a\b")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn windows_mixed_separators() {
+        assert_eq!(
+            utf8_path_overlap_end(Utf8Path::new(r"C:\a\b/c"), Utf8Path::new(r"b\c/d")),
+            Utf8Path::new("b/c")
+        );
+        assert_eq!(
+            utf8_join_dedup(Utf8Path::new(r"C:\a\b"), Utf8Path::new("b/c")),
+            Utf8PathBuf::from(r"C:\a\b\c")
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Overlap support for ring buffers.
+//!
+//! A [`VecDeque`] stores its elements in a ring buffer, which may wrap around the end of its
+//! allocation. Its contents are therefore not necessarily contiguous, and are instead exposed as
+//! two slices by [`VecDeque::as_slices()`]. The functions in this module find overlaps across both
+//! slices directly, including overlaps that straddle the point where they meet, without first
+//! making the contents contiguous.
+//!
+//! Since a contiguous slice of the overlap may not exist, these functions return the length of
+//! the overlap instead.
+//!
+//! [`VecDeque`]: alloc::collections::VecDeque
+//! [`VecDeque::as_slices()`]: alloc::collections::VecDeque::as_slices
+
+use alloc::collections::VecDeque;
+use core::cmp;
+use {failure_table, kmp_matched_len};
+
+/// Returns the length of the overlap found at the end of the concatenation of `front` and `back`,
+/// and the start of `right`.
+///
+/// This is intended for use with the two slices returned by [`VecDeque::as_slices()`], but works
+/// with any two slices whose concatenation should be searched. The overlap may lie entirely within
+/// `back`, or begin in `front` and continue through all of `back`.
+///
+/// This function is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// use str_overlap::split_overlap_end_len;
+///
+/// assert_eq!(split_overlap_end_len(b"ab", b"cd", b"bcde"), 3);
+/// ```
+///
+/// [`VecDeque::as_slices()`]: alloc::collections::VecDeque::as_slices
+#[must_use]
+pub fn split_overlap_end_len<T>(front: &[T], back: &[T], right: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    let mut eq = |left_element: &T, right_element: &T| left_element == right_element;
+
+    // Suffixes of the left side that are longer than `right` can never overlap, so they are
+    // skipped.
+    let left_len = front.len() + back.len();
+    let len = cmp::min(left_len, right.len());
+    let pattern = &right[..len];
+    let failure = failure_table(pattern, &mut eq);
+    kmp_matched_len(
+        front.iter().chain(back.iter()).skip(left_len - len),
+        pattern,
+        &failure,
+        &mut eq,
+    )
+}
+
+/// Returns the length of the overlap found at the end of `left` and the start of `right`.
+///
+/// The contents of `left` do not need to be contiguous. See [`split_overlap_end_len`] for
+/// details.
+///
+/// This function is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use str_overlap::vec_deque_overlap_end_len;
+///
+/// let mut left = VecDeque::with_capacity(4);
+/// left.extend(b"xxab");
+/// left.drain(..2);
+/// // These elements wrap around to the start of the ring buffer.
+/// left.extend(b"cd");
+///
+/// assert_eq!(vec_deque_overlap_end_len(&left, b"bcde"), 3);
+/// ```
+#[must_use]
+pub fn vec_deque_overlap_end_len<T>(left: &VecDeque<T>, right: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    let (front, back) = left.as_slices();
+    split_overlap_end_len(front, back, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_overlap_end_len, vec_deque_overlap_end_len};
+    use alloc::collections::VecDeque;
+    use Overlap;
+
+    /// Creates a `VecDeque` containing `elements`, whose first `front_len` elements are stored at
+    /// the end of its ring buffer and whose remaining elements wrap around to its start.
+    fn wrapped(elements: &[u8], front_len: usize) -> VecDeque<u8> {
+        let mut deque = VecDeque::with_capacity(elements.len());
+        let capacity = deque.capacity();
+        for _ in 0..(capacity - front_len) {
+            deque.push_back(0);
+        }
+        for _ in 0..(capacity - front_len) {
+            deque.pop_front();
+        }
+        deque.extend(elements.iter().cloned());
+        deque
+    }
+
+    #[test]
+    fn overlap_within_back() {
+        assert_eq!(split_overlap_end_len(b"abc", b"def", b"efg"), 2);
+    }
+
+    #[test]
+    fn overlap_within_front() {
+        // An overlap must be a suffix of the whole left side, so it can only lie within `front`
+        // if `back` is empty.
+        assert_eq!(split_overlap_end_len(b"abc", b"", b"bcd"), 2);
+        assert_eq!(split_overlap_end_len(b"abc", b"x", b"bcd"), 0);
+    }
+
+    #[test]
+    fn overlap_straddles_split() {
+        assert_eq!(split_overlap_end_len(b"ab", b"cd", b"bcde"), 3);
+        assert_eq!(split_overlap_end_len(b"a", b"bcd", b"abcde"), 4);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(split_overlap_end_len(b"ab", b"cd", b"xyz"), 0);
+        assert_eq!(split_overlap_end_len(b"", b"", b"xyz"), 0);
+        assert_eq!(split_overlap_end_len(b"ab", b"cd", b""), 0);
+    }
+
+    #[test]
+    fn matches_contiguous_overlap() {
+        let left = b"abaababa";
+        let rights: [&[u8]; 5] = [b"abab", b"babaa", b"aba", b"abaababab", b"c"];
+        for split in 0..(left.len() + 1) {
+            let (front, back) = left.split_at(split);
+            for right in &rights {
+                assert_eq!(
+                    split_overlap_end_len(front, back, right),
+                    left.overlap_end_len(right)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn vec_deque_wrapped() {
+        for front_len in 0..5 {
+            let deque = wrapped(b"abcd", front_len);
+
+            assert_eq!(vec_deque_overlap_end_len(&deque, b"bcde"), 3);
+            assert_eq!(vec_deque_overlap_end_len(&deque, b"abcd"), 4);
+            assert_eq!(vec_deque_overlap_end_len(&deque, b"e"), 0);
+        }
+    }
+
+    #[test]
+    fn vec_deque_is_actually_wrapped() {
+        let deque = wrapped(b"abcd", 2);
+        let (front, back) = deque.as_slices();
+
+        assert!(!back.is_empty());
+        assert_eq!(front.len() + back.len(), 4);
+    }
+}
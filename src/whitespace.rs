@@ -0,0 +1,125 @@
+//! Overlaps of strings that treat every run of whitespace as equal.
+//!
+//! Text from different sources often differs only in incidental whitespace, such as a wrapped line
+//! or a collapsed double space. Comparing each run of whitespace as a single unit lets these
+//! overlap, while still reporting the overlap as ranges within the original strings.
+
+use core::ops::Range;
+use mapped::Mapped;
+
+/// Splits `s` into units, where each unit is either a single non-whitespace character or a
+/// maximal run of whitespace, with `None` standing for a run of whitespace.
+#[inline]
+fn collapse(s: &str) -> Mapped<Option<char>> {
+    let mut collapsed = Mapped::new(s.len());
+    for (index, c) in s.char_indices() {
+        let end = index + c.len_utf8();
+        if c.is_whitespace() {
+            if let Some(&None) = collapsed.units.last() {
+                // Extend the current run of whitespace.
+                let last = collapsed.ranges.len() - 1;
+                collapsed.ranges[last].end = end;
+                continue;
+            }
+            collapsed.push(None, index..end);
+        } else {
+            collapsed.push(Some(c), index..end);
+        }
+    }
+    collapsed
+}
+
+/// Returns the byte ranges of the overlap found at the end of `left` and the start of `right`
+/// when every run of whitespace is treated as equal, within `left` and within `right`
+/// respectively.
+///
+/// Runs of whitespace are always matched whole, so a run at the bound of the overlap is included
+/// entirely. If nothing overlaps, the ranges are empty and located at the end of `left` and the
+/// start of `right`.
+#[inline]
+pub fn whitespace_overlap_indices(left: &str, right: &str) -> (Range<usize>, Range<usize>) {
+    collapse(left).overlap_indices(&collapse(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::whitespace_overlap_indices;
+    use StrOverlap;
+
+    #[test]
+    fn collapsed_spaces() {
+        assert_eq!(
+            "a b  c".overlap_end_whitespace_indices("b c d"),
+            (2..6, 0..3)
+        );
+        assert_eq!(
+            "b c d".overlap_start_whitespace_indices("a b  c"),
+            (0..3, 2..6)
+        );
+    }
+
+    #[test]
+    fn wrapped_lines() {
+        let left = "the quick\nbrown";
+        let right = "quick brown fox";
+
+        assert_eq!(left.overlap_end_whitespace_indices(right), (4..15, 0..11));
+        assert_eq!(&left[4..15], "quick\nbrown");
+        assert_eq!(&right[0..11], "quick brown");
+    }
+
+    #[test]
+    fn seam_within_run() {
+        // `left` was cut partway through the run of spaces that `right` contains whole.
+        let left = "x a  ";
+        let right = "a    b";
+
+        assert_eq!(left.overlap_end_whitespace_indices(right), (2..5, 0..5));
+        assert_eq!(right.overlap_start_whitespace_indices(left), (0..5, 2..5));
+    }
+
+    #[test]
+    fn leading_run() {
+        // The run at the start of `right` matches the run before "a" in `left`.
+        assert_eq!("x \ta".overlap_end_whitespace_indices(" a b"), (1..4, 0..2));
+        // A run only matches another run, never nothing at all.
+        assert_eq!("xa".overlap_end_whitespace_indices(" a b"), (2..2, 0..0));
+    }
+
+    #[test]
+    fn trailing_run() {
+        assert_eq!("x a\n".overlap_end_whitespace_indices("a  b"), (2..4, 0..3));
+        assert_eq!("x a\n".overlap_end_whitespace_indices("ab"), (4..4, 0..0));
+    }
+
+    #[test]
+    fn only_whitespace() {
+        assert_eq!("a  ".overlap_end_whitespace_indices(" \nb"), (1..3, 0..2));
+        assert_eq!("  ".overlap_end_whitespace_indices(" "), (0..2, 0..1));
+    }
+
+    #[test]
+    fn unicode_whitespace() {
+        // U+3000 IDEOGRAPHIC SPACE and U+00A0 NO-BREAK SPACE are both whitespace.
+        assert_eq!(
+            "日本\u{3000}語".overlap_end_whitespace_indices("本\u{a0}語だ"),
+            (3..12, 0..8)
+        );
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(whitespace_overlap_indices("abc", "def"), (3..3, 0..0));
+        assert_eq!(whitespace_overlap_indices("", "a"), (0..0, 0..0));
+        assert_eq!(whitespace_overlap_indices("a", ""), (1..1, 0..0));
+    }
+
+    #[test]
+    fn exact_matches_unchanged() {
+        assert_eq!(
+            "abcab".overlap_end_whitespace_indices("abcabd"),
+            (0..5, 0..5)
+        );
+        assert_eq!("ab".overlap_end_whitespace_indices("b"), (1..2, 0..1));
+    }
+}
@@ -0,0 +1,562 @@
+//! Overlap support for wide strings from the [`widestring`] crate.
+//!
+//! [`U32Str`] contains UTF-32 code units, so overlaps are found by comparing code units directly.
+//! [`U16Str`] contains UTF-16 code units, and its overlaps are found the same way as in
+//! [`utf16_overlap_end`], never splitting a surrogate pair.
+//!
+//! The NUL-terminated [`U16CStr`] and [`U32CStr`] have the same problem as [`CStr`]: a prefix of
+//! them is not NUL-terminated. Their overlaps are therefore provided through the separate
+//! [`WideCStrOverlap`] trait.
+//!
+//! [`CStr`]: https://doc.rust-lang.org/std/ffi/struct.CStr.html
+//! [`utf16_overlap_end`]: ::utf16_overlap_end
+//! [`widestring`]: https://docs.rs/widestring
+
+use utf16::{is_boundary, utf16_overlap_index};
+use widestring::{U16CStr, U16Str, U32CStr, U32Str};
+use {Direction, Overlap};
+
+/// Overlap methods for UTF-16 wide string slices.
+///
+/// Overlaps never split a surrogate pair in either string. Unpaired surrogates are compared like
+/// any other code unit. This implementation is only available when the `widestring` feature is
+/// enabled.
+impl Overlap for U16Str {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        U16Str::from_slice(&self.as_slice()[..self.overlap_start_len(other)])
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        U16Str::from_slice(&self.as_slice()[(self.len() - self.overlap_end_len(other))..])
+    }
+
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        other.len() - utf16_overlap_index(other.as_slice(), self.as_slice())
+    }
+
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.len() - utf16_overlap_index(self.as_slice(), other.as_slice())
+    }
+
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        self.overlap_start_len(other) != 0
+    }
+
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        self.overlap_end_len(other) != 0
+    }
+
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        self.overlap_start_len(other) == 0
+    }
+
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        self.overlap_end_len(other) == 0
+    }
+
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        let overlap = self.overlap_start(other);
+        if overlap.is_empty() {
+            None
+        } else {
+            Some(overlap)
+        }
+    }
+
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        let overlap = self.overlap_end(other);
+        if overlap.is_empty() {
+            None
+        } else {
+            Some(overlap)
+        }
+    }
+
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        let len = self.overlap_start_len(other);
+        U16Str::from_slice(&self.as_slice()[..(if len < min_len { 0 } else { len })])
+    }
+
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        let len = self.overlap_end_len(other);
+        U16Str::from_slice(&self.as_slice()[(self.len() - if len < min_len { 0 } else { len })..])
+    }
+
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        let code_units = self.as_slice();
+        let mut len = self.overlap_start_len(other);
+        if len > max_len {
+            len = max_len;
+            // Truncating can only split a surrogate pair by a single code unit.
+            if !is_boundary(code_units, len) {
+                len -= 1;
+            }
+        }
+        U16Str::from_slice(&code_units[..len])
+    }
+
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        let code_units = self.as_slice();
+        let mut index = self.len() - self.overlap_end_len(other);
+        if self.len() - index > max_len {
+            index = self.len() - max_len;
+            // Truncating can only split a surrogate pair by a single code unit.
+            if !is_boundary(code_units, index) {
+                index += 1;
+            }
+        }
+        U16Str::from_slice(&code_units[index..])
+    }
+
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        U16Str::from_slice(&self.as_slice()[self.overlap_start_len(other)..])
+    }
+
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        U16Str::from_slice(&self.as_slice()[..(self.len() - self.overlap_end_len(other))])
+    }
+
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let (overlap, rest) = self.as_slice().split_at(self.overlap_start_len(other));
+        (U16Str::from_slice(overlap), U16Str::from_slice(rest))
+    }
+
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let (rest, overlap) = self
+            .as_slice()
+            .split_at(self.len() - self.overlap_end_len(other));
+        (U16Str::from_slice(rest), U16Str::from_slice(overlap))
+    }
+}
+
+/// Overlap methods for UTF-32 wide string slices.
+///
+/// Every code unit is a whole character, so these methods behave the same as the implementation
+/// on `[u32]`. This implementation is only available when the `widestring` feature is enabled.
+impl Overlap for U32Str {
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_start(other.as_slice()))
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_end(other.as_slice()))
+    }
+
+    #[inline]
+    fn overlap_start_len(&self, other: &Self) -> usize {
+        self.as_slice().overlap_start_len(other.as_slice())
+    }
+
+    #[inline]
+    fn overlap_end_len(&self, other: &Self) -> usize {
+        self.as_slice().overlap_end_len(other.as_slice())
+    }
+
+    #[inline]
+    fn overlap(&self, other: &Self) -> (&Self, &Self) {
+        (self.overlap_start(other), self.overlap_end(other))
+    }
+
+    #[inline]
+    fn has_overlap_start(&self, other: &Self) -> bool {
+        self.as_slice().has_overlap_start(other.as_slice())
+    }
+
+    #[inline]
+    fn has_overlap_end(&self, other: &Self) -> bool {
+        self.as_slice().has_overlap_end(other.as_slice())
+    }
+
+    #[inline]
+    fn overlap_start_is_empty(&self, other: &Self) -> bool {
+        self.as_slice().overlap_start_is_empty(other.as_slice())
+    }
+
+    #[inline]
+    fn overlap_end_is_empty(&self, other: &Self) -> bool {
+        self.as_slice().overlap_end_is_empty(other.as_slice())
+    }
+
+    #[inline]
+    fn try_overlap_start(&self, other: &Self) -> Option<&Self> {
+        self.as_slice()
+            .try_overlap_start(other.as_slice())
+            .map(U32Str::from_slice)
+    }
+
+    #[inline]
+    fn try_overlap_end(&self, other: &Self) -> Option<&Self> {
+        self.as_slice()
+            .try_overlap_end(other.as_slice())
+            .map(U32Str::from_slice)
+    }
+
+    #[inline]
+    fn overlap_start_min(&self, other: &Self, min_len: usize) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_start_min(other.as_slice(), min_len))
+    }
+
+    #[inline]
+    fn overlap_end_min(&self, other: &Self, min_len: usize) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_end_min(other.as_slice(), min_len))
+    }
+
+    #[inline]
+    fn overlap_start_max(&self, other: &Self, max_len: usize) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_start_max(other.as_slice(), max_len))
+    }
+
+    #[inline]
+    fn overlap_end_max(&self, other: &Self, max_len: usize) -> &Self {
+        U32Str::from_slice(self.as_slice().overlap_end_max(other.as_slice(), max_len))
+    }
+
+    #[inline]
+    fn max_overlap(&self, other: &Self) -> (&Self, Direction) {
+        let start = self.overlap_start(other);
+        let end = self.overlap_end(other);
+        if start.len() > end.len() {
+            (start, Direction::OtherThenSelf)
+        } else {
+            (end, Direction::SelfThenOther)
+        }
+    }
+
+    #[inline]
+    fn trim_overlap_start(&self, other: &Self) -> &Self {
+        U32Str::from_slice(self.as_slice().trim_overlap_start(other.as_slice()))
+    }
+
+    #[inline]
+    fn trim_overlap_end(&self, other: &Self) -> &Self {
+        U32Str::from_slice(self.as_slice().trim_overlap_end(other.as_slice()))
+    }
+
+    #[inline]
+    fn split_at_overlap_start(&self, other: &Self) -> (&Self, &Self) {
+        let (overlap, rest) = self.as_slice().split_at_overlap_start(other.as_slice());
+        (U32Str::from_slice(overlap), U32Str::from_slice(rest))
+    }
+
+    #[inline]
+    fn split_at_overlap_end(&self, other: &Self) -> (&Self, &Self) {
+        let (rest, overlap) = self.as_slice().split_at_overlap_end(other.as_slice());
+        (U32Str::from_slice(rest), U32Str::from_slice(overlap))
+    }
+}
+
+/// Provides methods for finding overlaps between NUL-terminated wide strings.
+///
+/// This is the wide string equivalent of [`CStrOverlap`]. Overlaps are found by comparing the
+/// code units excluding the terminating NUL, the same way as the implementations of [`Overlap`] on
+/// the matching unterminated wide string slices. This trait is made available by pulling it into
+/// scope:
+///
+/// ```
+/// use str_overlap::WideCStrOverlap;
+/// ```
+///
+/// This trait is only available when the `widestring` feature is enabled.
+///
+/// [`CStrOverlap`]: ::CStrOverlap
+pub trait WideCStrOverlap {
+    /// The unterminated wide string slice type, used for overlaps at the start of `self`.
+    type Str: ?Sized;
+
+    /// Returns the overlap found at the start of `self` and the end of `other`.
+    ///
+    /// Since the overlap is a prefix of `self`, it is not NUL-terminated, and is therefore
+    /// returned as an unterminated wide string slice.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate str_overlap;
+    /// extern crate widestring;
+    ///
+    /// use str_overlap::WideCStrOverlap;
+    /// use widestring::{u16cstr, u16str};
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16cstr!("bcd").overlap_start(u16cstr!("abc")), u16str!("bc"));
+    /// # }
+    /// ```
+    #[must_use]
+    fn overlap_start(&self, other: &Self) -> &Self::Str;
+
+    /// Returns the overlap found at the end of `self` and the start of `other`.
+    ///
+    /// Since the overlap is a suffix of `self`, it is returned as a NUL-terminated wide string
+    /// that shares the terminating NUL of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate str_overlap;
+    /// extern crate widestring;
+    ///
+    /// use str_overlap::WideCStrOverlap;
+    /// use widestring::u16cstr;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16cstr!("abc").overlap_end(u16cstr!("bcd")), u16cstr!("bc"));
+    /// # }
+    /// ```
+    #[must_use]
+    fn overlap_end(&self, other: &Self) -> &Self;
+}
+
+/// Converts `$slice`, a suffix of the code units backing a `$c_str` including its terminating
+/// NUL, back into a `$c_str`.
+///
+/// The code units are only validated when the `safe` feature is enabled.
+#[cfg(not(feature = "safe"))]
+macro_rules! from_slice_with_nul {
+    ($c_str:ident, $slice:expr) => {
+        unsafe { $c_str::from_slice_unchecked($slice) }
+    };
+}
+
+/// Converts `$slice`, a suffix of the code units backing a `$c_str` including its terminating
+/// NUL, back into a `$c_str`.
+///
+/// The code units are only validated when the `safe` feature is enabled.
+#[cfg(feature = "safe")]
+macro_rules! from_slice_with_nul {
+    ($c_str:ident, $slice:expr) => {
+        $c_str::from_slice($slice).unwrap()
+    };
+}
+
+impl WideCStrOverlap for U16CStr {
+    type Str = U16Str;
+
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &U16Str {
+        self.as_ustr().overlap_start(other.as_ustr())
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        let code_units = self.as_slice_with_nul();
+        let overlap_len = self.as_ustr().overlap_end_len(other.as_ustr());
+        // SAFETY: The slice is a suffix of `self`'s code units including the terminating NUL. It
+        // therefore ends with exactly one NUL and contains no interior NULs.
+        from_slice_with_nul!(U16CStr, &code_units[(code_units.len() - 1 - overlap_len)..])
+    }
+}
+
+impl WideCStrOverlap for U32CStr {
+    type Str = U32Str;
+
+    #[inline]
+    fn overlap_start(&self, other: &Self) -> &U32Str {
+        self.as_ustr().overlap_start(other.as_ustr())
+    }
+
+    #[inline]
+    fn overlap_end(&self, other: &Self) -> &Self {
+        let code_units = self.as_slice_with_nul();
+        let overlap_len = self.as_ustr().overlap_end_len(other.as_ustr());
+        // SAFETY: The slice is a suffix of `self`'s code units including the terminating NUL. It
+        // therefore ends with exactly one NUL and contains no interior NULs.
+        from_slice_with_nul!(U32CStr, &code_units[(code_units.len() - 1 - overlap_len)..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WideCStrOverlap;
+    use widestring::{u16cstr, u16str, u32cstr, u32str, U16CStr, U16Str};
+    use {Direction, Overlap};
+
+    #[test]
+    fn u16_partial_overlap_start() {
+        assert_eq!(u16str!("bcd").overlap_start(u16str!("abc")), u16str!("bc"));
+    }
+
+    #[test]
+    fn u16_partial_overlap_end() {
+        assert_eq!(u16str!("abc").overlap_end(u16str!("bcd")), u16str!("bc"));
+    }
+
+    #[test]
+    fn u16_no_overlap() {
+        assert_eq!(u16str!("abc").overlap_start(u16str!("def")), u16str!(""));
+        assert_eq!(u16str!("abc").overlap_end(u16str!("def")), u16str!(""));
+        assert!(u16str!("abc").try_overlap_end(u16str!("def")).is_none());
+    }
+
+    #[test]
+    fn u16_surrogate_pair_overlap() {
+        assert_eq!(u16str!("a😀").overlap_end(u16str!("😀b")), u16str!("😀"));
+        assert_eq!(u16str!("😀b").overlap_start(u16str!("a😀")), u16str!("😀"));
+    }
+
+    #[test]
+    fn u16_does_not_split_surrogate_pair() {
+        // "a😀" and "\u{DE00}b", where the second string begins with an unpaired trailing
+        // surrogate matching the second half of the emoji.
+        let left = U16Str::from_slice(&[0x61, 0xD83D, 0xDE00]);
+        let right = U16Str::from_slice(&[0xDE00, 0x62]);
+
+        assert_eq!(left.as_slice().overlap_end(right.as_slice()), [0xDE00]);
+        assert_eq!(left.overlap_end(right), u16str!(""));
+        assert_eq!(left.overlap_end_len(right), 0);
+        assert!(!left.has_overlap_end(right));
+        assert_eq!(right.overlap_start(left), u16str!(""));
+    }
+
+    #[test]
+    fn u16_min_overlap() {
+        assert_eq!(
+            u16str!("abc").overlap_end_min(u16str!("bcd"), 2),
+            u16str!("bc")
+        );
+        assert_eq!(
+            u16str!("abc").overlap_end_min(u16str!("bcd"), 3),
+            u16str!("")
+        );
+        assert_eq!(
+            u16str!("bcd").overlap_start_min(u16str!("abc"), 3),
+            u16str!("")
+        );
+    }
+
+    #[test]
+    fn u16_max_overlap_does_not_split_surrogate_pair() {
+        assert_eq!(
+            u16str!("a😀b").overlap_end_max(u16str!("😀bc"), 2),
+            u16str!("b")
+        );
+        assert_eq!(
+            u16str!("😀bc").overlap_start_max(u16str!("a😀b"), 2),
+            u16str!("😀")
+        );
+        assert_eq!(
+            u16str!("😀bc").overlap_start_max(u16str!("a😀b"), 1),
+            u16str!("")
+        );
+    }
+
+    #[test]
+    fn u32_overlap() {
+        assert_eq!(u32str!("bcd").overlap_start(u32str!("abc")), u32str!("bc"));
+        assert_eq!(u32str!("abc").overlap_end(u32str!("bcd")), u32str!("bc"));
+        assert_eq!(u32str!("a😀").overlap_end(u32str!("😀b")), u32str!("😀"));
+        assert_eq!(
+            u32str!("abc").overlap_end_max(u32str!("bcd"), 1),
+            u32str!("c")
+        );
+    }
+
+    #[test]
+    fn u16_c_str_overlap() {
+        assert_eq!(
+            u16cstr!("bcd").overlap_start(u16cstr!("abc")),
+            u16str!("bc")
+        );
+        assert_eq!(u16cstr!("abc").overlap_end(u16cstr!("bcd")), u16cstr!("bc"));
+        assert_eq!(u16cstr!("abc").overlap_end(u16cstr!("def")), u16cstr!(""));
+    }
+
+    #[test]
+    fn u16_c_str_overlap_end_shares_terminator() {
+        let c_str = u16cstr!("abc");
+        let overlap: &U16CStr = c_str.overlap_end(u16cstr!("bcd"));
+
+        assert_eq!(overlap.as_slice_with_nul(), [0x62, 0x63, 0]);
+        assert_eq!(overlap.as_ptr(), c_str.as_slice()[1..].as_ptr());
+    }
+
+    #[test]
+    fn u32_c_str_overlap() {
+        assert_eq!(
+            u32cstr!("bcd").overlap_start(u32cstr!("abc")),
+            u32str!("bc")
+        );
+        assert_eq!(u32cstr!("abc").overlap_end(u32cstr!("bcd")), u32cstr!("bc"));
+    }
+
+    #[test]
+    fn max_overlap() {
+        assert_eq!(
+            u16str!("c😀e").max_overlap(u16str!("abc😀")),
+            (u16str!("c😀"), Direction::OtherThenSelf)
+        );
+        assert_eq!(
+            u32str!("abc").max_overlap(u32str!("bcd")),
+            (u32str!("bc"), Direction::SelfThenOther)
+        );
+    }
+
+    #[test]
+    fn trim_overlap() {
+        assert_eq!(
+            u16str!("ab😀").trim_overlap_end(u16str!("😀c")),
+            u16str!("ab")
+        );
+        assert_eq!(
+            u16str!("😀c").trim_overlap_start(u16str!("ab😀")),
+            u16str!("c")
+        );
+        assert_eq!(
+            u32str!("abc").trim_overlap_end(u32str!("bcd")),
+            u32str!("a")
+        );
+        assert_eq!(
+            u32str!("abc").trim_overlap_start(u32str!("def")),
+            u32str!("abc")
+        );
+    }
+
+    #[test]
+    fn split_at_overlap() {
+        assert_eq!(
+            u16str!("ab😀").split_at_overlap_end(u16str!("😀c")),
+            (u16str!("ab"), u16str!("😀"))
+        );
+        assert_eq!(
+            u16str!("😀c").split_at_overlap_start(u16str!("ab😀")),
+            (u16str!("😀"), u16str!("c"))
+        );
+        assert_eq!(
+            u32str!("abc").split_at_overlap_end(u32str!("bcd")),
+            (u32str!("a"), u32str!("bc"))
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Overlaps of strings containing a wildcard character.
+
+/// Returns whether `a` and `b` match, where `wildcard` matches any character.
+#[inline]
+fn matches(a: char, b: char, wildcard: char) -> bool {
+    a == b || a == wildcard || b == wildcard
+}
+
+/// Finds the index at which two strings overlap, where `wildcard` in either string matches any
+/// single character in the other.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+///
+/// The overlap has the same number of characters in both strings, but since a wildcard may be
+/// matched with a character of a different encoded length, not necessarily the same number of
+/// bytes. Matching with a wildcard is not transitive, so every candidate overlap is compared
+/// separately. This takes quadratic time in the worst case.
+#[inline]
+#[must_use]
+pub fn wildcard_overlap_index(left: &str, right: &str, wildcard: char) -> usize {
+    left.char_indices()
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let mut right_chars = right.chars();
+            left[index..]
+                .chars()
+                .all(|left_char| match right_chars.next() {
+                    Some(right_char) => matches(left_char, right_char, wildcard),
+                    None => false,
+                })
+        })
+        .unwrap_or(left.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wildcard_overlap_index;
+    use {Overlap, StrOverlap};
+
+    #[test]
+    fn wildcard_in_self() {
+        assert_eq!("ab?".overlap_end("bcd"), "");
+        assert_eq!("ab?".overlap_end_wildcard("bcd", '?'), "b?");
+    }
+
+    #[test]
+    fn wildcard_in_other() {
+        assert_eq!("abc".overlap_end_wildcard("?cd", '?'), "bc");
+        assert_eq!("abc".overlap_end_wildcard("b?d", '?'), "bc");
+    }
+
+    #[test]
+    fn wildcards_in_both() {
+        assert_eq!("a?c".overlap_end_wildcard("?bcd", '?'), "a?c");
+        assert_eq!("??".overlap_end_wildcard("??", '?'), "??");
+    }
+
+    #[test]
+    fn only_wildcards() {
+        // A string of wildcards overlaps any string long enough.
+        assert_eq!("x???".overlap_end_wildcard("abc", '?'), "???");
+        assert_eq!("xyz".overlap_end_wildcard("??", '?'), "yz");
+    }
+
+    #[test]
+    fn longest_overlap_found() {
+        // Matching with a wildcard is not transitive, so no candidate may be skipped.
+        assert_eq!("a?a".overlap_end_wildcard("aba", '?'), "a?a");
+        assert_eq!("ab?b".overlap_end_wildcard("bab", '?'), "b?b");
+        assert_eq!("aab".overlap_end_wildcard("a?b?", '?'), "aab");
+    }
+
+    #[test]
+    fn other_wildcard_characters() {
+        assert_eq!("ab*".overlap_end_wildcard("b?d", '*'), "b*");
+        assert_eq!("ab?".overlap_end_wildcard("bcd", '*'), "");
+    }
+
+    #[test]
+    fn wildcards_at_seam() {
+        // The last character of `self` and the first of `other` are both wildcards.
+        assert_eq!("ab?".overlap_end_wildcard("?cd", '?'), "b?");
+        assert_eq!("abc?".overlap_end_wildcard("?xyz", '?'), "c?");
+        assert_eq!("x?".overlap_end_wildcard("?", '?'), "?");
+        // A wildcard at the seam doesn't extend the overlap past a mismatch.
+        assert_eq!("ab?".overlap_end_wildcard("c?d", '?'), "?");
+    }
+
+    #[test]
+    fn multi_byte() {
+        // A wildcard matches a single character of any encoded length.
+        assert_eq!("caf?".overlap_end_wildcard("\u{e9}s", '?'), "?");
+        assert_eq!("caf?".overlap_end_wildcard("es", '?'), "?");
+        assert_eq!(
+            "caf\u{b7}".overlap_end_wildcard("\u{e9}s", '\u{b7}'),
+            "\u{b7}"
+        );
+        assert_eq!("caf\u{b7}".overlap_end_wildcard("es", '\u{b7}'), "\u{b7}");
+        assert_eq!(
+            "\u{fffc}b".overlap_end_wildcard("abc", '\u{fffc}'),
+            "\u{fffc}b"
+        );
+        assert_eq!("ab".overlap_end_wildcard("\u{fffc}\u{e9}", '\u{fffc}'), "b");
+    }
+
+    #[test]
+    fn overlap_longer_in_bytes_than_other() {
+        // The three-byte wildcards each match a single one-byte character.
+        assert_eq!(
+            "x\u{fffc}\u{fffc}".overlap_end_wildcard("ab", '\u{fffc}'),
+            "\u{fffc}\u{fffc}"
+        );
+        assert_eq!(wildcard_overlap_index("??", "\u{e9}", '?'), 1);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert_eq!(wildcard_overlap_index("abc", "def", '?'), 3);
+        assert_eq!(wildcard_overlap_index("", "?", '?'), 0);
+        assert_eq!(wildcard_overlap_index("?", "", '?'), 1);
+    }
+}
@@ -0,0 +1,147 @@
+//! Overlaps of strings that begin and end on word bounds.
+//!
+//! Without the `unicode-segmentation` feature, words are separated by ASCII whitespace and
+//! punctuation. With it, the word bounds defined by [Unicode Standard Annex #29] are used instead,
+//! which also separate words in scripts that don't use spaces.
+//!
+//! [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/
+
+use slice_overlap_index_at_boundary;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Returns whether `byte` is ASCII whitespace or punctuation.
+///
+/// This is the same as `u8::is_ascii_whitespace()` or `u8::is_ascii_punctuation()`, which are not
+/// available on the MSRV.
+#[cfg(not(feature = "unicode-segmentation"))]
+#[inline]
+fn is_separator(byte: u8) -> bool {
+    b"\t\n\x0C\r !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".contains(&byte)
+}
+
+/// Returns whether `index` is on a word bound of `s`.
+///
+/// The start and end of `s` are always word bounds. Otherwise, `index` is a word bound if the
+/// byte on either side of it is ASCII whitespace or punctuation.
+#[cfg(not(feature = "unicode-segmentation"))]
+#[inline]
+fn is_word_boundary(s: &str, index: usize) -> bool {
+    let bytes = s.as_bytes();
+    index == 0
+        || index == bytes.len()
+        || is_separator(bytes[index - 1])
+        || is_separator(bytes[index])
+}
+
+/// Returns whether `index` is on a word bound of `s`.
+///
+/// The start and end of `s` are always word bounds. Otherwise, the word bounds of Unicode Standard
+/// Annex #29 are used.
+#[cfg(feature = "unicode-segmentation")]
+#[inline]
+fn is_word_boundary(s: &str, index: usize) -> bool {
+    index == s.len()
+        || s.split_word_bound_indices()
+            .map(|(start, _)| start)
+            .take_while(|&start| start <= index)
+            .any(|start| start == index)
+}
+
+/// Finds the index at which two strings overlap, only accepting overlaps that begin on a word
+/// bound of `left` and end on a word bound of `right`.
+///
+/// The `left` and `right` parameters are defined the same as in
+/// [`slice_overlap_index`](::slice_overlap_index).
+///
+/// Every candidate overlap is on character bounds of both strings, for the reasons described on
+/// [`overlap_index`](::overlap_index). Candidates that aren't also on word bounds
+/// are skipped in favor of shorter ones.
+#[inline]
+pub fn word_overlap_index(left: &str, right: &str) -> usize {
+    slice_overlap_index_at_boundary(left.as_bytes(), right.as_bytes(), |index| {
+        is_word_boundary(left, index) && is_word_boundary(right, left.len() - index)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_word_boundary;
+    use StrOverlap;
+
+    #[test]
+    fn boundaries() {
+        assert!(is_word_boundary("the cat", 0));
+        assert!(is_word_boundary("the cat", 3));
+        assert!(is_word_boundary("the cat", 4));
+        assert!(!is_word_boundary("the cat", 5));
+        assert!(is_word_boundary("the cat", 7));
+        assert!(is_word_boundary("", 0));
+    }
+
+    #[test]
+    fn end_within_word_of_other() {
+        // "ca" begins a word of `self`, but ends in the middle of "cat".
+        assert_eq!("a ca".overlap_end_words("cat"), "");
+        assert_eq!("cat".overlap_start_words("a ca"), "");
+    }
+
+    #[test]
+    fn shrink_to_word_bounds_of_both() {
+        // "ab ab" ends on a bound of `other` but begins in the middle of "xab". "ab" is on bounds
+        // of both.
+        assert_eq!("xab ab".overlap_end_words("ab ab c"), "ab");
+        assert_eq!("ab ab c".overlap_start_words("xab ab"), "ab");
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!("foo,bar".overlap_end_words("bar!"), "bar");
+        assert_eq!("say (hi".overlap_end_words("hi) there"), "hi");
+        assert_eq!("bar!".overlap_start_words("foo,bar"), "bar");
+    }
+
+    #[test]
+    fn whitespace_seam() {
+        assert_eq!("the ".overlap_end_words(" cat"), " ");
+        assert_eq!("the cat ".overlap_end_words("cat sat"), "cat ");
+    }
+
+    #[test]
+    fn retried_output() {
+        assert_eq!(
+            "the quick brown fox jumps overl".overlap_end_words("overlapping fences"),
+            ""
+        );
+        assert_eq!(
+            "the quick brown fox jumps over".overlap_end_words("fox jumps over the fence"),
+            "fox jumps over"
+        );
+    }
+
+    #[cfg(not(feature = "unicode-segmentation"))]
+    #[test]
+    fn non_ascii_is_not_separator() {
+        assert!(!is_word_boundary("a\u{a0}b", 1));
+        assert!(!is_word_boundary("a\u{a0}b", 3));
+        assert_eq!("the\u{a0}cat".overlap_end_words("cat"), "");
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn non_ascii_separator() {
+        assert!(is_word_boundary("a\u{a0}b", 1));
+        assert!(is_word_boundary("a\u{a0}b", 3));
+        assert_eq!("the\u{a0}cat".overlap_end_words("cat"), "cat");
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn unicode_words() {
+        // Letters on either side of a full stop form a single word.
+        assert!(!is_word_boundary("e.g", 1));
+        assert_eq!("see e.g".overlap_end_words("g. here"), "");
+        // Ideographs are each their own word.
+        assert_eq!("日本語".overlap_end_words("本語です"), "本語");
+    }
+}